@@ -0,0 +1,47 @@
+use clap_num::{si_number, si_number_range, Rounding, SiParser};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+const INPUTS: &[&str] = &[
+    "1",
+    "1_000_000",
+    "3k3",
+    "3.3k",
+    "1M",
+    "456789k123",
+    "1.5e3k",
+    "-1.234k",
+    "1Y123456789987654321",
+];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("si_number");
+    for input in INPUTS {
+        group.bench_with_input(BenchmarkId::from_parameter(input), input, |b, s| {
+            b.iter(|| si_number::<i128>(black_box(s)))
+        });
+    }
+    group.finish();
+}
+
+fn parse_range(c: &mut Criterion) {
+    c.bench_function("si_number_range", |b| {
+        b.iter(|| si_number_range::<u32>(black_box("999k999"), 1_000, 999_999))
+    });
+}
+
+fn parse_rounded(c: &mut Criterion) {
+    let parser = SiParser::new().rounding(Rounding::Nearest);
+    c.bench_function("si_number_rounded", |b| {
+        b.iter(|| parser.parse::<u32>(black_box("1.23456789k")))
+    });
+}
+
+fn parse_error(c: &mut Criterion) {
+    c.bench_function("si_number_error", |b| {
+        b.iter(|| si_number::<u8>(black_box("1k")))
+    });
+}
+
+criterion_group!(benches, parse, parse_range, parse_rounded, parse_error);
+criterion_main!(benches);
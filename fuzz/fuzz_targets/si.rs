@@ -0,0 +1,6 @@
+#![no_main]
+
+use clap_num::testing::{check_invariants, Grammar};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| check_invariants(Grammar::Si, s));
@@ -0,0 +1,139 @@
+//! Parsers for bounded integers from [bounded-integer].
+//!
+//! This module is only available with the `bounded-integer` feature.
+//!
+//! Each function is generic over the [`BoundedU8`], [`BoundedI32`], and other
+//! bounded integer types, and validates the value against the bounds of the
+//! type, so the bounds are only written once.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use bounded_integer::{BoundedU16, BoundedU8};
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[clap(long, value_parser=clap_num::bounded_integer::number::<BoundedU8<1, 99>>)]
+//!     percent: BoundedU8<1, 99>,
+//!     #[clap(long, value_parser=clap_num::bounded_integer::maybe_hex::<BoundedU16<0, 0x3FF>>)]
+//!     address: BoundedU16<0, 0x3FF>,
+//! }
+//! # let args = Args::parse_from(&["", "--percent", "42", "--address", "0x3FF"]);
+//! # assert_eq!(args.percent.get(), 42);
+//! # assert_eq!(args.address.get(), 0x3FF);
+//! ```
+//!
+//! ## Error Messages
+//!
+//! Values exceeding the bounds of the type will show an error message similar
+//! to this:
+//!
+//! ```text
+//! error: Invalid value for '--percent <percent>': exceeds maximum of 99
+//! ```
+//!
+//! [bounded-integer]: https://docs.rs/bounded-integer
+
+use crate::{maybe_hex_range, number_range, si_number_range};
+use ::bounded_integer::{
+    BoundedI128, BoundedI16, BoundedI32, BoundedI64, BoundedI8, BoundedIsize, BoundedU128,
+    BoundedU16, BoundedU32, BoundedU64, BoundedU8, BoundedUsize,
+};
+use core::fmt;
+use core::num::ParseIntError;
+use core::str::FromStr;
+use num_traits::{sign, Num, PrimInt};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A bounded integer type from [bounded-integer](https://docs.rs/bounded-integer),
+/// such as [`BoundedU8`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait BoundedInt: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    type Inner: PrimInt
+        + Num<FromStrRadixErr = ParseIntError>
+        + FromStr<Err = ParseIntError>
+        + TryFrom<u128, Error: fmt::Display>
+        + fmt::Display;
+    #[doc(hidden)]
+    const LOWER: Self::Inner;
+    #[doc(hidden)]
+    const UPPER: Self::Inner;
+    #[doc(hidden)]
+    fn from_inner(val: Self::Inner) -> Option<Self>;
+}
+
+macro_rules! bounded_int {
+    ($($t:ident($inner:ty)),* $(,)?) => {
+        $(
+            impl<const MIN: $inner, const MAX: $inner> sealed::Sealed for $t<MIN, MAX> {}
+
+            impl<const MIN: $inner, const MAX: $inner> BoundedInt for $t<MIN, MAX> {
+                type Inner = $inner;
+                const LOWER: $inner = MIN;
+                const UPPER: $inner = MAX;
+
+                fn from_inner(val: $inner) -> Option<Self> {
+                    Self::new(val)
+                }
+            }
+        )*
+    };
+}
+
+bounded_int!(
+    BoundedU8(u8),
+    BoundedU16(u16),
+    BoundedU32(u32),
+    BoundedU64(u64),
+    BoundedU128(u128),
+    BoundedUsize(usize),
+    BoundedI8(i8),
+    BoundedI16(i16),
+    BoundedI32(i32),
+    BoundedI64(i64),
+    BoundedI128(i128),
+    BoundedIsize(isize),
+);
+
+// wraps a value that has been checked against the bounds of the type
+fn wrap<T: BoundedInt>(val: T::Inner) -> T {
+    T::from_inner(val).expect("value is within the bounds of the type")
+}
+
+/// Validates a base-10 value within the bounds of the type, see
+/// [`number_range`].
+///
+/// ```
+/// use bounded_integer::BoundedI8;
+///
+/// let parse = clap_num::bounded_integer::number::<BoundedI8<-10, 10>>;
+/// assert_eq!(parse("-10").map(BoundedI8::get), Ok(-10));
+/// assert_eq!(parse("11"), Err(String::from("exceeds maximum of 10")));
+/// ```
+pub fn number<T: BoundedInt>(s: &str) -> Result<T, String> {
+    number_range(s, T::LOWER, T::UPPER).map(wrap)
+}
+
+/// Validates a value that can be base-10 or base-16 within the bounds of the
+/// type, see [`maybe_hex_range`].
+pub fn maybe_hex<T>(s: &str) -> Result<T, String>
+where
+    T: BoundedInt,
+    T::Inner: sign::Unsigned,
+{
+    maybe_hex_range(s, T::LOWER, T::UPPER).map(wrap)
+}
+
+/// Validates a value with a [metric prefix] within the bounds of the type,
+/// see [`si_number_range`].
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number<T: BoundedInt>(s: &str) -> Result<T, String> {
+    si_number_range(s, T::LOWER, T::UPPER).map(wrap)
+}
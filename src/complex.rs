@@ -0,0 +1,150 @@
+use crate::{check_range, parse_decimal};
+use num_complex::Complex;
+
+// parses the real or imaginary part of a complex number
+fn component(s: &str) -> Result<f64, String> {
+    let val: f64 = parse_decimal(s)?;
+    if val.is_nan() {
+        Err(String::from("value is not a number"))
+    } else {
+        Ok(val)
+    }
+}
+
+/// Validates a complex number in rectangular form, such as `3+4i`, `-2.5i`,
+/// or `1.0`, or in polar form, such as `1∠45` or `1<45deg`.
+///
+/// This is only available with the `num-complex` feature.
+///
+/// In rectangular form the imaginary unit can be written as `i` or `j`, and
+/// either part can be omitted.
+/// Both parts accept exponent notation, such as `1e-3+2e-3i`.
+///
+/// In polar form the magnitude and angle are separated by `∠`, or by `<` for
+/// keyboards without it, and the value is converted to rectangular form.
+/// The angle is in degrees, optionally suffixed with `deg` or `°`, or in
+/// radians when suffixed with `rad`, such as `1<0.5rad`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::complex;
+/// use num_complex::Complex;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=complex, allow_hyphen_values=true)]
+///     coefficient: Complex<f64>,
+/// }
+/// # let args = Args::parse_from(&["", "--coefficient", "3+4i"]);
+/// # assert_eq!(args.coefficient, Complex::new(3.0, 4.0));
+/// # let args = Args::parse_from(&["", "--coefficient", "2∠180"]);
+/// # assert!((args.coefficient - Complex::new(-2.0, 0.0)).norm() < 1e-12);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are not complex numbers will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--coefficient <coefficient>': invalid float literal
+/// ```
+pub fn complex(s: &str) -> Result<Complex<f64>, String> {
+    if let Some((magnitude, angle)) = s.split_once(['∠', '<']) {
+        return polar(magnitude, angle);
+    }
+
+    let Some(rest) = s.strip_suffix(['i', 'j']) else {
+        return component(s).map(|re| Complex::new(re, 0.0));
+    };
+
+    // the imaginary part starts at the last sign that is not the sign of the
+    // real part or of an exponent
+    let split = rest
+        .char_indices()
+        .rev()
+        .find(|&(idx, c)| matches!(c, '+' | '-') && idx > 0 && !rest[..idx].ends_with(['e', 'E']))
+        .map(|(idx, _)| idx);
+    let (re, im) = match split {
+        Some(idx) => (component(&rest[..idx])?, &rest[idx..]),
+        None => (0.0, rest),
+    };
+
+    let im = match im {
+        "" | "+" => 1.0,
+        "-" => -1.0,
+        im => component(im)?,
+    };
+    Ok(Complex::new(re, im))
+}
+
+// converts a magnitude and an angle in degrees or radians to rectangular form
+fn polar(magnitude: &str, angle: &str) -> Result<Complex<f64>, String> {
+    let magnitude = component(magnitude)?;
+    if magnitude < 0.0 {
+        return Err(String::from("magnitude cannot be negative"));
+    }
+    let theta = match angle.strip_suffix("rad") {
+        Some(radians) => component(radians)?,
+        None => {
+            let degrees = angle
+                .strip_suffix("deg")
+                .or_else(|| angle.strip_suffix('°'))
+                .unwrap_or(angle);
+            component(degrees)?.to_radians()
+        }
+    };
+
+    let val = Complex::from_polar(magnitude, theta);
+    if val.is_nan() {
+        Err(String::from("value is not a number"))
+    } else {
+        Ok(val)
+    }
+}
+
+/// Validates a complex number with a magnitude within a range.
+///
+/// This is only available with the `num-complex` feature.
+///
+/// This accepts the same formats as [`complex`].
+///
+/// # Example
+///
+/// This allows for a filter pole within the unit circle.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::complex_magnitude_range;
+/// use num_complex::Complex;
+///
+/// fn pole(s: &str) -> Result<Complex<f64>, String> {
+///     complex_magnitude_range(s, 0.0, 1.0)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=pole, allow_hyphen_values=true)]
+///     pole: Complex<f64>,
+/// }
+/// # let args = Args::parse_from(&["", "--pole", "0.6-0.8i"]);
+/// # assert_eq!(args.pole, Complex::new(0.6, -0.8));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with a magnitude exceeding the limits will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--pole <pole>': magnitude exceeds maximum of 1
+/// ```
+pub fn complex_magnitude_range(s: &str, min: f64, max: f64) -> Result<Complex<f64>, String> {
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let val = complex(s)?;
+    check_range(val.norm(), min, max)
+        .map(|_| val)
+        .map_err(|e| format!("magnitude {e}"))
+}
@@ -0,0 +1,366 @@
+// Parsers for the `const_num!` and `const_si!` macros.
+//
+// These are const fns, so invalid constants fail to compile. Panics in const
+// fns cannot format the message, so the messages of the runtime parsers are
+// repeated as literals.
+
+/// Creates an integer constant from a string in the notation of
+/// [`maybe_hex`](crate::maybe_hex), [`maybe_bin`](crate::maybe_bin), and
+/// [`maybe_oct`](crate::maybe_oct), such as `const_num!(u32, "0xDEAD_BEEF")`.
+///
+/// The value is parsed at compile time, so default values and bounds can be
+/// written in the same notation as the command line, and invalid values fail
+/// to compile.
+///
+/// Values are base-10 by default, or base-16, base-2, or base-8 with a `0x`,
+/// `0b`, or `0o` prefix, case insensitive.
+/// Values can be negative for signed types, and underscores can be used as
+/// digit separators.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{const_num, maybe_hex};
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=maybe_hex::<u32>, default_value_t=const_num!(u32, "0xDEAD_BEEF"))]
+///     magic: u32,
+/// }
+/// # let args = Args::parse_from(&[""]);
+/// # assert_eq!(args.magic, 0xDEAD_BEEF);
+/// ```
+///
+/// Values that do not fit in the type fail to compile:
+///
+/// ```compile_fail
+/// let value = clap_num::const_num!(u8, "0x100");
+/// ```
+#[macro_export]
+macro_rules! const_num {
+    ($t:ty, $s:expr) => {
+        $crate::__const_int!($t, $crate::__const_num($s))
+    };
+}
+
+/// Creates an integer constant from a string with a [metric prefix] in the
+/// notation of [`si_number`](crate::si_number), such as
+/// `const_si!(u64, "4k7")`.
+///
+/// The value is parsed at compile time, so default values and bounds can be
+/// written in the same notation as the command line, and invalid values fail
+/// to compile.
+///
+/// The SI symbol can be used as a suffix, such as `1.5k`, or in place of the
+/// decimal point, such as `1k5`.
+/// Exponent notation can be used with or without a suffix, such as `2e3` or
+/// `1.5e3k`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{const_si, si_number_range};
+///
+/// const MIN_RATE: u64 = const_si!(u64, "1k");
+/// const MAX_RATE: u64 = const_si!(u64, "10M");
+///
+/// fn rate(s: &str) -> Result<u64, String> {
+///     si_number_range(s, MIN_RATE, MAX_RATE)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=rate, default_value_t=const_si!(u64, "44k1"))]
+///     rate: u64,
+/// }
+/// # let args = Args::parse_from(&[""]);
+/// # assert_eq!(args.rate, 44_100);
+/// ```
+///
+/// Values that are not an integer fail to compile:
+///
+/// ```compile_fail
+/// let value = clap_num::const_si!(u32, "1.2345k");
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+#[macro_export]
+macro_rules! const_si {
+    ($t:ty, $s:expr) => {
+        $crate::__const_int!($t, $crate::__const_si($s))
+    };
+}
+
+// converts a sign and magnitude to an integer constant of the type
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_int {
+    ($t:ty, $parsed:expr) => {{
+        const VALUE: $t = {
+            let (negative, magnitude): (bool, u128) = $parsed;
+            if negative && magnitude != 0 {
+                if <$t>::MIN == 0 {
+                    panic!("value cannot be negative");
+                } else if magnitude > (<$t>::MIN as i128).unsigned_abs() {
+                    panic!("number too small to fit in target type");
+                }
+                (magnitude as i128).wrapping_neg() as $t
+            } else {
+                if magnitude > <$t>::MAX as u128 {
+                    panic!("number too large to fit in target type");
+                }
+                magnitude as $t
+            }
+        };
+        VALUE
+    }};
+}
+
+// splits the sign from a value
+const fn split_sign(s: &[u8]) -> (bool, &[u8]) {
+    match s {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        _ => (false, s),
+    }
+}
+
+// parses digits of a radix, ignoring underscores
+const fn parse_digits(digits: &[u8], radix: u32) -> u128 {
+    let mut value: u128 = 0;
+    let mut empty = true;
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = match digits[i] {
+            b'_' => {
+                i += 1;
+                continue;
+            }
+            d @ b'0'..=b'9' => d - b'0',
+            d @ b'a'..=b'z' => d - b'a' + 10,
+            d @ b'A'..=b'Z' => d - b'A' + 10,
+            _ => panic!("invalid digit found in string"),
+        };
+        if digit as u32 >= radix {
+            panic!("invalid digit found in string");
+        }
+        value = match value.checked_mul(radix as u128) {
+            Some(value) => match value.checked_add(digit as u128) {
+                Some(value) => value,
+                None => panic!("number too large to fit in target type"),
+            },
+            None => panic!("number too large to fit in target type"),
+        };
+        empty = false;
+        i += 1;
+    }
+    if empty {
+        panic!("cannot parse integer from empty string");
+    }
+    value
+}
+
+// counts the digits of a value, ignoring underscores
+const fn count_digits(digits: &[u8]) -> u32 {
+    let mut count = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        if digits[i] != b'_' {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+// the power of ten of an SI symbol
+const fn si_digits(symbol: u8) -> Option<i64> {
+    match symbol {
+        b'k' | b'K' => Some(3),
+        b'M' => Some(6),
+        b'G' => Some(9),
+        b'T' => Some(12),
+        b'P' => Some(15),
+        b'E' => Some(18),
+        b'Z' => Some(21),
+        b'Y' => Some(24),
+        _ => None,
+    }
+}
+
+// finds the index of the first SI symbol
+const fn find_si_symbol(s: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i < s.len() {
+        if si_digits(s[i]).is_some() {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+// splits a value at the first occurrence of a byte, excluding the byte
+const fn split_once(s: &[u8], byte: u8) -> Option<(&[u8], &[u8])> {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] == byte {
+            let (before, after) = s.split_at(i);
+            return Some((before, after.split_at(1).1));
+        }
+        i += 1;
+    }
+    None
+}
+
+// splits the exponent from a value, such as "1.5e3"
+const fn split_exponent(s: &[u8]) -> (&[u8], i64) {
+    let Some((mantissa, exponent)) = split_once(s, b'e') else {
+        return (s, 0);
+    };
+    let (negative, digits) = split_sign(exponent);
+    if digits.is_empty() {
+        panic!("invalid digit found in string");
+    }
+    let mut value: i64 = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        if !digits[i].is_ascii_digit() {
+            panic!("invalid digit found in string");
+        }
+        value = value * 10 + (digits[i] - b'0') as i64;
+        // the exponent is an i32, as in `si_number`
+        if value > i32::MAX as i64 + negative as i64 {
+            panic!("invalid digit found in string");
+        }
+        i += 1;
+    }
+    (mantissa, if negative { -value } else { value })
+}
+
+// Converts the decimal number `int.frac * 10^scale` to a sign and magnitude,
+// where `int` may be signed, as with `scale_decimal` without rounding.
+const fn scale_decimal(int: &[u8], frac: &[u8], scale: i64) -> (bool, u128) {
+    let (negative, unsigned) = split_sign(int);
+    let signed = unsigned.len() != int.len();
+    let int = unsigned;
+    let len = int.len() + frac.len();
+
+    let keep = (count_digits(int) as i64).saturating_add(scale);
+    let mut invalid = false;
+    let mut remainder = false;
+    let mut total: i64 = 0;
+    let mut i = 0;
+    while i < len {
+        let b = digit_at(int, frac, i);
+        i += 1;
+        if b == b'_' {
+            continue;
+        }
+        invalid |= !b.is_ascii_digit();
+        remainder |= total >= keep && b != b'0';
+        total += 1;
+    }
+
+    if remainder {
+        panic!("not an integer");
+    } else if invalid || (total == 0 && signed) {
+        panic!("invalid digit found in string");
+    } else if total == 0 {
+        panic!("cannot parse integer from empty string");
+    }
+
+    let mut value: u128 = 0;
+    let mut idx: i64 = 0;
+    let mut i = 0;
+    while i < len && idx < keep {
+        let b = digit_at(int, frac, i);
+        i += 1;
+        if b == b'_' {
+            continue;
+        }
+        value = push_digit(value, b - b'0', negative);
+        idx += 1;
+    }
+
+    // pad with zeros when the scale extends beyond the provided digits
+    while value != 0 && idx < keep {
+        value = push_digit(value, 0, negative);
+        idx += 1;
+    }
+
+    (negative, value)
+}
+
+// returns a digit of the integer part followed by the fractional part
+const fn digit_at(int: &[u8], frac: &[u8], i: usize) -> u8 {
+    if i < int.len() {
+        int[i]
+    } else {
+        frac[i - int.len()]
+    }
+}
+
+// appends a base-10 digit to a magnitude
+const fn push_digit(value: u128, digit: u8, negative: bool) -> u128 {
+    if let Some(value) = value.checked_mul(10) {
+        if let Some(value) = value.checked_add(digit as u128) {
+            return value;
+        }
+    }
+    if negative {
+        panic!("number too small to fit in target type");
+    } else {
+        panic!("number too large to fit in target type");
+    }
+}
+
+#[doc(hidden)]
+pub const fn __const_num(s: &str) -> (bool, u128) {
+    let (negative, digits) = split_sign(s.as_bytes());
+    let (radix, digits) = match digits {
+        [b'0', b'x' | b'X', rest @ ..] => (16, rest),
+        [b'0', b'b' | b'B', rest @ ..] => (2, rest),
+        [b'0', b'o' | b'O', rest @ ..] => (8, rest),
+        _ => (10, digits),
+    };
+    (negative, parse_digits(digits, radix))
+}
+
+// The syntax is the same as `SiParser::parse_value` without options.
+#[doc(hidden)]
+pub const fn __const_si(s: &str) -> (bool, u128) {
+    let s = s.as_bytes();
+
+    let (mantissa, digits) = match find_si_symbol(s) {
+        // no SI symbol or exponent, parse normally
+        None if split_once(s, b'e').is_none() => return scale_decimal(s, &[], 0),
+        // exponent without an SI symbol, in the format of "2e6"
+        None => (s, 0),
+        Some(idx) => {
+            let (pre_si, rest) = s.split_at(idx);
+            let (symbol, post_si) = rest.split_at(1);
+            let Some(digits) = si_digits(symbol[0]) else {
+                unreachable!()
+            };
+            if pre_si.is_empty() {
+                panic!("no value found before SI symbol");
+            } else if !post_si.is_empty() {
+                // in the format of "1k234" for 1_234
+                return scale_decimal(pre_si, post_si, digits);
+            }
+            // in the format of "1.234k" for 1_234, or "1.234e3k" for
+            // 1_234_000
+            (pre_si, digits)
+        }
+    };
+
+    let (mantissa, exponent) = split_exponent(mantissa);
+    let (int, frac) = match split_once(mantissa, b'.') {
+        Some(split) => split,
+        None => mantissa.split_at(mantissa.len()),
+    };
+    scale_decimal(int, frac, digits + exponent)
+}
@@ -0,0 +1,352 @@
+use crate::prefix::PrefixBound;
+use crate::{check_range, scale_decimal, NOT_AN_INTEGER_MSG};
+use core::fmt;
+use num_traits::identities::Zero;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+
+// A currency with units that are a power of ten of its smallest unit.
+struct Currency {
+    // units with the number of decimal places of each unit in the smallest
+    // unit
+    units: &'static [(&'static str, u32)],
+    // units used to format range bounds, the smallest unit is used if none
+    // of them divides the bound exactly
+    bound_units: &'static [(&'static str, u128)],
+    // the unit of the parsed values
+    smallest: &'static str,
+    // error message for amounts that are not a whole number of the smallest
+    // unit
+    precision_msg: &'static str,
+}
+
+const ETHER: Currency = Currency {
+    units: &[
+        ("wei", 0),
+        ("kwei", 3),
+        ("mwei", 6),
+        ("gwei", 9),
+        ("szabo", 12),
+        ("finney", 15),
+        ("ether", 18),
+        ("eth", 18),
+    ],
+    bound_units: &[("gwei", 1_000_000_000), ("eth", 1_000_000_000_000_000_000)],
+    smallest: "wei",
+    precision_msg: "not a whole number of wei",
+};
+
+const BITCOIN: Currency = Currency {
+    units: &[
+        ("btc", 8),
+        ("mbtc", 5),
+        ("ubtc", 2),
+        ("sat", 0),
+        ("sats", 0),
+    ],
+    bound_units: &[("btc", 100_000_000)],
+    smallest: "sat",
+    precision_msg: "not a whole number of satoshis",
+};
+
+// A bound formatted with the largest bound unit of a currency that divides
+// it exactly, or in the smallest unit otherwise, such as `1.5eth` is shown
+// as `1500000000gwei`.
+struct CurrencyBound<'a, T> {
+    bound: T,
+    currency: &'a Currency,
+}
+
+impl<T: fmt::Display> fmt::Display for CurrencyBound<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bound = PrefixBound {
+            bound: &self.bound,
+            table: self.currency.bound_units,
+        }
+        .to_string();
+        if bound.ends_with(|c: char| c.is_ascii_digit()) {
+            write!(f, "{bound}{}", self.currency.smallest)
+        } else {
+            f.write_str(&bound)
+        }
+    }
+}
+
+impl Currency {
+    // lists the units, such as "wei, kwei, mwei"
+    fn unit_list(&self) -> String {
+        self.units
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // parses a decimal amount followed by a unit (case insensitive), in the
+    // smallest unit
+    fn parse<T>(&self, s: &str) -> Result<T, String>
+    where
+        T: CheckedAdd + CheckedMul + CheckedSub + TryFrom<u128> + Zero,
+    {
+        let value_len = s
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '_' | '+' | '-')))
+            .unwrap_or(s.len());
+        let (value, unit) = s.split_at(value_len);
+        let unit = unit.trim_start();
+
+        if unit.is_empty() {
+            return Err(format!(
+                "missing unit, expected one of {}",
+                self.unit_list()
+            ));
+        }
+        let decimals = self
+            .units
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|&(_, decimals)| decimals)
+            .ok_or_else(|| {
+                format!(
+                    "unknown unit '{unit}', expected one of {}",
+                    self.unit_list()
+                )
+            })?;
+        if value.is_empty() {
+            return Err(format!("no value found before unit '{unit}'"));
+        }
+
+        let (int, frac) = value.split_once('.').unwrap_or((value, ""));
+        scale_decimal(int, frac, i64::from(decimals), None).map_err(|e| {
+            if e == NOT_AN_INTEGER_MSG {
+                String::from(self.precision_msg)
+            } else {
+                e
+            }
+        })
+    }
+
+    // parses an amount within a range
+    fn parse_range<T>(&self, s: &str, min: T, max: T) -> Result<T, String>
+    where
+        T: CheckedAdd + CheckedMul + CheckedSub + TryFrom<u128> + Zero + Ord + fmt::Display,
+    {
+        debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+        let val = self.parse(s)?;
+        check_range(val, min, max).map_err(|e| {
+            String::from(e.map(|bound| CurrencyBound {
+                bound,
+                currency: self,
+            }))
+        })
+    }
+}
+
+/// Validate an amount of ether in wei, such as `1.5eth`, `20gwei`, or
+/// `1000000wei`.
+///
+/// The amount is a decimal number followed by a unit, units are case
+/// insensitive and can be separated from the number by whitespace.
+///
+/// | Unit              | Value in wei |
+/// |-------------------|--------------|
+/// | `wei`             | 1            |
+/// | `kwei`            | 10³          |
+/// | `mwei`            | 10⁶          |
+/// | `gwei`            | 10⁹          |
+/// | `szabo`           | 10¹²         |
+/// | `finney`          | 10¹⁵         |
+/// | `eth`, `ether`    | 10¹⁸         |
+///
+/// The conversion is exact, the digits are scaled without floating point
+/// arithmetic, and amounts that are not a whole number of wei are rejected
+/// instead of being rounded.
+/// Values without a unit are rejected, because a bare number is as likely
+/// to be meant in ether as in wei.
+///
+/// Underscores can be used as digit separators, for example `1_000gwei`.
+///
+/// With the `primitive-types` feature [`u256::wei`](crate::u256::wei) parses
+/// amounts as a `U256`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::wei;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=wei::<u128>)]
+///     value: u128,
+///     #[clap(long, value_parser=wei::<u128>)]
+///     gas_price: u128,
+/// }
+/// # let args = Args::parse_from(&["", "--value", "1.5eth", "--gas-price", "20gwei"]);
+/// # assert_eq!(args.value, 1_500_000_000_000_000_000);
+/// # assert_eq!(args.gas_price, 20_000_000_000);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with more decimal places than the unit allows will show an error
+/// message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--gas-price <gas-price>': not a whole number of wei
+/// ```
+///
+/// Values without a unit will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--value <value>': missing unit, expected one of wei, kwei, mwei, gwei, szabo, finney, ether, eth
+/// ```
+pub fn wei<T>(s: &str) -> Result<T, String>
+where
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    ETHER.parse(s)
+}
+
+/// Validate an amount of ether in wei within a range.
+///
+/// This combines [`wei`] and [`number_range`](crate::number_range), see the
+/// documentation for those functions for details.
+///
+/// # Example
+///
+/// This allows for a priority fee of at most 2 gwei.
+///
+/// ```
+/// use clap_num::wei_range;
+///
+/// fn priority_fee(s: &str) -> Result<u128, String> {
+///     wei_range(s, 0, 2_000_000_000)
+/// }
+///
+/// assert_eq!(priority_fee("1.5gwei"), Ok(1_500_000_000));
+/// assert_eq!(priority_fee("0.1eth"), Err(String::from("exceeds maximum of 2gwei")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown in `eth` or `gwei` if either divides them exactly,
+/// or in wei otherwise, values exceeding the limits will show an error
+/// message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--priority-fee <priority-fee>': exceeds maximum of 2gwei
+/// ```
+pub fn wei_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Ord,
+    T: fmt::Display,
+{
+    ETHER.parse_range(s, min, max)
+}
+
+/// Validate an amount of bitcoin in satoshis, such as `0.001btc`, `1.5mBTC`,
+/// or `150000sat`.
+///
+/// The amount is a decimal number followed by a unit, units are case
+/// insensitive and can be separated from the number by whitespace.
+///
+/// | Unit          | Value in satoshis |
+/// |---------------|-------------------|
+/// | `sat`, `sats` | 1                 |
+/// | `ubtc`        | 10²               |
+/// | `mbtc`        | 10⁵               |
+/// | `btc`         | 10⁸               |
+///
+/// The conversion is exact as with [`wei`], amounts with more than 8 decimal
+/// places of bitcoin, or any decimal places of satoshis, are rejected instead
+/// of being rounded.
+/// Values without a unit are rejected.
+///
+/// Underscores can be used as digit separators, for example `150_000sat`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::satoshi;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=satoshi::<u64>)]
+///     amount: u64,
+///     #[clap(long, value_parser=satoshi::<u64>)]
+///     fee: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--amount", "0.001btc", "--fee", "1500sat"]);
+/// # assert_eq!(args.amount, 100_000);
+/// # assert_eq!(args.fee, 1_500);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with more decimal places than the unit allows will show an error
+/// message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--amount <amount>': not a whole number of satoshis
+/// ```
+pub fn satoshi<T>(s: &str) -> Result<T, String>
+where
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    BITCOIN.parse(s)
+}
+
+/// Validate an amount of bitcoin in satoshis within a range.
+///
+/// This combines [`satoshi`] and [`number_range`](crate::number_range), see
+/// the documentation for those functions for details.
+///
+/// # Example
+///
+/// This allows for an amount of at most the supply of 21 million bitcoin.
+///
+/// ```
+/// use clap_num::satoshi_range;
+///
+/// fn amount(s: &str) -> Result<u64, String> {
+///     satoshi_range(s, 546, 21_000_000 * 100_000_000)
+/// }
+///
+/// assert_eq!(amount("1.5mBTC"), Ok(150_000));
+/// assert_eq!(amount("22000000btc"), Err(String::from("exceeds maximum of 21000000btc")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown in `btc` if it divides them exactly, or in satoshis
+/// otherwise, values exceeding the limits will show an error message similar
+/// to this:
+///
+/// ```text
+/// error: Invalid value for '--amount <amount>': less than minimum of 546sat
+/// ```
+pub fn satoshi_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Ord,
+    T: fmt::Display,
+{
+    BITCOIN.parse_range(s, min, max)
+}
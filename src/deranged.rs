@@ -0,0 +1,139 @@
+//! Parsers for bounded integers from [deranged].
+//!
+//! This module is only available with the `deranged` feature.
+//!
+//! Each function is generic over the [`RangedU8`], [`RangedI32`], and other
+//! ranged integer types, and validates the value against the bounds of the
+//! type, so the bounds are only written once.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use deranged::{RangedU16, RangedU8};
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[clap(long, value_parser=clap_num::deranged::number::<RangedU8<1, 99>>)]
+//!     percent: RangedU8<1, 99>,
+//!     #[clap(long, value_parser=clap_num::deranged::maybe_hex::<RangedU16<0, 0x3FF>>)]
+//!     address: RangedU16<0, 0x3FF>,
+//! }
+//! # let args = Args::parse_from(&["", "--percent", "42", "--address", "0x3FF"]);
+//! # assert_eq!(args.percent.get(), 42);
+//! # assert_eq!(args.address.get(), 0x3FF);
+//! ```
+//!
+//! ## Error Messages
+//!
+//! Values exceeding the bounds of the type will show an error message similar
+//! to this:
+//!
+//! ```text
+//! error: Invalid value for '--percent <percent>': exceeds maximum of 99
+//! ```
+//!
+//! [deranged]: https://docs.rs/deranged
+
+use crate::{maybe_hex_range, number_range, si_number_range};
+use ::deranged::{
+    RangedI128, RangedI16, RangedI32, RangedI64, RangedI8, RangedIsize, RangedU128, RangedU16,
+    RangedU32, RangedU64, RangedU8, RangedUsize,
+};
+use core::fmt;
+use core::num::ParseIntError;
+use core::str::FromStr;
+use num_traits::{sign, Num, PrimInt};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A ranged integer type from [deranged](https://docs.rs/deranged), such as
+/// [`RangedU8`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait RangedInt: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    type Inner: PrimInt
+        + Num<FromStrRadixErr = ParseIntError>
+        + FromStr<Err = ParseIntError>
+        + TryFrom<u128, Error: fmt::Display>
+        + fmt::Display;
+    #[doc(hidden)]
+    const LOWER: Self::Inner;
+    #[doc(hidden)]
+    const UPPER: Self::Inner;
+    #[doc(hidden)]
+    fn from_inner(val: Self::Inner) -> Option<Self>;
+}
+
+macro_rules! ranged_int {
+    ($($t:ident($inner:ty)),* $(,)?) => {
+        $(
+            impl<const MIN: $inner, const MAX: $inner> sealed::Sealed for $t<MIN, MAX> {}
+
+            impl<const MIN: $inner, const MAX: $inner> RangedInt for $t<MIN, MAX> {
+                type Inner = $inner;
+                const LOWER: $inner = MIN;
+                const UPPER: $inner = MAX;
+
+                fn from_inner(val: $inner) -> Option<Self> {
+                    Self::new(val)
+                }
+            }
+        )*
+    };
+}
+
+ranged_int!(
+    RangedU8(u8),
+    RangedU16(u16),
+    RangedU32(u32),
+    RangedU64(u64),
+    RangedU128(u128),
+    RangedUsize(usize),
+    RangedI8(i8),
+    RangedI16(i16),
+    RangedI32(i32),
+    RangedI64(i64),
+    RangedI128(i128),
+    RangedIsize(isize),
+);
+
+// wraps a value that has been checked against the bounds of the type
+fn wrap<T: RangedInt>(val: T::Inner) -> T {
+    T::from_inner(val).expect("value is within the bounds of the type")
+}
+
+/// Validates a base-10 value within the bounds of the type, see
+/// [`number_range`].
+///
+/// ```
+/// use deranged::RangedI8;
+///
+/// let parse = clap_num::deranged::number::<RangedI8<-10, 10>>;
+/// assert_eq!(parse("-10").map(RangedI8::get), Ok(-10));
+/// assert_eq!(parse("11"), Err(String::from("exceeds maximum of 10")));
+/// ```
+pub fn number<T: RangedInt>(s: &str) -> Result<T, String> {
+    number_range(s, T::LOWER, T::UPPER).map(wrap)
+}
+
+/// Validates a value that can be base-10 or base-16 within the bounds of the
+/// type, see [`maybe_hex_range`].
+pub fn maybe_hex<T>(s: &str) -> Result<T, String>
+where
+    T: RangedInt,
+    T::Inner: sign::Unsigned,
+{
+    maybe_hex_range(s, T::LOWER, T::UPPER).map(wrap)
+}
+
+/// Validates a value with a [metric prefix] within the bounds of the type,
+/// see [`si_number_range`].
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number<T: RangedInt>(s: &str) -> Result<T, String> {
+    si_number_range(s, T::LOWER, T::UPPER).map(wrap)
+}
@@ -0,0 +1,166 @@
+//! Formatting adapters that write values in the same notation as the
+//! parsers.
+//!
+//! Each adapter implements [`Display`], and the output is accepted by the
+//! corresponding parser, parsing to the same value.
+//! This allows help text, default values, and log output to use the same
+//! notation as the command line.
+//!
+//! | Adapter         | Example       | Parser                                 |
+//! |-----------------|---------------|----------------------------------------|
+//! | [`Grouped`]     | `1_000_000`   | [`number_range`](crate::number_range)  |
+//! | [`Hex`]         | `0xDEADBEEF`  | [`maybe_hex`](crate::maybe_hex)        |
+//! | [`Bin`]         | `0b1010`      | [`maybe_bin`](crate::maybe_bin)        |
+//! | [`Oct`]         | `0o755`       | [`maybe_oct`](crate::maybe_oct)        |
+//! | [`Si`]          | `4.7k`        | [`si_number`](crate::si_number)        |
+//! | [`Engineering`] | `4.7e3`       | [`si_number`](crate::si_number)        |
+//!
+//! The alternate flag groups the digits of [`Hex`], [`Bin`], and [`Oct`]
+//! with underscores, such as `0xDEAD_BEEF` for `{:#}`.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use clap_num::{fmt, maybe_hex};
+//!
+//! const DEFAULT_ADDRESS: u32 = 0x4000_0000;
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[clap(long, value_parser=maybe_hex::<u32>, default_value_t=DEFAULT_ADDRESS)]
+//!     address: u32,
+//! }
+//!
+//! assert_eq!(fmt::Hex(DEFAULT_ADDRESS).to_string(), "0x40000000");
+//! assert_eq!(format!("{:#}", fmt::Hex(DEFAULT_ADDRESS)), "0x4000_0000");
+//! assert_eq!(fmt::Si(4_700_000u32).to_string(), "4.7M");
+//! # let args = Args::parse_from(&["", "--address", &fmt::Hex(0x10u32).to_string()]);
+//! # assert_eq!(args.address, 0x10);
+//! ```
+
+use crate::{split_sign, SiBound};
+use core::fmt::{self, Display};
+use num_traits::{sign, PrimInt};
+
+// writes digits with an underscore between each group of `size` digits,
+// counting from the right
+fn write_grouped(f: &mut fmt::Formatter<'_>, digits: &str, size: usize) -> fmt::Result {
+    for (idx, c) in digits.chars().enumerate() {
+        if idx != 0 && (digits.len() - idx).is_multiple_of(size) {
+            f.write_str("_")?;
+        }
+        write!(f, "{c}")?;
+    }
+    Ok(())
+}
+
+/// Formats a base-10 integer with underscores between each group of three
+/// digits, such as `1_000_000`.
+///
+/// ```
+/// use clap_num::fmt::Grouped;
+///
+/// assert_eq!(Grouped(1_000_000).to_string(), "1_000_000");
+/// assert_eq!(Grouped(-12_345).to_string(), "-12_345");
+/// assert_eq!(Grouped(999).to_string(), "999");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Grouped<T>(pub T);
+
+impl<T: PrimInt + Display> Display for Grouped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimal = self.0.to_string();
+        let (negative, digits) = split_sign(&decimal);
+        if negative {
+            f.write_str("-")?;
+        }
+        write_grouped(f, digits, 3)
+    }
+}
+
+macro_rules! radix_adapter {
+    ($name:ident, $trait:ident, $spec:literal, $prefix:literal, $group:literal, $base:literal) => {
+        #[doc = concat!("Formats an unsigned integer in ", $base, " with a `", $prefix, "` prefix.")]
+        ///
+        #[doc = concat!("With the alternate flag, `{:#}`, the digits are grouped by ", $group)]
+        /// with underscores.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name<T>(pub T);
+
+        impl<T: PrimInt + sign::Unsigned + fmt::$trait> Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let digits = format!($spec, self.0);
+                f.write_str($prefix)?;
+                if f.alternate() {
+                    write_grouped(f, &digits, $group)
+                } else {
+                    f.write_str(&digits)
+                }
+            }
+        }
+    };
+}
+
+radix_adapter!(Hex, UpperHex, "{:X}", "0x", 4, "base-16");
+radix_adapter!(Bin, Binary, "{:b}", "0b", 4, "base-2");
+radix_adapter!(Oct, Octal, "{:o}", "0o", 3, "base-8");
+
+/// Formats an integer with the largest SI symbol that keeps an integer part,
+/// such as `4.7k` for 4700.
+///
+/// Values that are not a multiple of the prefix have a fractional part, such
+/// as `1.234567M`, so the value is always exact.
+///
+/// ```
+/// use clap_num::fmt::Si;
+///
+/// assert_eq!(Si(4_700).to_string(), "4.7k");
+/// assert_eq!(Si(-2_000_000).to_string(), "-2M");
+/// assert_eq!(Si(999).to_string(), "999");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Si<T>(pub T);
+
+impl<T: PrimInt + Display> Display for Si<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        SiBound(&self.0).fmt(f)
+    }
+}
+
+/// Formats an integer in engineering notation, with an exponent that is a
+/// multiple of three, such as `4.7e3` for 4700.
+///
+/// Unlike [`Si`] this is not limited by the largest SI symbol.
+///
+/// ```
+/// use clap_num::fmt::Engineering;
+///
+/// assert_eq!(Engineering(4_700).to_string(), "4.7e3");
+/// assert_eq!(Engineering(-1_500_000).to_string(), "-1.5e6");
+/// assert_eq!(Engineering(999).to_string(), "999");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Engineering<T>(pub T);
+
+impl<T: PrimInt + Display> Display for Engineering<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimal = self.0.to_string();
+        let (negative, digits) = split_sign(&decimal);
+        let exponent = (digits.len() - 1) / 3 * 3;
+        if exponent == 0 {
+            return f.write_str(&decimal);
+        }
+
+        let (int, frac) = digits.split_at(digits.len() - exponent);
+        let frac = frac.trim_end_matches('0');
+        if negative {
+            f.write_str("-")?;
+        }
+        f.write_str(int)?;
+        if !frac.is_empty() {
+            write!(f, ".{frac}")?;
+        }
+        write!(f, "e{exponent}")
+    }
+}
@@ -0,0 +1,183 @@
+use crate::number_range;
+
+/// A size with an optional offset, returned by [`GeometryParser`] and
+/// [`geometry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Geometry {
+    /// Width.
+    pub width: u32,
+    /// Height.
+    pub height: u32,
+    /// Horizontal and vertical offset, if any.
+    pub offset: Option<(i32, i32)>,
+}
+
+/// Builder for a parser of ImageMagick-style geometry, in the format of
+/// `640x480` or `640x480+10+20`.
+///
+/// The offsets are signed, such as `640x480-10+20`, and are either both
+/// present or both absent.
+/// Each field can have its own bounds, by default any `u32` size and `i32`
+/// offset is accepted.
+///
+/// # Example
+///
+/// This allows for a crop window of at most 1920x1080, with an offset
+/// within the screen.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{Geometry, GeometryParser};
+///
+/// fn crop(s: &str) -> Result<Geometry, String> {
+///     GeometryParser::new()
+///         .width(1, 1920)
+///         .height(1, 1080)
+///         .x(0, 1919)
+///         .y(0, 1079)
+///         .parse(s)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=crop)]
+///     crop: Geometry,
+/// }
+/// # let args = Args::parse_from(&["", "--crop", "640x480+10+20"]);
+/// # assert_eq!(
+/// #     args.crop,
+/// #     Geometry { width: 640, height: 480, offset: Some((10, 20)) }
+/// # );
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are not geometry will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--crop <crop>': expected geometry in the format of 640x480+10+20
+/// ```
+///
+/// Fields exceeding their limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--crop <crop>': invalid width '4000': exceeds maximum of 1920
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GeometryParser {
+    width: (u32, u32),
+    height: (u32, u32),
+    x: (i32, i32),
+    y: (i32, i32),
+}
+
+impl Default for GeometryParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GeometryParser {
+    /// Create a new parser without bounds.
+    pub const fn new() -> Self {
+        Self {
+            width: (u32::MIN, u32::MAX),
+            height: (u32::MIN, u32::MAX),
+            x: (i32::MIN, i32::MAX),
+            y: (i32::MIN, i32::MAX),
+        }
+    }
+
+    /// Sets the minimum and maximum width, inclusive.
+    #[must_use]
+    pub const fn width(mut self, min: u32, max: u32) -> Self {
+        self.width = (min, max);
+        self
+    }
+
+    /// Sets the minimum and maximum height, inclusive.
+    #[must_use]
+    pub const fn height(mut self, min: u32, max: u32) -> Self {
+        self.height = (min, max);
+        self
+    }
+
+    /// Sets the minimum and maximum horizontal offset, inclusive.
+    #[must_use]
+    pub const fn x(mut self, min: i32, max: i32) -> Self {
+        self.x = (min, max);
+        self
+    }
+
+    /// Sets the minimum and maximum vertical offset, inclusive.
+    #[must_use]
+    pub const fn y(mut self, min: i32, max: i32) -> Self {
+        self.y = (min, max);
+        self
+    }
+
+    /// Parses geometry.
+    pub fn parse(&self, s: &str) -> Result<Geometry, String> {
+        let format = || String::from("expected geometry in the format of 640x480+10+20");
+
+        let (size, offset) = s.split_at(s.find(['+', '-']).unwrap_or(s.len()));
+        let (width, height) = size.split_once(['x', 'X']).ok_or_else(format)?;
+        let width = field("width", width, self.width)?;
+        let height = field("height", height, self.height)?;
+
+        let offset = if offset.is_empty() {
+            None
+        } else {
+            // the vertical offset starts at the second sign
+            let y_start = offset[1..].find(['+', '-']).ok_or_else(format)? + 1;
+            let (x, y) = offset.split_at(y_start);
+            Some((
+                field("horizontal offset", x, self.x)?,
+                field("vertical offset", y, self.y)?,
+            ))
+        };
+
+        Ok(Geometry {
+            width,
+            height,
+            offset,
+        })
+    }
+}
+
+// parses one field of the geometry within its bounds
+fn field<T>(name: &str, s: &str, (min, max): (T, T)) -> Result<T, String>
+where
+    <T as core::str::FromStr>::Err: std::fmt::Display,
+    T: core::str::FromStr,
+    T: Ord,
+    T: std::fmt::Display,
+{
+    number_range(s, min, max).map_err(|e| format!("invalid {name} '{s}': {e}"))
+}
+
+/// Validates ImageMagick-style geometry, in the format of `640x480` or
+/// `640x480+10+20`, without bounds.
+///
+/// See [`GeometryParser`] for details and bounds.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{geometry, Geometry};
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=geometry, allow_hyphen_values=true)]
+///     window: Geometry,
+/// }
+/// # let args = Args::parse_from(&["", "--window", "800x600-10+0"]);
+/// # assert_eq!(
+/// #     args.window,
+/// #     Geometry { width: 800, height: 600, offset: Some((-10, 0)) }
+/// # );
+/// ```
+pub fn geometry(s: &str) -> Result<Geometry, String> {
+    GeometryParser::new().parse(s)
+}
@@ -0,0 +1,156 @@
+//! Parsers for half-precision floating point values from [half].
+//!
+//! This module is only available with the `half` feature.
+//!
+//! Each function is generic over [`f16`](struct@f16) and [`bf16`].
+//! Values are rounded to the nearest value of the type, values that are too
+//! large for the type are rejected instead of becoming infinite.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use half::{bf16, f16};
+//!
+//! fn threshold(s: &str) -> Result<f16, String> {
+//!     clap_num::half::float_range(s, f16::ZERO, f16::ONE)
+//! }
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[clap(long, value_parser=threshold)]
+//!     threshold: f16,
+//!     #[clap(long, value_parser=clap_num::half::si_float::<bf16>)]
+//!     scale: bf16,
+//! }
+//! # let args = Args::parse_from(&["", "--threshold", "0.25", "--scale", "1.5k"]);
+//! # assert_eq!(args.threshold, f16::from_f32(0.25));
+//! # assert_eq!(args.scale, bf16::from_f32(1500.0));
+//! ```
+//!
+//! ## Error Messages
+//!
+//! Values that are too large for the type will show an error message similar
+//! to this:
+//!
+//! ```text
+//! error: Invalid value for '--threshold <threshold>': number too large to fit in target type
+//! ```
+//!
+//! [half]: https://docs.rs/half
+
+use crate::{check_range, maybe_hex, parse_decimal, split_si_symbol};
+use crate::{INVALID_DIGIT_MSG, OVERFLOW_MSG, UNDERFLOW_MSG};
+use ::half::{bf16, f16};
+use core::fmt;
+use std::borrow::Cow;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A half-precision floating point type, [`f16`](struct@f16) or [`bf16`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfFloat: sealed::Sealed + Copy + PartialOrd + fmt::Display {
+    #[doc(hidden)]
+    fn from_f64(val: f64) -> Self;
+    #[doc(hidden)]
+    fn from_bits(bits: u16) -> Self;
+    #[doc(hidden)]
+    fn is_infinite(self) -> bool;
+}
+
+macro_rules! half_float {
+    ($t:ty) => {
+        impl sealed::Sealed for $t {}
+
+        impl HalfFloat for $t {
+            fn from_f64(val: f64) -> Self {
+                <$t>::from_f64(val)
+            }
+
+            fn from_bits(bits: u16) -> Self {
+                <$t>::from_bits(bits)
+            }
+
+            fn is_infinite(self) -> bool {
+                <$t>::is_infinite(self)
+            }
+        }
+    };
+}
+
+half_float!(f16);
+half_float!(bf16);
+
+// converts a finite value to the type, rejecting values that become infinite
+fn convert<T: HalfFloat>(val: f64) -> Result<T, String> {
+    if val.is_nan() {
+        return Err(String::from("value is not a number"));
+    }
+    let converted = T::from_f64(val);
+    if !converted.is_infinite() {
+        Ok(converted)
+    } else if val.is_sign_negative() {
+        Err(String::from(UNDERFLOW_MSG))
+    } else {
+        Err(String::from(OVERFLOW_MSG))
+    }
+}
+
+/// Validates a half-precision floating point value, such as `0.25` or
+/// `1.5e-3`.
+///
+/// Underscores can be used as digit separators.
+pub fn float<T: HalfFloat>(s: &str) -> Result<T, String> {
+    convert(parse_decimal(s)?)
+}
+
+/// Validates a half-precision floating point value within a range.
+///
+/// The value is rounded to the type before it is compared to the bounds.
+pub fn float_range<T: HalfFloat>(s: &str, min: T, max: T) -> Result<T, String> {
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    check_range(float(s)?, min, max).map_err(String::from)
+}
+
+/// Validates a half-precision floating point value with a [metric prefix],
+/// such as `1.5k` or `1k5`.
+///
+/// This accepts the same SI symbols as [`si_number`](crate::si_number), and
+/// the decimal prefixes `da`, `h`, `d`, and `c` used by quantities such as
+/// hectopascals and centimetres, such as `5h` for 500 or `25c` for 0.25.
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_float<T: HalfFloat>(s: &str) -> Result<T, String> {
+    let symbol = split_si_symbol(s, true).map(|(int, prefix, frac)| (int, prefix.exponent(), frac));
+    let (mantissa, scale) = match symbol {
+        Some((int, scale, "")) => (Cow::Borrowed(int), scale),
+        Some((int, scale, frac)) if !int.contains('.') && !frac.starts_with(['+', '-']) => {
+            (Cow::Owned(format!("{int}.{frac}")), scale)
+        }
+        Some(_) => return Err(String::from(INVALID_DIGIT_MSG)),
+        None => (Cow::Borrowed(s), 0),
+    };
+    let val = parse_decimal::<f64>(&mantissa)? * 10f64.powi(scale);
+    convert(val)
+}
+
+/// Validates a half-precision floating point value with a [metric prefix]
+/// within a range.
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_float_range<T: HalfFloat>(s: &str, min: T, max: T) -> Result<T, String> {
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    check_range(si_float(s)?, min, max).map_err(String::from)
+}
+
+/// Validates the bit pattern of a half-precision floating point value, such
+/// as `0x3C00` for 1.0 as an [`f16`](struct@f16).
+///
+/// The bits can be base-10 or base-16, as with [`maybe_hex`], and every bit
+/// pattern is accepted, including NaN and infinity.
+pub fn from_bits<T: HalfFloat>(s: &str) -> Result<T, String> {
+    maybe_hex::<u16>(s).map(T::from_bits)
+}
@@ -7,38 +7,483 @@
 //!   Validates an unsigned integer value that can be base-10 or base-16.
 //! * `maybe_hex_range`
 //!   Validates an unsigned integer value that can be base-10 or base-16 within a range.
+//! * `maybe_hex_at_least`, `maybe_hex_at_most`
+//!   Validates an unsigned integer value that can be base-10 or base-16 with a minimum or maximum.
+//! * `hex_only`
+//!   Validates an unsigned integer value that is always base-16.
+//! * `hex_only_range`
+//!   Validates an unsigned integer value that is always base-16 within a range.
+//! * `relative_offset`
+//!   Validates a signed offset that can be base-10 or base-16, with a mandatory sign.
+//! * `offset`
+//!   Validates an absolute position or a signed relative offset.
+//! * `maybe_bin`
+//!   Validates an unsigned integer value that can be base-10 or base-2.
+//! * `maybe_bin_range`
+//!   Validates an unsigned integer value that can be base-10 or base-2 within a range.
+//! * `maybe_oct`
+//!   Validates an unsigned integer value that can be base-10 or base-8.
+//! * `maybe_oct_range`
+//!   Validates an unsigned integer value that can be base-10 or base-8 within a range.
+//! * `maybe_hex_with_base`, `maybe_bin_with_base`, `maybe_oct_with_base`
+//!   Validates an unsigned integer value like `maybe_hex`, `maybe_bin`, or `maybe_oct`, and returns the base that was used.
 //! * `number_range`
 //!   Validate a signed or unsigned integer value.
+//! * `number_at_least`, `number_at_most`
+//!   Validate a signed or unsigned integer value with a minimum or maximum.
+//! * `exact_digits`, `exact_digits_range`
+//!   Validate an unsigned integer value with an exact number of digits, such as a PIN.
+//! * `digit_string`, `digit_string_range`
+//!   Validate a string of digits, such as a postal code, keeping the leading zeros.
+//! * `positive`, `non_negative`, `negative`
+//!   Validate a signed value that is greater than, at least, or less than zero.
 //! * `si_number`
 //!   Validate a signed or unsigned integer value with a metric prefix.
 //! * `si_number_range`
 //!   Validate a signed or unsigned integer value with a metric prefix within a range.
+//! * `si_range!`
+//!   Creates a parser for a range with the bounds written with a metric prefix.
+//! * `si_number_at_least`, `si_number_at_most`
+//!   Validate a signed or unsigned integer value with a metric prefix and a minimum or maximum.
+//! * `si_number_with_unit`
+//!   Validate a signed or unsigned integer value with a metric prefix and a unit.
+//! * `si_number_with_prefix`
+//!   Validate a signed or unsigned integer value like `si_number`, and returns the prefix that was used.
+//! * `mem_size`, `mem_size_range`
+//!   Validate a memory size with SI or binary units, such as `4KiB`.
+//! * `page_aligned`, `page_aligned_to`
+//!   Validate a size or address that is a multiple of the page size.
+//! * `bit_rate`, `bit_rate_range`
+//!   Validate a bit rate with units, such as `100Mbps`.
+//! * `prefixed_number`, `prefixed_number_range`
+//!   Validate a signed or unsigned integer value with a suffix from a custom table, such as `4Ki`.
+//! * `si_interval`
+//!   Validates an interval of integer values with metric prefixes, such as `10k..20k`.
+//! * `interval`
+//!   Validates an interval in mathematical notation, such as `[0,10)`.
+//! * `pair`
+//!   Validates a pair of values, such as `10:20`.
+//! * `tolerance`
+//!   Validates a nominal value with a tolerance, such as `100±5`.
+//! * `geometry`
+//!   Validates ImageMagick-style geometry, such as `640x480+10+20`.
+//! * `duration`
+//!   Validates a duration with units, such as `1h30m`.
+//! * `duration_range`
+//!   Validates a duration with units within a range.
+//! * `multiplier`, `multiplier_range`
+//!   Validates a multiplier, such as `4x` or `0.5x`.
+//! * `fraction`, `fraction_range`
+//!   Validates a fraction, such as `1/3`, or a decimal value.
+//! * `gain`
+//!   Validates a gain as a linear multiplier, such as `2x`, or in decibels,
+//!   such as `+6dB`.
+//! * `bcd`
+//!   Validates an unsigned integer value encoded as packed binary-coded decimal.
+//! * `gray_code`
+//!   Validates a Gray-coded unsigned integer value that can be base-10 or base-16.
+//! * `ipv4`
+//!   Validates an IPv4 address in dotted-quad or integer form.
+//! * `ipv4_prefix_len`, `ipv6_prefix_len`
+//!   Validates a CIDR prefix length.
+//! * `ipv4_netmask`, `ipv6_netmask`
+//!   Validates a CIDR prefix length and converts it to a netmask.
+//! * `file_mode`
+//!   Validates a chmod-style octal file permission mode.
+//! * `uuid`
+//!   Validates a UUID in hyphenated, bare, or hex integer form.
+//! * `hex_color`, `hex_color_rgba`
+//!   Validates a hex color code.
+//! * `repr_enum`
+//!   Validates an unsigned integer value that can be base-10 or base-16 and converts it to an enum.
+//! * `i2c_address`, `i2c_address_unreserved`
+//!   Validates a 7-bit I2C address.
+//! * `utc_offset`
+//!   Validates a UTC offset, such as `+05:30` or `-0800`, and converts it to minutes.
+//! * `cpu_list`, `cpu_mask`
+//!   Validates a taskset-style list of CPUs, such as `0-3,8`.
+//! * `key_value`
+//!   Validates a `NAME=VALUE` pair with a numeric value.
+//! * `named_or`
+//!   Validates a named value from a list, or any other value with another parser.
+//! * `verbatim`
+//!   Validates a value with any parser, keeping the string it was parsed from.
+//! * `parse_os`, `number_range_os`, `maybe_hex_os`, `maybe_bin_os`, `maybe_oct_os`, `si_number_os`
+//!   Validates an `OsStr` argument, rejecting arguments that are not valid UTF-8.
+//! * `wei`, `wei_range`
+//!   Validates an amount of ether in wei, such as `1.5eth` or `20gwei`.
+//! * `satoshi`, `satoshi_range`
+//!   Validates an amount of bitcoin in satoshis, such as `0.001btc` or `150000sat`.
+//!
+//! The [`presets`] module contains ready-made parsers for common bounded
+//! quantities, such as PWM duty cycles, probabilities, and DAC codes.
+//!
+//! The [`fmt`](mod@crate::fmt) module contains formatting adapters that write values in the
+//! same notation as the parsers, such as `0xDEAD_BEEF` or `4.7k`, for help text,
+//! default values, and log output.
+//!
+//! The [`raw`] module contains variants of `number_range` and `maybe_hex`
+//! returning the original `ParseIntError` or `FromStrRadixErr` instead of an
+//! error message, and the error messages, range check, and tokenization
+//! helpers of this crate for custom parsers that stay consistent with it.
+//!
+//! The [`NumberParser`] builder combines any of these parsers with optional
+//! bounds and custom error messages, and the [`ListParser`] builder parses
+//! delimited lists of these values.
+//!
+//! The [`Bounds`] type checks that the minimum of a range does not exceed the
+//! maximum in all build profiles, not only in debug builds.
+//!
+//! The [`TickParser`] builder converts durations to an integer number of ticks
+//! with a given period or frequency.
+//!
+//! The [`HexParser`] builder accepts additional prefixes for base-16 values,
+//! such as `$FF` or `#FF`, and the [`RadixParser`] builder accepts prefixes for any
+//! radix, such as `%` for base-2.
+//!
+//! The [`SiPrefix`] type lists the metric prefixes accepted by the SI parsers, for
+//! formatting values and generating documentation with the same prefixes.
+//!
+//! The [`GeometryParser`] builder validates ImageMagick-style geometry with
+//! bounds on each field, such as a maximum width.
+//!
+//! The [`UnitTable`] builder defines custom units, such as sectors or pages,
+//! for use with `prefixed_number`.
+//!
+//! The [`QuantityParser`] builder converts quantities with units, such as `12.5cm` or
+//! `3in`, to a base unit with a table of conversion factors.
+//!
+//! The `MaybeHex`, `MaybeBin`, `MaybeOct`, `HexOnly`, and `SiNumber` wrapper
+//! types implement [`FromStr`] and [`TryFrom<&str>`] with the parser of the
+//! same name, for use outside of clap, and `ValueParserFactory` with the
+//! `clap` feature.
+//!
+//! The [`Verbatim`] wrapper type keeps the string a value was parsed from, so
+//! applications can show values exactly as the user wrote them.
+//!
+//! The [`Ranged`] wrapper type has its inclusive bounds in the type, such as
+//! `Ranged<u8, 0, 99>`, so a field can use `value_parser` without a range
+//! function.
+//!
+//! The [`const_num!`] and [`const_si!`] macros parse integer constants at
+//! compile time, so default values and bounds can be written in the same
+//! notation as the command line.
+//!
+//! # Features
+//!
+//! * `bounded-integer`
+//!   Parsers for `BoundedU8` and the other bounded integer types, with the
+//!   range taken from the type, see the
+//!   [`bounded_integer`](mod@crate::bounded_integer) module.
+//! * `clap`
+//!   Implements `clap::builder::ValueParserFactory` for the wrapper types, so
+//!   `value_parser!(MaybeHex<u32>)` uses the parser of the same name.
+//! * `deranged`
+//!   Parsers for `RangedU8` and the other ranged integer types, with the range
+//!   taken from the type, see the [`deranged`](mod@crate::deranged) module.
+//! * `half`
+//!   Parsers for `f16` and `bf16` values, see the [`half`](mod@crate::half)
+//!   module.
+//! * `miette`
+//!   Implements `miette::Diagnostic` for [`ParseError`], labeling the span of
+//!   the value that caused the error.
+//! * `num-complex`
+//!   Parsers for complex numbers, such as `3+4i` or `1∠45`, see `complex`.
+//! * `primitive-types`
+//!   Parsers for 256-bit `U256` values, see the [`u256`](mod@crate::u256)
+//!   module.
+//! * `page-size`
+//!   Queries the page size of the system at runtime for `page_aligned`, see
+//!   `page_size`.
+//! * `serde`
+//!   Deserialization helpers accepting the same syntax as each parser, see the
+//!   [`serde`](mod@crate::serde) module.
+//! * `testing`
+//!   Property testing strategies, fuzzing inputs, and invariant checks for
+//!   each parser, see the [`testing`] module.
+//! * `uom`
+//!   Parsers returning typed quantities, such as lengths and frequencies, see
+//!   the [`uom`](mod@crate::uom) module.
 //!
 //! [clap]: https://github.com/clap-rs/clap
 #![deny(missing_docs)]
 
-use core::{iter, str::FromStr};
+use core::cmp::Ordering;
+use core::ops::{Bound, Range};
+use core::str::FromStr;
 use num_traits::identities::Zero;
-use num_traits::{sign, CheckedAdd, CheckedMul, CheckedSub, Num};
+use num_traits::{sign, CheckedAdd, CheckedMul, CheckedSub, Num, PrimInt};
+use std::borrow::Cow;
+use std::net::Ipv4Addr;
 
-fn check_range<T>(val: T, min: T, max: T) -> Result<T, String>
-where
-    T: FromStr,
-    <T as FromStr>::Err: std::fmt::Display,
-    T: Ord,
-    T: std::fmt::Display,
-{
+#[cfg(feature = "num-complex")]
+mod complex;
+mod constant;
+mod currency;
+pub mod fmt;
+mod geometry;
+mod list;
+mod os;
+mod parser;
+mod prefix;
+pub mod presets;
+mod quantity;
+pub mod raw;
+mod wrappers;
+
+#[cfg(feature = "num-complex")]
+pub use complex::{complex, complex_magnitude_range};
+#[doc(hidden)]
+pub use constant::{__const_num, __const_si};
+pub use currency::{satoshi, satoshi_range, wei, wei_range};
+pub use geometry::{geometry, Geometry, GeometryParser};
+pub use list::ListParser;
+pub use os::{maybe_bin_os, maybe_hex_os, maybe_oct_os, number_range_os, parse_os, si_number_os};
+use parser::ErrorMessage;
+pub use parser::{ErrorKind, Grouping, NumberParser, ParseError};
+pub use prefix::{
+    bit_rate, bit_rate_range, mem_size, mem_size_range, page_aligned_to, prefixed_number,
+    prefixed_number_range, PrefixTable, UnitTable,
+};
+#[cfg(feature = "page-size")]
+pub use prefix::{page_aligned, page_size};
+pub use quantity::QuantityParser;
+pub use wrappers::{HexOnly, MaybeBin, MaybeHex, MaybeOct, Ranged, SiNumber, Verbatim};
+
+use raw::{
+    EMPTY_MSG, INVALID_DIGIT_MSG, NEGATIVE_MSG, NOT_AN_INTEGER_MSG, OVERFLOW_MSG, UNDERFLOW_MSG,
+};
+
+#[cfg(feature = "bounded-integer")]
+pub mod bounded_integer;
+#[cfg(feature = "deranged")]
+pub mod deranged;
+#[cfg(feature = "half")]
+pub mod half;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "primitive-types")]
+pub mod u256;
+#[cfg(feature = "uom")]
+pub mod uom;
+
+// A value outside of the inclusive range.
+//
+// This holds the violated bound, which is only formatted when the error is
+// converted to a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RangeError<T> {
+    AboveMaximum(T),
+    BelowMinimum(T),
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for RangeError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AboveMaximum(max) => write!(f, "exceeds maximum of {max}"),
+            Self::BelowMinimum(min) => write!(f, "less than minimum of {min}"),
+        }
+    }
+}
+
+impl<T: std::fmt::Display> From<RangeError<T>> for String {
+    fn from(e: RangeError<T>) -> Self {
+        e.to_string()
+    }
+}
+
+impl<T> RangeError<T> {
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> RangeError<U> {
+        match self {
+            Self::AboveMaximum(max) => RangeError::AboveMaximum(f(max)),
+            Self::BelowMinimum(min) => RangeError::BelowMinimum(f(min)),
+        }
+    }
+}
+
+// An unsigned bound formatted in a base other than 10, such as `0x200`.
+//
+// The digits are converted from the decimal representation, which avoids
+// additional bounds on the range functions.
+struct RadixBound<'a, T> {
+    bound: T,
+    prefix: &'a str,
+    radix: u32,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for RadixBound<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut decimal: Vec<u32> = self
+            .bound
+            .to_string()
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .collect();
+        let mut digits: Vec<char> = Vec::new();
+
+        // repeated long division of the decimal digits by the radix
+        while !decimal.is_empty() {
+            let mut remainder = 0;
+            let mut quotient = Vec::with_capacity(decimal.len());
+            for digit in decimal {
+                let current = remainder * 10 + digit;
+                remainder = current % self.radix;
+                if !quotient.is_empty() || current / self.radix != 0 {
+                    quotient.push(current / self.radix);
+                }
+            }
+            digits.extend(char::from_digit(remainder, self.radix).map(|c| c.to_ascii_uppercase()));
+            decimal = quotient;
+        }
+
+        if digits.is_empty() {
+            digits.push('0');
+        }
+
+        f.write_str(self.prefix)?;
+        digits.iter().rev().try_for_each(|c| write!(f, "{c}"))
+    }
+}
+
+// converts a range error to a message, formatting the bound in the base of
+// the input if it has a radix prefix
+fn radix_range_error<T: std::fmt::Display>(e: RangeError<T>, s: &str, base: Base) -> String {
+    if strip_radix_prefix(s, base.symbol()).is_some() {
+        prefixed_range_error(e, base.prefix(), base.radix())
+    } else {
+        String::from(e)
+    }
+}
+
+// converts a range error to a message, formatting the bound in `radix` with
+// `prefix`, or in base-10 if the prefix is empty
+fn prefixed_range_error<T: std::fmt::Display>(
+    e: RangeError<T>,
+    prefix: &str,
+    radix: u32,
+) -> String {
+    if prefix.is_empty() {
+        String::from(e)
+    } else {
+        String::from(e.map(|bound| RadixBound {
+            bound,
+            prefix,
+            radix,
+        }))
+    }
+}
+
+fn check_range<T: PartialOrd>(val: T, min: T, max: T) -> Result<T, RangeError<T>> {
     if val > max {
-        Err(format!("exceeds maximum of {max}"))
+        Err(RangeError::AboveMaximum(max))
     } else if val < min {
-        Err(format!("less than minimum of {min}"))
+        Err(RangeError::BelowMinimum(min))
+    } else {
+        Ok(val)
+    }
+}
+
+fn check_min<T: PartialOrd>(val: T, min: T) -> Result<T, RangeError<T>> {
+    if val < min {
+        Err(RangeError::BelowMinimum(min))
+    } else {
+        Ok(val)
+    }
+}
+
+fn check_max<T: PartialOrd>(val: T, max: T) -> Result<T, RangeError<T>> {
+    if val > max {
+        Err(RangeError::AboveMaximum(max))
     } else {
         Ok(val)
     }
 }
 
+/// Inclusive bounds of a range, checked once when they are created.
+///
+/// The range functions, such as [`number_range`], only check that the
+/// minimum does not exceed the maximum in debug builds.
+/// Creating the bounds with [`Bounds::new`] reports invalid bounds in all
+/// build profiles.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::{maybe_hex, Bounds};
+///
+/// let bounds = Bounds::new(0x10, 0xFF).unwrap();
+/// assert_eq!(bounds.parse("0x20", maybe_hex::<u8>), Ok(0x20));
+/// assert_eq!(
+///     bounds.parse("0x1", maybe_hex::<u8>),
+///     Err(String::from("less than minimum of 16"))
+/// );
+///
+/// assert_eq!(
+///     Bounds::new(10, 1),
+///     Err(String::from("minimum of 10 exceeds maximum of 1"))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<T> {
+    min: T,
+    max: T,
+}
+
+impl<T: PartialOrd + std::fmt::Display> Bounds<T> {
+    /// Creates bounds from a minimum and maximum, inclusive.
+    ///
+    /// Returns an error if the minimum exceeds the maximum, or if the bounds
+    /// cannot be compared, such as NaN.
+    pub fn new(min: T, max: T) -> Result<Self, String> {
+        if min <= max {
+            Ok(Self { min, max })
+        } else {
+            Err(format!("minimum of {min} exceeds maximum of {max}"))
+        }
+    }
+
+    /// Checks that a value is within the bounds.
+    pub fn check(&self, val: T) -> Result<T, String>
+    where
+        T: Clone,
+    {
+        check_range(val, self.min.clone(), self.max.clone()).map_err(String::from)
+    }
+
+    /// Parses a value with any parser, then checks that it is within the
+    /// bounds.
+    pub fn parse<F>(&self, s: &str, parse: F) -> Result<T, String>
+    where
+        T: Clone,
+        F: Fn(&str) -> Result<T, String>,
+    {
+        self.check(parse(s)?)
+    }
+}
+
+impl<T> Bounds<T> {
+    /// Returns the minimum, inclusive.
+    pub fn min(&self) -> &T {
+        &self.min
+    }
+
+    /// Returns the maximum, inclusive.
+    pub fn max(&self) -> &T {
+        &self.max
+    }
+
+    /// Returns the minimum and maximum.
+    pub fn into_inner(self) -> (T, T) {
+        (self.min, self.max)
+    }
+}
+
 /// Validate a signed or unsigned integer value.
 ///
+/// Underscores can be used as digit separators, for example `1_000`.
+///
 /// # Arguments
 ///
 /// * `s` - String to parse.
@@ -103,319 +548,4881 @@ where
     T: std::fmt::Display,
 {
     debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
-    let val = s.parse::<T>().map_err(stringify)?;
-    check_range(val, min, max)
-}
-
-static OVERFLOW_MSG: &str = "number too large to fit in target type";
-
-// helper for mapping errors to strings
-fn stringify<T: std::fmt::Display>(e: T) -> String {
-    format!("{e}")
+    let val = parse_decimal(s)?;
+    check_range(val, min, max).map_err(String::from)
 }
 
-#[derive(Copy, Clone)]
-enum SiPrefix {
-    Yotta,
-    Zetta,
-    Exa,
-    Peta,
-    Tera,
-    Giga,
-    Mega,
-    Kilo,
+// parses a base-10 integer with optional underscore separators
+fn parse_decimal<T>(s: &str) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+{
+    strip_underscores(s)
+        .parse::<T>()
+        .map_err(stringify)
+        .map_err(|e| {
+            reject_negative(s, e, |m| {
+                strip_underscores(m).parse::<T>().map_err(stringify)
+            })
+        })
+        .map_err(|e| reject_radix(s, e, None))
 }
 
-impl SiPrefix {
-    fn from_char(symbol: char) -> Option<Self> {
-        match symbol {
-            'Y' => Some(Self::Yotta),
-            'Z' => Some(Self::Zetta),
-            'E' => Some(Self::Exa),
-            'P' => Some(Self::Peta),
-            'T' => Some(Self::Tera),
-            'G' => Some(Self::Giga),
-            'M' => Some(Self::Mega),
-            'k' | 'K' => Some(Self::Kilo),
-            _ => None,
+// replaces the error for a negative value with a friendlier message if the
+// magnitude has valid digits, whatever its size, which only happens for
+// unsigned types
+fn reject_negative<V>(s: &str, e: String, parse: impl FnOnce(&str) -> Result<V, String>) -> String {
+    match s.strip_prefix('-') {
+        Some(magnitude)
+            if e != UNDERFLOW_MSG
+                && !magnitude.starts_with(['+', '-'])
+                && parse(magnitude).map_or_else(|e| e == OVERFLOW_MSG, |_| true) =>
+        {
+            String::from(NEGATIVE_MSG)
         }
+        _ => e,
     }
+}
 
-    fn multiplier(&self) -> u128 {
-        match self {
-            SiPrefix::Yotta => 1_000_000_000_000_000_000_000_000,
-            SiPrefix::Zetta => 1_000_000_000_000_000_000_000,
-            SiPrefix::Exa => 1_000_000_000_000_000_000,
-            SiPrefix::Peta => 1_000_000_000_000_000,
-            SiPrefix::Tera => 1_000_000_000_000,
-            SiPrefix::Giga => 1_000_000_000,
-            SiPrefix::Mega => 1_000_000,
-            SiPrefix::Kilo => 1_000,
+// radix prefix symbols, with the radix and the name of the base
+const RADIXES: [(char, u32, &str); 3] = [
+    ('x', 16, "hexadecimal"),
+    ('b', 2, "binary"),
+    ('o', 8, "octal"),
+];
+
+// replaces the error for a value with a radix prefix that the parser does not
+// accept, such as `0b1010` passed to a parser of decimal and hex values, with
+// a message naming the accepted formats
+fn reject_radix(s: &str, e: String, accepted: Option<char>) -> String {
+    let (_, magnitude) = split_sign(s);
+
+    for (symbol, radix, name) in RADIXES {
+        if Some(symbol) == accepted {
+            continue;
         }
-    }
 
-    fn digits(&self) -> usize {
-        match self {
-            SiPrefix::Yotta => 24,
-            SiPrefix::Zetta => 21,
-            SiPrefix::Exa => 18,
-            SiPrefix::Peta => 15,
-            SiPrefix::Tera => 12,
-            SiPrefix::Giga => 9,
-            SiPrefix::Mega => 6,
-            SiPrefix::Kilo => 3,
+        let Some(digits) = strip_radix_prefix(magnitude, symbol) else {
+            continue;
+        };
+
+        if digits.chars().any(|c| c.is_digit(radix))
+            && digits.chars().all(|c| c == '_' || c.is_digit(radix))
+        {
+            let prefix = &magnitude[..2];
+            let expected = RADIXES
+                .iter()
+                .find(|(symbol, _, _)| Some(*symbol) == accepted)
+                .map(|(symbol, _, name)| format!("decimal or {name} with '0{symbol}'"))
+                .unwrap_or_else(|| String::from("decimal"));
+            return format!("{name} prefix '{prefix}' is not accepted, expected {expected}");
         }
     }
-}
 
-fn parse_post<T>(mut post: String, digits: usize) -> Result<T, String>
-where
-    <T as FromStr>::Err: std::fmt::Display,
-    T: PartialOrd + FromStr,
-{
-    if let Some(zeros) = digits.checked_sub(post.len()) {
-        post.extend(iter::repeat('0').take(zeros));
-        post.parse::<T>().map_err(stringify)
-    } else {
-        Err(String::from("not an integer"))
-    }
+    e
 }
 
-/// Validate a signed or unsigned integer value with a [metric prefix].
-///
-/// This can accept strings with the (case sensitive) SI symbols.
+/// Validate a signed or unsigned integer value with a minimum.
 ///
-/// | Symbol | Name  | Value                             |
-/// |--------|-------|-----------------------------------|
-/// | Y      | yotta | 1_000_000_000_000_000_000_000_000 |
-/// | Z      | zetta | 1_000_000_000_000_000_000_000     |
-/// | E      | exa   | 1_000_000_000_000_000_000         |
-/// | P      | peta  | 1_000_000_000_000_000             |
-/// | T      | tera  | 1_000_000_000_000                 |
-/// | G      | giga  | 1_000_000_000                     |
-/// | M      | mega  | 1_000_000                         |
-/// | k      | kilo  | 1_000                             |
+/// This is [`number_range`] without a maximum, values too large for the
+/// target type are rejected as overflow.
 ///
-/// The strings can be provided with a decimal, or using the SI symbol as the
-/// decimal separator.
+/// # Arguments
 ///
-/// | String | Value     |
-/// |--------|-----------|
-/// | 3k3    | 3300      |
-/// | 3.3k   | 3300      |
-/// | 1M     | 1_000_000 |
+/// * `s` - String to parse.
+/// * `min` - Minimum value, inclusive.
 ///
 /// # Example
 ///
-/// This allows for resistance value to be passed using SI symbols.
+/// This allows for a non-zero number of retries.
 ///
 /// ```
 /// use clap::Parser;
-/// use clap_num::si_number;
+/// use clap_num::number_at_least;
+///
+/// fn at_least_1(s: &str) -> Result<u32, String> {
+///     number_at_least(s, 1)
+/// }
 ///
 /// #[derive(Parser)]
 /// struct Args {
-///     #[clap(short, long, value_parser=si_number::<u128>)]
-///     resistance: u128,
+///     #[clap(long, value_parser=at_least_1)]
+///     retries: u32,
 /// }
-/// # let args = Args::parse_from(&["", "--resistance", "1M1"]);
-/// # assert_eq!(args.resistance, 1_100_000);
+/// # let args = Args::parse_from(&["", "--retries", "3"]);
+/// # assert_eq!(args.retries, 3);
 /// ```
 ///
-/// To run this example use `cargo run --example resistance`, giving arguments
-/// to the program after `--`, for example:
+/// ## Error Messages
+///
+/// Values below the minimum will show an error message similar to this:
 ///
 /// ```text
-/// $ cargo run --example resistance -- --resistance 1M1
-/// Resistance: 1100000 ohms
+/// error: Invalid value for '--retries <retries>': less than minimum of 1
 /// ```
-///
-/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
-pub fn si_number<T>(s: &str) -> Result<T, String>
+pub fn number_at_least<T>(s: &str, min: T) -> Result<T, String>
 where
-    <T as TryFrom<u128>>::Error: std::fmt::Display,
-    <T as FromStr>::Err: std::fmt::Display,
-    T: CheckedAdd,
-    T: CheckedMul,
-    T: CheckedSub,
     T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
     T: PartialOrd,
-    T: TryFrom<u128>,
-    T: Zero,
+    T: std::fmt::Display,
 {
-    // contains SI symbol
-    if let Some(si_prefix_index) = s.find(|c| SiPrefix::from_char(c).is_some()) {
-        let si_prefix = SiPrefix::from_char(s.as_bytes()[si_prefix_index] as char).unwrap();
-        let multiplier: T = T::try_from(si_prefix.multiplier()).map_err(|_| OVERFLOW_MSG)?;
-
-        let (pre_si, post_si) = s.split_at(si_prefix_index);
-        let post_si = &post_si[1..];
-
-        if pre_si.is_empty() {
-            return Err("no value found before SI symbol".to_string());
-        }
-
-        // in the format of "1k234" for 1_234
-        let (pre, post) = if !post_si.is_empty() {
-            (
-                pre_si.parse::<T>().map_err(stringify)?,
-                parse_post(post_si.to_string(), si_prefix.digits())?,
-            )
-
-        // in the format of "1.234k" for 1_234
-        } else if let Some((pre_dec, post_dec)) = s.split_once('.') {
-            let mut post_dec: String = post_dec.to_string();
-            post_dec.pop(); // remove SI symbol
-            let post_dec = parse_post(post_dec, si_prefix.digits())?;
-            (pre_dec.parse::<T>().map_err(stringify)?, post_dec)
-
-        // no decimal
-        } else {
-            (pre_si.parse::<T>().map_err(stringify)?, T::zero())
-        };
-
-        let pre = pre.checked_mul(&multiplier).ok_or(OVERFLOW_MSG)?;
-
-        if pre >= T::zero() {
-            pre.checked_add(&post)
-        } else {
-            pre.checked_sub(&post)
-        }
-        .ok_or_else(|| OVERFLOW_MSG.to_string())
-    } else {
-        // no SI symbol, parse normally
-        s.chars()
-            .filter(|&c| c != '_')
-            .collect::<String>()
-            .parse::<T>()
-            .map_err(stringify)
-    }
+    let val = parse_decimal(s)?;
+    check_min(val, min).map_err(String::from)
 }
 
-/// Validate a signed or unsigned integer value with a [metric prefix] within
-/// a range.
+/// Validate a signed or unsigned integer value with a maximum.
 ///
-/// This combines [`si_number`] and [`number_range`], see the
-/// documentation for those functions for details.
+/// This is [`number_range`] without a minimum, values too small for the
+/// target type are rejected as underflow.
 ///
-/// # Example
+/// # Arguments
 ///
-/// This extends the example in [`si_number`], and only allows a range of
-/// resistances from 1k to 999.999k.
+/// * `s` - String to parse.
+/// * `max` - Maximum value, inclusive.
+///
+/// # Example
 ///
 /// ```
 /// use clap::Parser;
-/// use clap_num::si_number_range;
+/// use clap_num::number_at_most;
 ///
-/// fn kilo(s: &str) -> Result<u32, String> {
-///     si_number_range(s, 1_000, 999_999)
+/// fn at_most_10(s: &str) -> Result<i8, String> {
+///     number_at_most(s, 10)
 /// }
 ///
 /// #[derive(Parser)]
 /// struct Args {
-///     #[clap(short, long, value_parser=kilo)]
-///     resistance: u32,
+///     #[clap(long, value_parser=at_most_10, allow_hyphen_values=true)]
+///     offset: i8,
 /// }
-/// # let args = Args::parse_from(&["", "--resistance", "999k999"]);
-/// # assert_eq!(args.resistance, 999_999);
+/// # let args = Args::parse_from(&["", "--offset", "-128"]);
+/// # assert_eq!(args.offset, -128);
 /// ```
 ///
-/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
-pub fn si_number_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+/// ## Error Messages
+///
+/// Values above the maximum will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--offset <offset>': exceeds maximum of 10
+/// ```
+pub fn number_at_most<T>(s: &str, max: T) -> Result<T, String>
 where
-    <T as TryFrom<u128>>::Error: std::fmt::Display,
-    <T as FromStr>::Err: std::fmt::Display,
-    T: CheckedAdd,
-    T: CheckedMul,
-    T: CheckedSub,
     T: FromStr,
-    T: PartialOrd,
-    T: TryFrom<u128>,
-    T: Zero,
-    T: Ord,
+    <T as FromStr>::Err: std::fmt::Display,
     T: PartialOrd,
     T: std::fmt::Display,
 {
-    let val = si_number(s)?;
-    check_range(val, min, max)
+    let val = parse_decimal(s)?;
+    check_max(val, max).map_err(String::from)
 }
 
-/// Validates an unsigned integer value that can be base-10 or base-16.
+// parses a decimal value with a sign in `allowed`, relative to zero
+fn parse_with_sign<T>(s: &str, allowed: &[Ordering], msg: &str) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: sign::Signed,
+    T: PartialOrd,
+{
+    let val: T = parse_decimal(s)?;
+    match val.partial_cmp(&T::zero()) {
+        Some(ordering) if allowed.contains(&ordering) => Ok(val),
+        _ => Err(String::from(msg)),
+    }
+}
+
+/// Validate a signed value that is greater than zero.
 ///
-/// The number is assumed to be base-10 by default, it is parsed as hex if the
-/// number is prefixed with `0x`, case insensitive.
+/// This accepts the same values as [`number_range`], and also works for
+/// floating point types.
 ///
 /// # Example
 ///
-/// This allows base-10 addresses to be passed normally, or base-16 values to
-/// be passed when prefixed with `0x`.
+/// ```
+/// use clap::Parser;
+/// use clap_num::positive;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=positive::<i32>)]
+///     step: i32,
+/// }
+/// # let args = Args::parse_from(&["", "--step", "1"]);
+/// # assert_eq!(args.step, 1);
+/// # assert_eq!(positive::<i32>("0"), Err(String::from("value must be positive")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are zero or negative will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--step <step>': value must be positive
+/// ```
+pub fn positive<T>(s: &str) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: sign::Signed,
+    T: PartialOrd,
+{
+    parse_with_sign(s, &[Ordering::Greater], "value must be positive")
+}
+
+/// Validate a signed value that is zero or greater.
+///
+/// This accepts the same values as [`number_range`], and also works for
+/// floating point types.
+///
+/// # Example
 ///
 /// ```
 /// use clap::Parser;
-/// use clap_num::maybe_hex;
+/// use clap_num::non_negative;
 ///
 /// #[derive(Parser)]
 /// struct Args {
-///     #[clap(short, long, value_parser=maybe_hex::<u32>)]
-///     address: u32,
+///     #[clap(long, value_parser=non_negative::<i64>)]
+///     delay: i64,
 /// }
-/// # let args = Args::parse_from(&["", "-a", "0x10"]);
-/// # assert_eq!(args.address, 16);
+/// # let args = Args::parse_from(&["", "--delay", "0"]);
+/// # assert_eq!(args.delay, 0);
+/// # assert_eq!(non_negative::<i64>("-1"), Err(String::from("value cannot be negative")));
 /// ```
-pub fn maybe_hex<T: Num + sign::Unsigned>(s: &str) -> Result<T, String>
+///
+/// ## Error Messages
+///
+/// Values that are negative will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--delay <delay>': value cannot be negative
+/// ```
+pub fn non_negative<T>(s: &str) -> Result<T, String>
 where
-    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: sign::Signed,
+    T: PartialOrd,
 {
-    const HEX_PREFIX: &str = "0x";
-    const HEX_PREFIX_UPPER: &str = "0X";
-    const HEX_PREFIX_LEN: usize = HEX_PREFIX.len();
+    parse_with_sign(s, &[Ordering::Greater, Ordering::Equal], NEGATIVE_MSG)
+}
 
-    let result = if s.starts_with(HEX_PREFIX) || s.starts_with(HEX_PREFIX_UPPER) {
-        T::from_str_radix(&s[HEX_PREFIX_LEN..], 16)
-    } else {
-        T::from_str_radix(s, 10)
-    };
+/// Validate a signed value that is less than zero.
+///
+/// This accepts the same values as [`number_range`], and also works for
+/// floating point types.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::negative;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=negative::<f32>, allow_hyphen_values=true)]
+///     threshold: f32,
+/// }
+/// # let args = Args::parse_from(&["", "--threshold", "-3.5"]);
+/// # assert_eq!(args.threshold, -3.5);
+/// # assert_eq!(negative::<f32>("0"), Err(String::from("value must be negative")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are zero or positive will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--threshold <threshold>': value must be negative
+/// ```
+pub fn negative<T>(s: &str) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: sign::Signed,
+    T: PartialOrd,
+{
+    parse_with_sign(s, &[Ordering::Less], "value must be negative")
+}
 
-    result.map_err(stringify)
+/// Validate an unsigned integer value with exactly `count` base-10 digits,
+/// such as a 4-digit PIN or a 6-digit one-time password.
+///
+/// Leading zeros count towards the number of digits, `0042` has 4 digits.
+/// Signs and underscores are not accepted.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::exact_digits;
+///
+/// fn pin(s: &str) -> Result<u16, String> {
+///     exact_digits(s, 4)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=pin)]
+///     pin: u16,
+/// }
+/// # let args = Args::parse_from(&["", "--pin", "0042"]);
+/// # assert_eq!(args.pin, 42);
+/// # assert_eq!(pin("42"), Err(String::from("expected 4 digits, got 2")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with a different number of digits will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--pin <pin>': expected 4 digits, got 2
+/// ```
+pub fn exact_digits<T>(s: &str, count: usize) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: sign::Unsigned,
+{
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        // keep the more specific errors for signs and radix prefixes
+        return Err(parse_decimal::<T>(s)
+            .err()
+            .unwrap_or_else(|| String::from(INVALID_DIGIT_MSG)));
+    }
+
+    if s.len() != count {
+        let unit = if count == 1 { "digit" } else { "digits" };
+        return Err(format!("expected {count} {unit}, got {}", s.len()));
+    }
+
+    parse_decimal(s)
 }
 
-/// Validates an unsigned integer value that can be base-10 or base-16 within
-/// a range.
+/// Validate an unsigned integer value with exactly `count` base-10 digits
+/// within a range.
 ///
-/// This combines [`maybe_hex`] and [`number_range`], see the
+/// This combines [`exact_digits`] and [`number_range`], see the
 /// documentation for those functions for details.
+/// The number of digits is checked before the range.
 ///
 /// # Example
 ///
-/// This extends the example in [`maybe_hex`], and only allows a range of
-/// addresses from `0x100` to `0x200`.
+/// This allows for a 3-digit area code that does not start with 0 or 1.
 ///
 /// ```
-/// use clap::Parser;
-/// use clap_num::maybe_hex_range;
+/// use clap_num::exact_digits_range;
 ///
-/// fn address_in_range(s: &str) -> Result<u32, String> {
-///     maybe_hex_range(s, 0x100, 0x200)
+/// fn area_code(s: &str) -> Result<u16, String> {
+///     exact_digits_range(s, 3, 200, 999)
 /// }
+/// # assert_eq!(area_code("212"), Ok(212));
+/// # assert_eq!(area_code("012"), Err(String::from("less than minimum of 200")));
+/// # assert_eq!(area_code("2120"), Err(String::from("expected 3 digits, got 4")));
+/// ```
+pub fn exact_digits_range<T>(s: &str, count: usize, min: T, max: T) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: sign::Unsigned,
+    T: Ord,
+    T: std::fmt::Display,
+{
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let val = exact_digits(s, count)?;
+    check_range(val, min, max).map_err(String::from)
+}
+
+/// Validate a string of base-10 digits, keeping the string along with the
+/// value.
+///
+/// This is for numbers where the formatting matters, such as account numbers
+/// or postal codes, where the leading zeros of `02134` must be kept.
+/// The string is returned as the `input` of the [`Verbatim`], and the
+/// integer as the `value`.
+///
+/// Signs and underscores are not accepted.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{digit_string, Verbatim};
 ///
 /// #[derive(Parser)]
 /// struct Args {
-///     #[clap(short, long, value_parser=address_in_range)]
-///     address: u32,
+///     #[clap(long, value_parser=digit_string::<u64>)]
+///     account: Verbatim<u64>,
 /// }
-/// # let args = Args::parse_from(&["", "-a", "300"]);
-/// # assert_eq!(args.address, 300);
+/// # let args = Args::parse_from(&["", "--account", "000123"]);
+/// # assert_eq!(args.account.input, "000123");
+/// # assert_eq!(args.account.value, 123);
 /// ```
-pub fn maybe_hex_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+pub fn digit_string<T>(s: &str) -> Result<Verbatim<T>, String>
 where
-    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: FromStr,
     <T as FromStr>::Err: std::fmt::Display,
+    T: sign::Unsigned,
+{
+    verbatim(s, |s| exact_digits(s, s.len()))
+}
+
+/// Validate a string of base-10 digits within a range, keeping the string
+/// along with the value.
+///
+/// This combines [`digit_string`] and [`number_range`], see the
+/// documentation for those functions for details.
+/// Use [`exact_digits_range`] to also check the number of digits.
+///
+/// # Example
+///
+/// This allows for a US ZIP code, keeping the leading zeros.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{digit_string_range, Verbatim};
+///
+/// fn zip_code(s: &str) -> Result<Verbatim<u32>, String> {
+///     digit_string_range(s, 501, 99_950)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=zip_code)]
+///     zip: Verbatim<u32>,
+/// }
+/// # let args = Args::parse_from(&["", "--zip", "02134"]);
+/// # assert_eq!(args.zip.input, "02134");
+/// # assert_eq!(args.zip.value, 2_134);
+/// # assert_eq!(zip_code("00400"), Err(String::from("less than minimum of 501")));
+/// ```
+pub fn digit_string_range<T>(s: &str, min: T, max: T) -> Result<Verbatim<T>, String>
+where
     T: FromStr,
-    T: std::fmt::Display,
-    T: Ord,
-    T: Num,
+    <T as FromStr>::Err: std::fmt::Display,
     T: sign::Unsigned,
+    T: Ord,
+    T: std::fmt::Display,
 {
-    let val = maybe_hex(s)?;
-    check_range(val, min, max)
+    verbatim(s, |s| exact_digits_range(s, s.len(), min, max))
+}
+
+// removes underscore separators, only allocating if there are separators
+fn strip_underscores(s: &str) -> Cow<'_, str> {
+    if s.contains('_') {
+        Cow::Owned(s.replace('_', ""))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+// helper for mapping errors to strings
+fn stringify<T: std::fmt::Display>(e: T) -> String {
+    format!("{e}")
+}
+
+/// A metric prefix accepted by [`si_number`] and the other SI parsers, such
+/// as `k` for kilo.
+///
+/// This allows applications to use the same prefixes as the parsers for
+/// formatting values and generating documentation.
+///
+/// The decimal prefixes, from [`Hecto`](Self::Hecto) to
+/// [`Centi`](Self::Centi), are only accepted with
+/// [`SiParser::decimal_prefixes`] and by `half::si_float`.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::SiPrefix;
+///
+/// let prefix: SiPrefix = "M".parse().unwrap();
+/// assert_eq!(prefix, SiPrefix::Mega);
+/// assert_eq!(prefix.multiplier(), Some(1_000_000));
+/// assert_eq!(prefix.to_string(), "M");
+///
+/// let symbols: Vec<&str> = SiPrefix::ALL.iter().map(|p| p.symbol()).collect();
+/// assert_eq!(symbols, ["Y", "Z", "E", "P", "T", "G", "M", "k"]);
+///
+/// let symbols: Vec<&str> = SiPrefix::DECIMAL.iter().map(|p| p.symbol()).collect();
+/// assert_eq!(symbols, ["h", "da", "d", "c"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SiPrefix {
+    /// 10<sup>24</sup>, `Y`.
+    Yotta,
+    /// 10<sup>21</sup>, `Z`.
+    Zetta,
+    /// 10<sup>18</sup>, `E`.
+    Exa,
+    /// 10<sup>15</sup>, `P`.
+    Peta,
+    /// 10<sup>12</sup>, `T`.
+    Tera,
+    /// 10<sup>9</sup>, `G`.
+    Giga,
+    /// 10<sup>6</sup>, `M`.
+    Mega,
+    /// 10<sup>3</sup>, `k`.
+    Kilo,
+    /// 10<sup>2</sup>, `h`.
+    Hecto,
+    /// 10<sup>1</sup>, `da`.
+    Deca,
+    /// 10<sup>-1</sup>, `d`.
+    Deci,
+    /// 10<sup>-2</sup>, `c`.
+    Centi,
+}
+
+impl SiPrefix {
+    /// The prefixes accepted by [`si_number`], from the largest to the
+    /// smallest.
+    pub const ALL: [SiPrefix; 8] = [
+        SiPrefix::Yotta,
+        SiPrefix::Zetta,
+        SiPrefix::Exa,
+        SiPrefix::Peta,
+        SiPrefix::Tera,
+        SiPrefix::Giga,
+        SiPrefix::Mega,
+        SiPrefix::Kilo,
+    ];
+
+    /// The decimal prefixes accepted with [`SiParser::decimal_prefixes`],
+    /// from the largest to the smallest.
+    pub const DECIMAL: [SiPrefix; 4] = [
+        SiPrefix::Hecto,
+        SiPrefix::Deca,
+        SiPrefix::Deci,
+        SiPrefix::Centi,
+    ];
+
+    // the prefixes accepted by a parser, with the decimal prefixes if enabled
+    fn accepted(decimal: bool) -> impl Iterator<Item = Self> {
+        let decimal_prefixes: &[Self] = if decimal { &Self::DECIMAL } else { &[] };
+        Self::ALL
+            .into_iter()
+            .chain(decimal_prefixes.iter().copied())
+    }
+
+    // returns the prefix whose symbol starts a string, `da` is listed before
+    // `d` so that it is matched first
+    fn from_symbol(s: &str, decimal: bool) -> Option<Self> {
+        if s.starts_with('K') {
+            return Some(Self::Kilo);
+        }
+        Self::accepted(decimal).find(|prefix| s.starts_with(prefix.symbol()))
+    }
+
+    fn from_name(name: &str, decimal: bool) -> Option<Self> {
+        Self::accepted(decimal).find(|prefix| prefix.name().eq_ignore_ascii_case(name))
+    }
+
+    // the value of the prefix as a power of 1024, for `SiParser::binary`, or
+    // `None` for the decimal prefixes
+    fn binary_multiplier(self) -> Option<u128> {
+        let exponent = u32::try_from(self.exponent()).ok().filter(|&e| e >= 3)?;
+        Some(1 << (exponent / 3 * 10))
+    }
+
+    /// Returns the power of ten of the prefix, such as `3` for
+    /// [`Kilo`](Self::Kilo), or `-2` for [`Centi`](Self::Centi).
+    pub fn exponent(self) -> i32 {
+        match self {
+            SiPrefix::Yotta => 24,
+            SiPrefix::Zetta => 21,
+            SiPrefix::Exa => 18,
+            SiPrefix::Peta => 15,
+            SiPrefix::Tera => 12,
+            SiPrefix::Giga => 9,
+            SiPrefix::Mega => 6,
+            SiPrefix::Kilo => 3,
+            SiPrefix::Hecto => 2,
+            SiPrefix::Deca => 1,
+            SiPrefix::Deci => -1,
+            SiPrefix::Centi => -2,
+        }
+    }
+
+    /// Returns the value of the prefix, such as `1000` for
+    /// [`Kilo`](Self::Kilo), or `None` for [`Deci`](Self::Deci) and
+    /// [`Centi`](Self::Centi), which are less than one.
+    pub fn multiplier(self) -> Option<u128> {
+        u32::try_from(self.exponent()).ok().map(|e| 10u128.pow(e))
+    }
+
+    /// Returns the symbol of the prefix, such as `k` for
+    /// [`Kilo`](Self::Kilo).
+    pub fn symbol(self) -> &'static str {
+        match self {
+            SiPrefix::Yotta => "Y",
+            SiPrefix::Zetta => "Z",
+            SiPrefix::Exa => "E",
+            SiPrefix::Peta => "P",
+            SiPrefix::Tera => "T",
+            SiPrefix::Giga => "G",
+            SiPrefix::Mega => "M",
+            SiPrefix::Kilo => "k",
+            SiPrefix::Hecto => "h",
+            SiPrefix::Deca => "da",
+            SiPrefix::Deci => "d",
+            SiPrefix::Centi => "c",
+        }
+    }
+
+    /// Returns the name of the prefix, such as `kilo` for
+    /// [`Kilo`](Self::Kilo).
+    pub fn name(self) -> &'static str {
+        match self {
+            SiPrefix::Yotta => "yotta",
+            SiPrefix::Zetta => "zetta",
+            SiPrefix::Exa => "exa",
+            SiPrefix::Peta => "peta",
+            SiPrefix::Tera => "tera",
+            SiPrefix::Giga => "giga",
+            SiPrefix::Mega => "mega",
+            SiPrefix::Kilo => "kilo",
+            SiPrefix::Hecto => "hecto",
+            SiPrefix::Deca => "deca",
+            SiPrefix::Deci => "deci",
+            SiPrefix::Centi => "centi",
+        }
+    }
+}
+
+// Error for an SI symbol that could be a power of 1000 or of 1024, naming the
+// explicit units of a size in bytes, such as "use kB (1000) or KiB (1024)",
+// or `None` for the decimal prefixes, which have no binary value.
+fn ambiguous_unit_error(si_prefix: SiPrefix) -> Option<String> {
+    Some(format!(
+        "ambiguous unit: use {}B ({}) or {}iB ({})",
+        si_prefix.symbol(),
+        si_prefix.multiplier()?,
+        si_prefix.symbol().to_ascii_uppercase(),
+        si_prefix.binary_multiplier()?
+    ))
+}
+
+/// Formats the symbol of the prefix, such as `k`.
+impl std::fmt::Display for SiPrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// Parses a symbol, such as `k`, or a name, such as `kilo` (case
+/// insensitive), with the same symbols as the SI parsers, including the
+/// decimal prefixes.
+impl FromStr for SiPrefix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let symbol = Self::from_symbol(s, true).filter(|p| p.symbol().len() == s.len());
+        symbol.or_else(|| Self::from_name(s, true)).ok_or_else(|| {
+            let symbols: Vec<String> = Self::accepted(true).map(|p| p.to_string()).collect();
+            format!(
+                "unknown SI prefix '{s}', expected one of {}",
+                symbols.join(", ")
+            )
+        })
+    }
+}
+
+// A bound formatted with the largest SI symbol that keeps an integer part,
+// such as `999.999k`.
+struct SiBound<T>(T);
+
+impl<T: std::fmt::Display> std::fmt::Display for SiBound<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decimal = self.0.to_string();
+        let (negative, digits) = split_sign(&decimal);
+
+        match SiPrefix::ALL
+            .iter()
+            .find(|p| digits.len() > p.exponent() as usize)
+        {
+            Some(prefix) => {
+                let (int, frac) = digits.split_at(digits.len() - prefix.exponent() as usize);
+                let frac = frac.trim_end_matches('0');
+                if negative {
+                    f.write_str("-")?;
+                }
+                f.write_str(int)?;
+                if !frac.is_empty() {
+                    write!(f, ".{frac}")?;
+                }
+                write!(f, "{}", prefix.symbol())
+            }
+            None => f.write_str(&decimal),
+        }
+    }
+}
+
+// SI symbols with binary values, for the bounds of values parsed with
+// `SiParser::binary`
+const BINARY_SI_PREFIXES: [(&str, u128); 8] = [
+    ("k", 1 << 10),
+    ("M", 1 << 20),
+    ("G", 1 << 30),
+    ("T", 1 << 40),
+    ("P", 1 << 50),
+    ("E", 1 << 60),
+    ("Z", 1 << 70),
+    ("Y", 1 << 80),
+];
+
+// converts a range error to a message, formatting the bound with an SI symbol
+// if the input has one
+fn si_range_error<T: std::fmt::Display>(e: RangeError<T>, s: &str, si: bool) -> String {
+    if si {
+        String::from(e.map(SiBound))
+    } else {
+        append_si_hint(s, e.to_string())
+    }
+}
+
+// appends the SI notation of a long plain decimal value to an error message,
+// such as "(4000000000 can be written as 4G)", if it is much shorter
+fn append_si_hint(s: &str, e: String) -> String {
+    const MIN_DIGITS: usize = 7;
+    const MIN_SAVED: usize = 4;
+
+    let (_, digits) = split_sign(s);
+    if digits.len() < MIN_DIGITS
+        || digits.starts_with('0')
+        || !digits.bytes().all(|b| b.is_ascii_digit())
+    {
+        return e;
+    }
+
+    let si = SiBound(s).to_string();
+    if si.len() + MIN_SAVED <= s.len() {
+        format!("{e} ({s} can be written as {si})")
+    } else {
+        e
+    }
+}
+
+// splits a string at the first SI symbol, with the decimal prefixes if
+// enabled, returning the value before and the digits after the symbol
+fn split_si_symbol(s: &str, decimal: bool) -> Option<(&str, SiPrefix, &str)> {
+    s.char_indices().find_map(|(idx, _)| {
+        let si_prefix = SiPrefix::from_symbol(&s[idx..], decimal)?;
+        Some((&s[..idx], si_prefix, &s[idx + si_prefix.symbol().len()..]))
+    })
+}
+
+// splits a string ending in a spelled-out SI prefix name
+fn split_si_name(s: &str, decimal: bool) -> Option<(&str, SiPrefix, &str)> {
+    let pre_si = s.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let si_prefix = SiPrefix::from_name(&s[pre_si.len()..], decimal)?;
+    Some((pre_si, si_prefix, ""))
+}
+
+// splits the sign from a number, returning true if the number is negative
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+// splits the exponent from a number in the format of "1.5e3"
+fn split_exponent(s: &str) -> Result<(&str, i32), String> {
+    match s.split_once('e') {
+        Some((mantissa, exponent)) => exponent
+            .parse::<i32>()
+            .map(|exponent| (mantissa, exponent))
+            .map_err(|_| String::from(INVALID_DIGIT_MSG)),
+        None => Ok((s, 0)),
+    }
+}
+
+// Converts the decimal number `int.frac * 10^scale` to an integer, where
+// `int` may be signed.
+//
+// Digits are accumulated directly into `T` with overflow checks, underscores
+// are ignored, and digits that fall after the decimal point once scaled are
+// either rejected or rounded.
+fn scale_decimal<T>(
+    int: &str,
+    frac: &str,
+    scale: i64,
+    rounding: Option<Rounding>,
+) -> Result<T, String>
+where
+    T: CheckedAdd + CheckedMul + CheckedSub + TryFrom<u128> + Zero,
+{
+    let (negative, unsigned) = split_sign(int);
+    let signed: bool = unsigned.len() != int.len();
+    let int = unsigned;
+
+    let digits = || {
+        int.bytes()
+            .chain(frac.bytes())
+            .filter(|&b| b != b'_')
+            .enumerate()
+    };
+    let int_len: i64 = int.bytes().filter(|&b| b != b'_').count() as i64;
+    let keep: i64 = int_len.saturating_add(scale);
+
+    let mut invalid: bool = false;
+    let mut remainder: bool = false;
+    let mut first_dropped: u8 = b'0';
+    let mut total: i64 = 0;
+    for (idx, b) in digits() {
+        let idx = idx as i64;
+        invalid |= !b.is_ascii_digit();
+        if idx >= keep {
+            remainder |= b != b'0';
+            if idx == keep {
+                first_dropped = b;
+            }
+        }
+        total = idx + 1;
+    }
+
+    if remainder && rounding.is_none() {
+        return Err(String::from(NOT_AN_INTEGER_MSG));
+    } else if invalid {
+        return Err(String::from(INVALID_DIGIT_MSG));
+    } else if total == 0 {
+        return Err(String::from(if signed {
+            INVALID_DIGIT_MSG
+        } else {
+            EMPTY_MSG
+        }));
+    }
+
+    let constant = |n: u128| T::try_from(n).map_err(|_| OVERFLOW_MSG.to_string());
+    let one: T = constant(1)?;
+    let ten: T = constant(10)?;
+
+    if negative && T::zero().checked_sub(&one).is_none() {
+        return Err(String::from(NEGATIVE_MSG));
+    }
+
+    let overflow = || {
+        String::from(if negative {
+            UNDERFLOW_MSG
+        } else {
+            OVERFLOW_MSG
+        })
+    };
+    let accumulate = |acc: T, digit: T| -> Result<T, String> {
+        let acc = acc.checked_mul(&ten).ok_or_else(overflow)?;
+        if negative {
+            acc.checked_sub(&digit)
+        } else {
+            acc.checked_add(&digit)
+        }
+        .ok_or_else(overflow)
+    };
+
+    let mut acc: T = T::zero();
+    for (idx, b) in digits() {
+        if idx as i64 >= keep {
+            break;
+        }
+        acc = accumulate(acc, constant(u128::from(b - b'0'))?)?;
+    }
+
+    // pad with zeros when the scale extends beyond the provided digits
+    if !acc.is_zero() {
+        for _ in total..keep {
+            acc = accumulate(acc, T::zero())?;
+        }
+    }
+
+    let round_up: bool = match rounding {
+        _ if !remainder => false,
+        Some(Rounding::Nearest) => first_dropped >= b'5',
+        Some(Rounding::Floor) => negative,
+        Some(Rounding::Ceil) => !negative,
+        None => false,
+    };
+
+    if round_up {
+        if negative {
+            acc.checked_sub(&one)
+        } else {
+            acc.checked_add(&one)
+        }
+        .ok_or_else(overflow)
+    } else {
+        Ok(acc)
+    }
+}
+
+// Converts the decimal number `int.frac * 10^exponent * multiplier` to an
+// integer, where `int` may be signed.
+//
+// This is used for multipliers that are not a power of ten, such as 1024.
+fn scale_binary<T>(
+    int: &str,
+    frac: &str,
+    exponent: i64,
+    multiplier: u128,
+    rounding: Option<Rounding>,
+) -> Result<T, String>
+where
+    T: CheckedSub + TryFrom<u128> + Zero,
+{
+    let (negative, unsigned) = split_sign(int);
+    if unsigned.len() != int.len() && unsigned.is_empty() && frac.is_empty() {
+        return Err(String::from(INVALID_DIGIT_MSG));
+    }
+
+    let overflow = || {
+        String::from(if negative {
+            UNDERFLOW_MSG
+        } else {
+            OVERFLOW_MSG
+        })
+    };
+    let pow10 = |exp: i64| {
+        u32::try_from(exp)
+            .ok()
+            .and_then(|exp| 10u128.checked_pow(exp))
+    };
+
+    // the digits without the decimal point, then scaled by the power of ten
+    // of the exponent less the digits of the fraction
+    let frac_digits = frac.bytes().filter(|&b| b != b'_').count() as i64;
+    let digits: u128 = scale_decimal(unsigned, frac, frac_digits, None).map_err(|e| {
+        if e == OVERFLOW_MSG {
+            overflow()
+        } else {
+            e
+        }
+    })?;
+    let scaled = digits.checked_mul(multiplier).ok_or_else(overflow)?;
+    let power = exponent.saturating_sub(frac_digits);
+
+    let magnitude = if scaled == 0 {
+        0
+    } else if power >= 0 {
+        pow10(power)
+            .and_then(|m| scaled.checked_mul(m))
+            .ok_or_else(overflow)?
+    } else {
+        let divisor = pow10(power.saturating_neg());
+        let (quotient, remainder) = match divisor {
+            Some(divisor) => (scaled / divisor, scaled % divisor),
+            None => (0, scaled),
+        };
+        let round_up: bool = match rounding {
+            _ if remainder == 0 => false,
+            Some(Rounding::Nearest) => divisor.is_some_and(|d| remainder >= d - remainder),
+            Some(Rounding::Floor) => negative,
+            Some(Rounding::Ceil) => !negative,
+            None => return Err(String::from(NOT_AN_INTEGER_MSG)),
+        };
+        quotient + u128::from(round_up)
+    };
+
+    prefix::apply_sign(negative, magnitude)
+}
+
+/// Validate a signed or unsigned integer value with a [metric prefix].
+///
+/// This can accept strings with the (case sensitive) SI symbols.
+///
+/// | Symbol | Name  | Value                             |
+/// |--------|-------|-----------------------------------|
+/// | Y      | yotta | 1_000_000_000_000_000_000_000_000 |
+/// | Z      | zetta | 1_000_000_000_000_000_000_000     |
+/// | E      | exa   | 1_000_000_000_000_000_000         |
+/// | P      | peta  | 1_000_000_000_000_000             |
+/// | T      | tera  | 1_000_000_000_000                 |
+/// | G      | giga  | 1_000_000_000                     |
+/// | M      | mega  | 1_000_000                         |
+/// | k      | kilo  | 1_000                             |
+///
+/// The strings can be provided with a decimal, or using the SI symbol as the
+/// decimal separator.
+///
+/// | String | Value     |
+/// |--------|-----------|
+/// | 3k3    | 3300      |
+/// | 3.3k   | 3300      |
+/// | 1M     | 1_000_000 |
+///
+/// Values can also use exponent notation, with or without an SI symbol.
+/// The exponent must use a lowercase `e`, because `E` is the symbol for exa.
+///
+/// | String | Value     |
+/// |--------|-----------|
+/// | 2e6    | 2_000_000 |
+/// | 1.5e3k | 1_500_000 |
+/// | 15e-1k | 1_500     |
+///
+/// # Example
+///
+/// This allows for resistance value to be passed using SI symbols.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::si_number;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=si_number::<u128>)]
+///     resistance: u128,
+/// }
+/// # let args = Args::parse_from(&["", "--resistance", "1M1"]);
+/// # assert_eq!(args.resistance, 1_100_000);
+/// ```
+///
+/// To run this example use `cargo run --example resistance`, giving arguments
+/// to the program after `--`, for example:
+///
+/// ```text
+/// $ cargo run --example resistance -- --resistance 1M1
+/// Resistance: 1100000 ohms
+/// ```
+///
+/// Values with more decimal places than the SI symbol allows are rejected,
+/// use [`SiParser::rounding`] to round them instead.
+///
+/// When a long value without an SI symbol is outside the bounds given to
+/// [`si_number_range`], the error suggests the shorter SI notation:
+///
+/// ```text
+/// error: Invalid value for '--resistance <resistance>': exceeds maximum of 999999 (1000000 can be written as 1M)
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number<T>(s: &str) -> Result<T, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    SiParser::new().parse(s)
+}
+
+/// Validate a signed or unsigned integer value with a [metric prefix], and
+/// return the prefix that was used, if any.
+///
+/// This accepts the same values as [`si_number`], the prefix allows
+/// applications to show the value back to the user in the same magnitude,
+/// or to warn when a value was expected to have a prefix.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::{si_number_with_prefix, SiPrefix};
+///
+/// assert_eq!(
+///     si_number_with_prefix::<u32>("3k3"),
+///     Ok((3_300, Some(SiPrefix::Kilo)))
+/// );
+/// assert_eq!(si_number_with_prefix::<u32>("3300"), Ok((3_300, None)));
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number_with_prefix<T>(s: &str) -> Result<(T, Option<SiPrefix>), String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    SiParser::new().parse_with_prefix(s)
+}
+
+/// Validate a signed or unsigned integer value with a [metric prefix] within
+/// a range.
+///
+/// This combines [`si_number`] and [`number_range`], see the
+/// documentation for those functions for details.
+///
+/// # Example
+///
+/// This extends the example in [`si_number`], and only allows a range of
+/// resistances from 1k to 999.999k.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::si_number_range;
+///
+/// fn kilo(s: &str) -> Result<u32, String> {
+///     si_number_range(s, 1_000, 999_999)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=kilo)]
+///     resistance: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--resistance", "999k999"]);
+/// # assert_eq!(args.resistance, 999_999);
+/// # assert_eq!(kilo("1M"), Err(String::from("exceeds maximum of 999.999k")));
+/// # assert_eq!(kilo("999"), Err(String::from("less than minimum of 1000")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown with an SI symbol if the value has one, values
+/// exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--resistance <resistance>': exceeds maximum of 999.999k
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Ord,
+    T: PartialOrd,
+    T: std::fmt::Display,
+{
+    let val = si_number(s)?;
+    check_range(val, min, max)
+        .map_err(|e| si_range_error(e, s, split_si_symbol(s, false).is_some()))
+}
+
+/// Creates a parser like [`si_number_range`] with the bounds written with a
+/// [metric prefix], such as `si_range!(u64, 1k..=10M)`.
+///
+/// The bounds are parsed at compile time with [`const_si!`], the same grammar
+/// as the values on the command line, which keeps large bounds readable where
+/// they are defined.
+/// The macro expands to a closure that can be passed to `value_parser`.
+///
+/// # Example
+///
+/// This allows for a sample rate from 1k to 10M.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::si_range;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=si_range!(u64, 1k..=10M))]
+///     rate: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--rate", "44k1"]);
+/// # assert_eq!(args.rate, 44_100);
+/// # assert!(Args::try_parse_from(&["", "--rate", "20M"]).is_err());
+/// ```
+///
+/// Bounds that are not a valid value of the type fail to compile:
+///
+/// ```compile_fail
+/// let percent = clap_num::si_range!(u8, 0..=300);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--rate <rate>': exceeds maximum of 10M
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+#[macro_export]
+macro_rules! si_range {
+    ($t:ty, $min:literal ..= $max:literal) => {
+        |s: &str| -> ::core::result::Result<$t, ::std::string::String> {
+            const MIN: $t = $crate::const_si!($t, stringify!($min));
+            const MAX: $t = $crate::const_si!($t, stringify!($max));
+            $crate::si_number_range(s, MIN, MAX)
+        }
+    };
+}
+
+/// Validate a signed or unsigned integer value with a [metric prefix] and a
+/// minimum.
+///
+/// This is [`si_number_range`] without a maximum.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::si_number_at_least;
+///
+/// fn at_least_1k(s: &str) -> Result<u64, String> {
+///     si_number_at_least(s, 1_000)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=at_least_1k)]
+///     buffer: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--buffer", "4M"]);
+/// # assert_eq!(args.buffer, 4_000_000);
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number_at_least<T>(s: &str, min: T) -> Result<T, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: std::fmt::Display,
+{
+    let val = si_number(s)?;
+    check_min(val, min).map_err(|e| si_range_error(e, s, split_si_symbol(s, false).is_some()))
+}
+
+/// Validate a signed or unsigned integer value with a [metric prefix] and a
+/// maximum.
+///
+/// This is [`si_number_range`] without a minimum.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::si_number_at_most;
+///
+/// fn at_most_1g(s: &str) -> Result<u64, String> {
+///     si_number_at_most(s, 1_000_000_000)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=at_most_1g)]
+///     rate: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--rate", "100M"]);
+/// # assert_eq!(args.rate, 100_000_000);
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number_at_most<T>(s: &str, max: T) -> Result<T, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: std::fmt::Display,
+{
+    let val = si_number(s)?;
+    check_max(val, max).map_err(|e| si_range_error(e, s, split_si_symbol(s, false).is_some()))
+}
+
+/// Rounding policy for values with more precision than the target type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest integer, with halfway values rounded away from
+    /// zero.
+    Nearest,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+}
+
+/// Configurable parser for signed or unsigned integer values with a
+/// [metric prefix].
+///
+/// The default configuration is identical to [`si_number`].
+///
+/// # Example
+///
+/// This rounds over-precise resistances to the nearest ohm instead of
+/// rejecting them.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{Rounding, SiParser};
+///
+/// const SI: SiParser = SiParser::new().rounding(Rounding::Nearest);
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=|s: &str| SI.parse::<u32>(s))]
+///     resistance: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--resistance", "1.2345k"]);
+/// # assert_eq!(args.resistance, 1_235);
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SiParser {
+    rounding: Option<Rounding>,
+    whitespace: bool,
+    names: bool,
+    unit: Option<&'static str>,
+    unit_required: bool,
+    binary: bool,
+    ambiguous: bool,
+    decimal: bool,
+}
+
+impl SiParser {
+    /// Create a new parser with the default configuration.
+    pub const fn new() -> Self {
+        Self {
+            rounding: None,
+            whitespace: false,
+            names: false,
+            unit: None,
+            unit_required: false,
+            binary: false,
+            ambiguous: false,
+            decimal: false,
+        }
+    }
+
+    /// Round values with more decimal places than the SI symbol allows,
+    /// instead of rejecting them with "not an integer".
+    ///
+    /// | String   | Nearest | Floor | Ceil  |
+    /// |----------|---------|-------|-------|
+    /// | 1.2345k  | 1235    | 1234  | 1235  |
+    /// | 1k2344   | 1234    | 1234  | 1235  |
+    /// | -1.2345k | -1235   | -1235 | -1234 |
+    #[must_use]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Allow whitespace between the numeric value and the SI symbol.
+    ///
+    /// This accepts values as they are written in prose and datasheets, such
+    /// as `10 k` or `3.3 M`.
+    #[must_use]
+    pub const fn allow_whitespace(mut self) -> Self {
+        self.whitespace = true;
+        self
+    }
+
+    /// Allow spelled-out prefix names, such as `kilo` or `mega`, in addition
+    /// to the SI symbols.
+    ///
+    /// Prefix names are case insensitive and must follow the numeric value,
+    /// for example `1kilo` or `2.5Mega`.
+    /// Combine this with [`allow_whitespace`](Self::allow_whitespace) to
+    /// accept values such as `2 mega`.
+    #[must_use]
+    pub const fn allow_names(mut self) -> Self {
+        self.names = true;
+        self
+    }
+
+    /// Require the unit after the value and SI symbol, such as `Hz` in
+    /// `10kHz`.
+    ///
+    /// See [`si_number_with_unit`] for details.
+    #[must_use]
+    pub const fn unit(mut self, unit: &'static str) -> Self {
+        self.unit = Some(unit);
+        self.unit_required = true;
+        self
+    }
+
+    /// Permit the unit after the value and SI symbol, such as `Hz` in
+    /// `10kHz`, without requiring it.
+    ///
+    /// Values with a different unit are rejected the same way as with
+    /// [`unit`](Self::unit).
+    #[must_use]
+    pub const fn optional_unit(mut self, unit: &'static str) -> Self {
+        self.unit = Some(unit);
+        self.unit_required = false;
+        self
+    }
+
+    /// Multiply by powers of 1024 instead of powers of 1000, such as 1024 for
+    /// `k` and 1_048_576 for `M`.
+    ///
+    /// This is the traditional meaning of the SI symbols for tools such as
+    /// `dd`, where users expect `1k` to be 1024 bytes.
+    /// Values without an SI symbol, including exponents such as `2e6`, are not
+    /// affected.
+    ///
+    /// | String | Value     |
+    /// |--------|-----------|
+    /// | 4k     | 4096      |
+    /// | 1.5M   | 1_572_864 |
+    /// | 1k5    | 1536      |
+    ///
+    /// Range errors show the bounds with binary values for the SI symbols,
+    /// such as `64M` for 67_108_864.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap::Parser;
+    /// use clap_num::SiParser;
+    ///
+    /// const BLOCK_SIZE: SiParser = SiParser::new().binary();
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     #[clap(long, value_parser=|s: &str| BLOCK_SIZE.parse::<u64>(s))]
+    ///     bs: u64,
+    /// }
+    /// # let args = Args::parse_from(&["", "--bs", "4k"]);
+    /// # assert_eq!(args.bs, 4096);
+    /// ```
+    #[must_use]
+    pub const fn binary(mut self) -> Self {
+        self.binary = true;
+        self
+    }
+
+    /// Reject values with an SI symbol, such as `4k`, instead of picking
+    /// between powers of 1000 and powers of 1024 for them.
+    ///
+    /// This is intended for sizes where users must be explicit about the
+    /// convention, such as with the `kB` and `KiB` units of [`mem_size`].
+    /// Values without an SI symbol, including exponents such as `4e3`, are
+    /// accepted, and this takes precedence over [`binary`](Self::binary).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::SiParser;
+    ///
+    /// const SIZE: SiParser = SiParser::new().reject_ambiguous();
+    ///
+    /// assert_eq!(SIZE.parse::<u64>("4096"), Ok(4096));
+    /// assert_eq!(
+    ///     SIZE.parse::<u64>("4k"),
+    ///     Err(String::from("ambiguous unit: use kB (1000) or KiB (1024)"))
+    /// );
+    /// ```
+    ///
+    /// ## Error Messages
+    ///
+    /// Values with an SI symbol will show an error message similar to this:
+    ///
+    /// ```text
+    /// error: Invalid value for '--size <size>': ambiguous unit: use MB (1000000) or MiB (1048576)
+    /// ```
+    #[must_use]
+    pub const fn reject_ambiguous(mut self) -> Self {
+        self.ambiguous = true;
+        self
+    }
+
+    /// Allow the decimal prefixes `da`, `h`, `d`, and `c`, in addition to the
+    /// SI symbols.
+    ///
+    /// These are excluded by default because `d` and `h` are also common
+    /// units, such as days and hours.
+    /// Values with `d` or `c` are only accepted if the result is an integer,
+    /// unless [`rounding`](Self::rounding) is set.
+    ///
+    /// | String | Value |
+    /// |--------|-------|
+    /// | 5da    | 50    |
+    /// | 10h    | 1000  |
+    /// | 1h5    | 150   |
+    /// | 200c   | 2     |
+    ///
+    /// These prefixes are powers of ten even with [`binary`](Self::binary),
+    /// and are not rejected by [`reject_ambiguous`](Self::reject_ambiguous).
+    /// With [`allow_names`](Self::allow_names) they can also be spelled out, such as
+    /// `5hecto`.
+    ///
+    /// # Example
+    ///
+    /// This allows for a pressure in pascals or hectopascals.
+    ///
+    /// ```
+    /// use clap::Parser;
+    /// use clap_num::SiParser;
+    ///
+    /// const PRESSURE: SiParser = SiParser::new().decimal_prefixes().optional_unit("Pa");
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     #[clap(long, value_parser=|s: &str| PRESSURE.parse::<u32>(s))]
+    ///     pressure: u32,
+    /// }
+    /// # let args = Args::parse_from(&["", "--pressure", "1013.25hPa"]);
+    /// # assert_eq!(args.pressure, 101_325);
+    /// # assert_eq!(PRESSURE.parse::<u32>("101k"), Ok(101_000));
+    /// ```
+    #[must_use]
+    pub const fn decimal_prefixes(mut self) -> Self {
+        self.decimal = true;
+        self
+    }
+
+    /// Validate a signed or unsigned integer value with a metric prefix.
+    ///
+    /// See [`si_number`] for details on the accepted syntax.
+    pub fn parse<T>(&self, s: &str) -> Result<T, String>
+    where
+        <T as TryFrom<u128>>::Error: std::fmt::Display,
+        <T as FromStr>::Err: std::fmt::Display,
+        T: CheckedAdd,
+        T: CheckedMul,
+        T: CheckedSub,
+        T: FromStr,
+        T: PartialOrd,
+        T: TryFrom<u128>,
+        T: Zero,
+    {
+        self.parse_value(self.strip_unit(s)?)
+    }
+
+    // removes the unit from a value, if the parser has one
+    fn strip_unit<'a>(&self, s: &'a str) -> Result<&'a str, String> {
+        match self.unit {
+            Some(unit) => strip_unit(
+                s,
+                unit,
+                self.unit_required,
+                self.whitespace,
+                self.names,
+                self.decimal,
+            ),
+            None => Ok(s),
+        }
+    }
+
+    // Parses a value, returning an error with the span of the invalid digit
+    // of the value, if any.
+    pub(crate) fn try_parse<T>(&self, s: &str) -> Result<T, ErrorMessage>
+    where
+        <T as TryFrom<u128>>::Error: std::fmt::Display,
+        <T as FromStr>::Err: std::fmt::Display,
+        T: CheckedAdd,
+        T: CheckedMul,
+        T: CheckedSub,
+        T: FromStr,
+        T: PartialOrd,
+        T: TryFrom<u128>,
+        T: Zero,
+    {
+        // the value is at the start of the string, so spans in the value are
+        // spans in the string
+        let value = self.strip_unit(s)?;
+        self.parse_value(value).map_err(|e| {
+            let e = ErrorMessage::from(e);
+            match self.invalid_char(value) {
+                Some(span) if e.kind() == ErrorKind::InvalidDigit => e.at(span),
+                _ => e,
+            }
+        })
+    }
+
+    // Returns the span of the first character of a value that cannot be part
+    // of a value with a metric prefix, such as `q` in `1.5q`.
+    //
+    // Characters that are only invalid in their position, such as the second
+    // SI symbol in `1k5k`, are not found.
+    fn invalid_char(&self, s: &str) -> Option<Range<usize>> {
+        let mut prev: Option<char> = None;
+        s.char_indices()
+            .find(|&(i, c)| {
+                let valid = c.is_ascii_digit()
+                    || matches!(c, '_' | '.' | 'e')
+                    || (matches!(c, '+' | '-') && (i == 0 || prev == Some('e')))
+                    || c == 'K'
+                    || SiPrefix::accepted(self.decimal).any(|p| p.symbol().contains(c))
+                    || (self.names && c.is_ascii_alphabetic())
+                    || (self.whitespace && c.is_whitespace());
+                prev = Some(c);
+                !valid
+            })
+            .map(|(i, c)| i..i + c.len_utf8())
+    }
+
+    // parses a value without a unit
+    fn parse_value<T>(&self, s: &str) -> Result<T, String>
+    where
+        <T as TryFrom<u128>>::Error: std::fmt::Display,
+        <T as FromStr>::Err: std::fmt::Display,
+        T: CheckedAdd,
+        T: CheckedMul,
+        T: CheckedSub,
+        T: FromStr,
+        T: PartialOrd,
+        T: TryFrom<u128>,
+        T: Zero,
+    {
+        // contains SI symbol or name
+        if let Some((pre_si, si_prefix, post_si)) = self.split_prefix(s) {
+            if self.ambiguous {
+                if let Some(e) = ambiguous_unit_error(si_prefix) {
+                    return Err(e);
+                }
+            }
+
+            let (int, frac, exponent) = self.split_mantissa(pre_si, post_si)?;
+
+            // the decimal prefixes are powers of ten even with `binary`
+            match si_prefix.binary_multiplier().filter(|_| self.binary) {
+                Some(multiplier) => scale_binary(int, frac, exponent, multiplier, self.rounding),
+                None => {
+                    let digits = i64::from(si_prefix.exponent());
+                    scale_decimal(int, frac, digits + exponent, self.rounding)
+                }
+            }
+        } else if s.contains('e') {
+            // exponent without an SI symbol, in the format of "2e6"
+            let (mantissa, exponent) = split_exponent(s)?;
+            let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+            scale_decimal(int, frac, i64::from(exponent), self.rounding)
+        } else {
+            // no SI symbol, parse normally
+            scale_decimal(s, "", 0, self.rounding)
+        }
+    }
+
+    // splits a value around an SI symbol into the integer part, the
+    // fractional part, and the exponent
+    fn split_mantissa<'a>(
+        &self,
+        pre_si: &'a str,
+        post_si: &'a str,
+    ) -> Result<(&'a str, &'a str, i64), String> {
+        let pre_si = if self.whitespace {
+            pre_si.trim_end()
+        } else {
+            pre_si
+        };
+
+        if pre_si.is_empty() {
+            return Err("no value found before SI symbol".to_string());
+        }
+
+        if !post_si.is_empty() {
+            // in the format of "1k234" for 1_234
+            Ok((pre_si, post_si, 0))
+        } else {
+            // in the format of "1.234k" for 1_234, or "1.234e3k" for
+            // 1_234_000
+            let (mantissa, exponent) = split_exponent(pre_si)?;
+            let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+            Ok((int, frac, i64::from(exponent)))
+        }
+    }
+
+    /// Validate a signed or unsigned integer value with a metric prefix, and
+    /// return the prefix that was used, if any.
+    ///
+    /// See [`si_number_with_prefix`] for details.
+    pub fn parse_with_prefix<T>(&self, s: &str) -> Result<(T, Option<SiPrefix>), String>
+    where
+        <T as TryFrom<u128>>::Error: std::fmt::Display,
+        <T as FromStr>::Err: std::fmt::Display,
+        T: CheckedAdd,
+        T: CheckedMul,
+        T: CheckedSub,
+        T: FromStr,
+        T: PartialOrd,
+        T: TryFrom<u128>,
+        T: Zero,
+    {
+        let s = self.strip_unit(s)?;
+        Ok((self.parse_value(s)?, self.prefix(s)))
+    }
+
+    // splits a value at the SI symbol, or the name if enabled
+    fn split_prefix<'a>(&self, s: &'a str) -> Option<(&'a str, SiPrefix, &'a str)> {
+        let token = if self.names {
+            split_si_name(s, self.decimal)
+        } else {
+            None
+        };
+        token.or_else(|| split_si_symbol(s, self.decimal))
+    }
+
+    // returns the SI symbol of the value, or the name if enabled
+    fn prefix(&self, s: &str) -> Option<SiPrefix> {
+        self.split_prefix(s).map(|(_, si_prefix, _)| si_prefix)
+    }
+
+    // returns true if the value has an SI symbol, or a name if enabled
+    fn has_prefix(&self, s: &str) -> bool {
+        self.prefix(s).is_some()
+    }
+
+    /// Validate a signed or unsigned integer value with a metric prefix within
+    /// a range.
+    ///
+    /// See [`si_number_range`] for details.
+    pub fn parse_range<T>(&self, s: &str, min: T, max: T) -> Result<T, String>
+    where
+        <T as TryFrom<u128>>::Error: std::fmt::Display,
+        <T as FromStr>::Err: std::fmt::Display,
+        T: CheckedAdd,
+        T: CheckedMul,
+        T: CheckedSub,
+        T: FromStr,
+        T: PartialOrd,
+        T: TryFrom<u128>,
+        T: Zero,
+        T: Ord,
+        T: std::fmt::Display,
+    {
+        let s = self.strip_unit(s)?;
+        let val = self.parse_value(s)?;
+        check_range(val, min, max).map_err(|e| {
+            if !self.binary {
+                si_range_error(e, s, self.has_prefix(s))
+            } else if self.has_prefix(s) {
+                let table = &BINARY_SI_PREFIXES;
+                String::from(e.map(|bound| prefix::PrefixBound { bound, table }))
+            } else {
+                String::from(e)
+            }
+        })
+    }
+}
+
+// Removes a unit from the end of a value, such as "Hz" from "10kHz".
+//
+// Trailing letters that are not the unit, the SI symbol, or a prefix name
+// are reported as a different unit.
+fn strip_unit<'a>(
+    s: &'a str,
+    unit: &str,
+    required: bool,
+    whitespace: bool,
+    names: bool,
+    decimal: bool,
+) -> Result<&'a str, String> {
+    if let Some(value) = s.strip_suffix(unit) {
+        return Ok(if whitespace { value.trim_end() } else { value });
+    }
+
+    let suffix = &s[s.trim_end_matches(char::is_alphabetic).len()..];
+    let other = match split_si_symbol(suffix, decimal) {
+        Some(("", _, post_si)) => post_si,
+        _ => suffix,
+    };
+    let is_name = names && SiPrefix::from_name(suffix, decimal).is_some();
+
+    if !other.is_empty() && !is_name {
+        Err(format!("unexpected unit '{other}', expected '{unit}'"))
+    } else if required {
+        Err(format!("missing unit '{unit}'"))
+    } else {
+        Ok(s)
+    }
+}
+
+/// Validate a signed or unsigned integer value with a [metric prefix] and a
+/// unit, such as `10kHz`.
+///
+/// The value accepts the same formats as [`si_number`], and must be followed
+/// by the unit.
+/// Values with a different unit, such as `10kB` for a frequency, are
+/// rejected instead of having the unit ignored.
+///
+/// Use [`SiParser::unit`] to combine this with other options, or
+/// [`SiParser::optional_unit`] to permit values without the unit.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::si_number_with_unit;
+///
+/// fn hertz(s: &str) -> Result<u32, String> {
+///     si_number_with_unit(s, "Hz")
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=hertz)]
+///     frequency: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--frequency", "2.4kHz"]);
+/// # assert_eq!(args.frequency, 2_400);
+/// # assert_eq!(hertz("10kB"), Err(String::from("unexpected unit 'B', expected 'Hz'")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with a different unit will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--frequency <frequency>': unexpected unit 'B', expected 'Hz'
+/// ```
+///
+/// Values without a unit will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--frequency <frequency>': missing unit 'Hz'
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number_with_unit<T>(s: &str, unit: &str) -> Result<T, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    si_number(strip_unit(s, unit, true, false, false, false)?)
+}
+
+/// Validates an interval of signed or unsigned integer values with a
+/// [metric prefix], in the format of `10k..20k`, within a range.
+///
+/// Both endpoints accept the same formats as [`si_number`], and are
+/// inclusive.
+///
+/// # Arguments
+///
+/// * `s` - String to parse.
+/// * `min` - Minimum value of either endpoint, inclusive.
+/// * `max` - Maximum value of either endpoint, inclusive.
+///
+/// # Example
+///
+/// This allows for a frequency sweep between 20Hz and 20kHz.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::si_interval;
+/// use std::ops::RangeInclusive;
+///
+/// fn sweep(s: &str) -> Result<RangeInclusive<u32>, String> {
+///     si_interval(s, 20, 20_000)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=sweep)]
+///     sweep: RangeInclusive<u32>,
+/// }
+/// # let args = Args::parse_from(&["", "--sweep", "100..1.5k"]);
+/// # assert_eq!(args.sweep, 100..=1_500);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are not intervals will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--sweep <sweep>': expected interval in the format of 10k..20k
+/// ```
+///
+/// Intervals with a start after the end will show an error message similar
+/// to this:
+///
+/// ```text
+/// error: Invalid value for '--sweep <sweep>': start of 2000 exceeds end of 1000
+/// ```
+///
+/// Endpoints exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--sweep <sweep>': exceeds maximum of 20k
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_interval<T>(s: &str, min: T, max: T) -> Result<core::ops::RangeInclusive<T>, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Ord,
+    T: Clone,
+    T: std::fmt::Display,
+{
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let (start, end) = s
+        .split_once("..")
+        .ok_or_else(|| String::from("expected interval in the format of 10k..20k"))?;
+
+    let start = si_number_range(start, min.clone(), max.clone())?;
+    let end = si_number_range(end, min, max)?;
+
+    if start > end {
+        Err(format!("start of {start} exceeds end of {end}"))
+    } else {
+        Ok(start..=end)
+    }
+}
+
+/// Validates an interval in mathematical notation, such as `[0,10)`,
+/// parsing the bounds with `parse`.
+///
+/// A square bracket includes the bound, and a parenthesis excludes it.
+/// An exclusive bound can be infinite, written as `-inf` or `inf`, which
+/// leaves that side of the interval unbounded.
+/// Whitespace around the bounds is ignored.
+///
+/// | String      | Interval           |
+/// |-------------|--------------------|
+/// | `[0,10]`    | 0 ≤ x ≤ 10         |
+/// | `[0,10)`    | 0 ≤ x < 10         |
+/// | `(0, 10]`   | 0 < x ≤ 10         |
+/// | `(-inf,10)` | x < 10             |
+///
+/// The bounds are returned as a pair of [`Bound`], which
+/// implements [`RangeBounds`](core::ops::RangeBounds).
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{interval, number_range};
+/// use std::ops::{Bound, RangeBounds};
+///
+/// type Interval = (Bound<i32>, Bound<i32>);
+///
+/// fn window(s: &str) -> Result<Interval, String> {
+///     interval(s, |b| number_range(b, -100, 100))
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=window, allow_hyphen_values=true)]
+///     window: Interval,
+/// }
+/// # let args = Args::parse_from(&["", "--window", "[0,10)"]);
+/// # assert_eq!(args.window, (Bound::Included(0), Bound::Excluded(10)));
+/// # assert!(args.window.contains(&0));
+/// # assert!(!args.window.contains(&10));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are not intervals will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--window <window>': expected interval in the format of [0,10)
+/// ```
+///
+/// Intervals that do not contain any values will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--window <window>': interval [10,0] is empty
+/// ```
+pub fn interval<T, F>(s: &str, parse: F) -> Result<(Bound<T>, Bound<T>), String>
+where
+    F: Fn(&str) -> Result<T, String>,
+    T: PartialOrd,
+{
+    let format = || String::from("expected interval in the format of [0,10)");
+
+    let (lower_inclusive, upper_inclusive) = match s.as_bytes() {
+        [first @ (b'[' | b'('), .., last @ (b']' | b')')] => (*first == b'[', *last == b']'),
+        _ => return Err(format()),
+    };
+    let (lower, upper) = s[1..s.len() - 1].split_once(',').ok_or_else(format)?;
+
+    let bound = |value: &str, inclusive: bool, infinity: &[&str]| {
+        let value = value.trim();
+        if infinity.contains(&value) {
+            if inclusive {
+                Err(format!("infinite bound '{value}' must be exclusive"))
+            } else {
+                Ok(Bound::Unbounded)
+            }
+        } else if inclusive {
+            parse(value).map(Bound::Included)
+        } else {
+            parse(value).map(Bound::Excluded)
+        }
+    };
+    let lower = bound(lower, lower_inclusive, &["-inf", "-∞"])?;
+    let upper = bound(upper, upper_inclusive, &["inf", "+inf", "∞", "+∞"])?;
+
+    let empty = match (&lower, &upper) {
+        (Bound::Included(l), Bound::Included(u)) => l > u,
+        (Bound::Included(l) | Bound::Excluded(l), Bound::Included(u) | Bound::Excluded(u)) => {
+            l >= u
+        }
+        _ => false,
+    };
+    if empty {
+        Err(format!("interval {s} is empty"))
+    } else {
+        Ok((lower, upper))
+    }
+}
+
+/// Validates a pair of values in the format of `LOW:HIGH`, such as `10:20`,
+/// parsing each value with `parse`.
+///
+/// This is intended for crop windows, thresholds, and clipping ranges.
+/// The low value must not exceed the high value, both values can be equal.
+///
+/// # Example
+///
+/// This allows for a clipping range of base-10 or base-16 values, such as
+/// `0x10:0xF0`.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{maybe_hex, pair};
+///
+/// fn clip(s: &str) -> Result<(u8, u8), String> {
+///     pair(s, maybe_hex)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=clip)]
+///     clip: (u8, u8),
+/// }
+/// # let args = Args::parse_from(&["", "--clip", "0x10:0xF0"]);
+/// # assert_eq!(args.clip, (0x10, 0xF0));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are not pairs will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--clip <clip>': expected pair in the format of 10:20
+/// ```
+///
+/// Pairs with a low value above the high value will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--clip <clip>': low value of 240 exceeds high value of 16
+/// ```
+pub fn pair<T, F>(s: &str, parse: F) -> Result<(T, T), String>
+where
+    F: Fn(&str) -> Result<T, String>,
+    T: PartialOrd,
+    T: std::fmt::Display,
+{
+    let (low, high) = s
+        .split_once(':')
+        .ok_or_else(|| String::from("expected pair in the format of 10:20"))?;
+
+    let low = parse(low)?;
+    let high = parse(high)?;
+
+    if low > high {
+        Err(format!("low value of {low} exceeds high value of {high}"))
+    } else {
+        Ok((low, high))
+    }
+}
+
+/// A nominal value with a symmetric tolerance, returned by [`tolerance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tolerance<T> {
+    /// Nominal value.
+    pub nominal: T,
+    /// Allowed deviation from the nominal value in either direction.
+    pub deviation: T,
+}
+
+impl<T> Tolerance<T>
+where
+    T: Clone,
+    T: core::ops::Add<Output = T>,
+    T: core::ops::Sub<Output = T>,
+{
+    /// Returns the range of values within the tolerance, inclusive.
+    ///
+    /// [`tolerance`] checks that the range fits in the type, a `Tolerance`
+    /// built with a range that does not fit overflows, which panics in debug
+    /// builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::tolerance;
+    ///
+    /// let limits = tolerance::<u32>("10k±5%").unwrap();
+    /// assert_eq!(limits.range(), 9_500..=10_500);
+    /// ```
+    pub fn range(&self) -> core::ops::RangeInclusive<T> {
+        let low = self.nominal.clone() - self.deviation.clone();
+        let high = self.nominal.clone() + self.deviation.clone();
+        low..=high
+    }
+}
+
+/// Validates a nominal value with a tolerance, in the format of `100±5`,
+/// with a [metric prefix].
+///
+/// The `±` can also be written as `+/-`, whitespace around it is ignored.
+/// Both values accept the same formats as [`si_number`].
+///
+/// The tolerance can be followed by `%`, which makes it a percentage of the
+/// nominal value, such as `0.5%`.
+///
+/// | String        | Nominal  | Deviation |
+/// |---------------|----------|-----------|
+/// | `100±5`       | `100`    | `5`       |
+/// | `100 +/- 5`   | `100`    | `5`       |
+/// | `10k±5%`      | `10000`  | `500`     |
+/// | `1000±0.5%`   | `1000`   | `5`       |
+/// | `1.5M+/-2k`   | `1500000`| `2000`    |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{tolerance, Tolerance};
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=tolerance::<u32>)]
+///     resistance: Tolerance<u32>,
+/// }
+/// # let args = Args::parse_from(&["", "--resistance", "4.7k±5%"]);
+/// # assert_eq!(args.resistance, Tolerance { nominal: 4700, deviation: 235 });
+/// ```
+///
+/// ## Error Messages
+///
+/// Values without a tolerance will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--resistance <resistance>': expected tolerance in the format of 100±5
+/// ```
+///
+/// Percentages that do not result in an integer deviation will show an error
+/// message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--resistance <resistance>': 5% of 10 is not an integer
+/// ```
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn tolerance<T>(s: &str) -> Result<Tolerance<T>, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Num,
+    T: Clone,
+    T: std::fmt::Display,
+{
+    let (nominal, deviation) = s
+        .split_once('±')
+        .or_else(|| s.split_once("+/-"))
+        .ok_or_else(|| String::from("expected tolerance in the format of 100±5"))?;
+
+    let nominal: T = si_number(nominal.trim_end())?;
+
+    // checked before parsing, so the sign is not dropped from percentages and
+    // unsigned types do not report a negative value
+    let deviation = deviation.trim_start();
+    if deviation.starts_with('-') {
+        return Err(String::from("tolerance must not be negative"));
+    }
+
+    let deviation: T = match deviation.strip_suffix('%') {
+        Some(percent) => percent_of(&nominal, percent)?,
+        None => si_number(deviation)?,
+    };
+
+    nominal
+        .checked_sub(&deviation)
+        .ok_or_else(|| String::from(UNDERFLOW_MSG))?;
+    nominal
+        .checked_add(&deviation)
+        .ok_or_else(|| String::from(OVERFLOW_MSG))?;
+
+    Ok(Tolerance { nominal, deviation })
+}
+
+// Computes a percentage of a value, such as "0.5" for 0.5%, as a magnitude.
+//
+// This is computed with u128 and split around the divisor, so the product
+// does not overflow for values that fit in the target type.
+fn percent_of<T>(nominal: &T, percent: &str) -> Result<T, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    T: TryFrom<u128> + std::fmt::Display,
+{
+    let overflow = || String::from(OVERFLOW_MSG);
+
+    let (_, magnitude) = split_sign(percent);
+    let (int, frac) = magnitude.split_once('.').unwrap_or((magnitude, ""));
+    let places = frac.bytes().filter(|&b| b != b'_').count();
+    let numerator: u128 = scale_decimal(int, frac, places as i64, None)?;
+    let denominator: u128 = u32::try_from(places + 2)
+        .ok()
+        .and_then(|exp| 10u128.checked_pow(exp))
+        .ok_or_else(overflow)?;
+
+    let nominal_str = nominal.to_string();
+    let (_, value) = split_sign(&nominal_str);
+    let value: u128 = value.parse().map_err(stringify)?;
+
+    let remainder = (value % denominator)
+        .checked_mul(numerator)
+        .ok_or_else(overflow)?;
+    if remainder % denominator != 0 {
+        return Err(format!("{percent}% of {nominal} is not an integer"));
+    }
+    let deviation = (value / denominator)
+        .checked_mul(numerator)
+        .and_then(|d| d.checked_add(remainder / denominator))
+        .ok_or_else(overflow)?;
+    T::try_from(deviation).map_err(|_| overflow())
+}
+
+// Units of a duration, with the number of nanoseconds in each unit.
+//
+// `µs` is listed after `us` so that error messages only list the ASCII
+// spelling.
+const DURATION_UNITS: [(&str, u128); 8] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("d", 86_400_000_000_000),
+    ("µs", 1_000),
+];
+
+const DURATION_UNITS_MSG: &str = "expected one of ns, us, ms, s, m, h, d";
+const DURATION_PRECISION_MSG: &str = "duration must be a whole number of nanoseconds";
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+// A duration formatted with units, such as `1m30s`.
+struct HumanDuration(core::time::Duration);
+
+impl std::fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut nanos: u128 = self.0.as_nanos();
+        if nanos == 0 {
+            return f.write_str("0s");
+        }
+
+        for (unit, unit_nanos) in DURATION_UNITS[..7].iter().rev() {
+            let count = nanos / unit_nanos;
+            if count != 0 {
+                write!(f, "{count}{unit}")?;
+                nanos %= unit_nanos;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// parses a single duration component, such as "1.5" with the unit "h", to
+// nanoseconds
+fn duration_component(value: &str, unit: &str) -> Result<u128, String> {
+    let (_, unit_nanos) = DURATION_UNITS
+        .iter()
+        .find(|(name, _)| *name == unit)
+        .ok_or_else(|| format!("unknown unit '{unit}', {DURATION_UNITS_MSG}"))?;
+
+    if value.is_empty() {
+        return Err(format!("no value found before unit '{unit}'"));
+    }
+
+    let (int, frac) = value.split_once('.').unwrap_or((value, ""));
+    let nano_units: u128 = match scale_decimal(int, frac, 9, None) {
+        Err(e) if e == NOT_AN_INTEGER_MSG => Err(String::from(DURATION_PRECISION_MSG)),
+        other => other,
+    }?;
+
+    let scaled = nano_units
+        .checked_mul(*unit_nanos)
+        .ok_or_else(|| String::from(OVERFLOW_MSG))?;
+    if scaled % NANOS_PER_SEC != 0 {
+        return Err(String::from(DURATION_PRECISION_MSG));
+    }
+    Ok(scaled / NANOS_PER_SEC)
+}
+
+/// Validates a duration with units, such as `1.5s` or `1h30m`.
+///
+/// The duration is a decimal value followed by a unit, or several of these
+/// that are added together.
+/// A value of `0` does not require a unit.
+///
+/// | Unit        | Name        |
+/// |-------------|-------------|
+/// | `ns`        | nanosecond  |
+/// | `us` / `µs` | microsecond |
+/// | `ms`        | millisecond |
+/// | `s`         | second      |
+/// | `m`         | minute      |
+/// | `h`         | hour        |
+/// | `d`         | day         |
+///
+/// Underscores can be used as digit separators, for example `1_000ms`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::duration;
+/// use std::time::Duration;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=duration)]
+///     interval: Duration,
+/// }
+/// # let args = Args::parse_from(&["", "--interval", "1m30s"]);
+/// # assert_eq!(args.interval, Duration::from_secs(90));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values without a unit will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--interval <interval>': missing unit, expected one of ns, us, ms, s, m, h, d
+/// ```
+///
+/// Values with an unknown unit will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--interval <interval>': unknown unit 'w', expected one of ns, us, ms, s, m, h, d
+/// ```
+pub fn duration(s: &str) -> Result<core::time::Duration, String> {
+    if s.is_empty() {
+        return Err(String::from(EMPTY_MSG));
+    } else if s == "0" {
+        return Ok(core::time::Duration::ZERO);
+    }
+
+    let mut nanos: u128 = 0;
+    let mut rest: &str = s;
+    while !rest.is_empty() {
+        let value_len = rest
+            .find(char::is_alphabetic)
+            .ok_or_else(|| format!("missing unit, {DURATION_UNITS_MSG}"))?;
+        let (value, tail) = rest.split_at(value_len);
+        let unit_len = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+
+        nanos = nanos
+            .checked_add(duration_component(value, unit)?)
+            .ok_or_else(|| String::from(OVERFLOW_MSG))?;
+        rest = tail;
+    }
+
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| String::from(OVERFLOW_MSG))?;
+    Ok(core::time::Duration::new(
+        secs,
+        (nanos % NANOS_PER_SEC) as u32,
+    ))
+}
+
+/// Validates a duration with units within a range.
+///
+/// This combines [`duration`] and [`number_range`], see the documentation
+/// for those functions for details.
+///
+/// # Example
+///
+/// This allows for a timeout between 100ms and 10m.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::duration_range;
+/// use std::time::Duration;
+///
+/// fn timeout(s: &str) -> Result<Duration, String> {
+///     duration_range(s, Duration::from_millis(100), Duration::from_secs(600))
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=timeout)]
+///     timeout: Duration,
+/// }
+/// # let args = Args::parse_from(&["", "--timeout", "2.5s"]);
+/// # assert_eq!(args.timeout, Duration::from_millis(2_500));
+/// # assert_eq!(timeout("1h"), Err(String::from("exceeds maximum of 10m")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown with units, values exceeding the limits will show an
+/// error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--timeout <timeout>': less than minimum of 100ms
+/// ```
+pub fn duration_range(
+    s: &str,
+    min: core::time::Duration,
+    max: core::time::Duration,
+) -> Result<core::time::Duration, String> {
+    debug_assert!(
+        min <= max,
+        "minimum of {} exceeds maximum of {}",
+        HumanDuration(min),
+        HumanDuration(max)
+    );
+    let val = duration(s)?;
+    check_range(val, min, max).map_err(|e| String::from(e.map(HumanDuration)))
+}
+
+/// Parser for durations converted to an integer number of ticks, such as the
+/// ticks of an RTOS scheduler.
+///
+/// Durations accept the same formats as [`duration`].
+/// Durations that are not a whole number of ticks are rejected, unless a
+/// [`Rounding`] policy is set.
+///
+/// # Example
+///
+/// This allows for a timeout in human units, converted to 1kHz ticks.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::TickParser;
+///
+/// const TICKS: TickParser = TickParser::frequency(1_000);
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=|s: &str| TICKS.parse::<u32>(s))]
+///     timeout: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--timeout", "1.5s"]);
+/// # assert_eq!(args.timeout, 1_500);
+/// ```
+///
+/// ## Error Messages
+///
+/// Durations that are not a whole number of ticks will show an error
+/// message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--timeout <timeout>': 1500us is not a whole number of ticks
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TickParser {
+    // ticks per nanosecond, as a fraction
+    numerator: u128,
+    denominator: u128,
+    rounding: Option<Rounding>,
+}
+
+impl TickParser {
+    /// Create a new parser for ticks with the given period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the period is zero.
+    pub const fn period(period: core::time::Duration) -> Self {
+        assert!(!period.is_zero(), "tick period must not be zero");
+        Self {
+            numerator: 1,
+            denominator: period.as_nanos(),
+            rounding: None,
+        }
+    }
+
+    /// Create a new parser for ticks with the given frequency in hertz.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency is zero.
+    pub const fn frequency(hz: u64) -> Self {
+        assert!(hz != 0, "tick frequency must not be zero");
+        Self {
+            numerator: hz as u128,
+            denominator: NANOS_PER_SEC,
+            rounding: None,
+        }
+    }
+
+    /// Round durations that are not a whole number of ticks, instead of
+    /// rejecting them.
+    #[must_use]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Validate a duration, and convert it to a number of ticks.
+    pub fn parse<T: TryFrom<u128>>(&self, s: &str) -> Result<T, String> {
+        let nanos = duration(s)?.as_nanos();
+        let product = nanos
+            .checked_mul(self.numerator)
+            .ok_or_else(|| String::from(OVERFLOW_MSG))?;
+        let ticks = product / self.denominator;
+        let remainder = product % self.denominator;
+
+        let round_up: bool = match self.rounding {
+            _ if remainder == 0 => false,
+            Some(Rounding::Nearest) => remainder >= self.denominator - remainder,
+            Some(Rounding::Floor) => false,
+            Some(Rounding::Ceil) => true,
+            None => return Err(format!("{s} is not a whole number of ticks")),
+        };
+
+        let ticks = if round_up { ticks + 1 } else { ticks };
+        T::try_from(ticks).map_err(|_| String::from(OVERFLOW_MSG))
+    }
+}
+
+/// Validates a multiplier, such as `4x`, `0.5x`, or `2×`.
+///
+/// The value is a decimal number followed by an optional `x`, `X`, or `×`.
+/// Integer types accept whole multiples, floating point types such as `f32`
+/// also accept fractional multipliers.
+///
+/// # Example
+///
+/// This allows for a playback speed factor.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::multiplier;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=multiplier::<f32>)]
+///     speed: f32,
+/// }
+/// # let args = Args::parse_from(&["", "--speed", "0.5x"]);
+/// # assert_eq!(args.speed, 0.5);
+/// ```
+///
+/// ## Error Messages
+///
+/// Floating point values that are not a number will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--speed <speed>': multiplier is not a number
+/// ```
+pub fn multiplier<T>(s: &str) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: PartialOrd,
+{
+    let value = s.strip_suffix(['x', 'X', '×']).unwrap_or(s);
+    let val: T = parse_decimal(value)?;
+
+    // NaN is not comparable to itself
+    if val.partial_cmp(&val).is_none() {
+        Err(String::from("multiplier is not a number"))
+    } else {
+        Ok(val)
+    }
+}
+
+/// Validates a multiplier, such as `4x`, `0.5x`, or `2×`, within a range.
+///
+/// This combines [`multiplier`] and [`number_range`], see the documentation
+/// for those functions for details.
+///
+/// # Example
+///
+/// This allows for an oversampling factor between 1x and 16x.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::multiplier_range;
+///
+/// fn oversampling(s: &str) -> Result<u8, String> {
+///     multiplier_range(s, 1, 16)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=oversampling)]
+///     oversampling: u8,
+/// }
+/// # let args = Args::parse_from(&["", "--oversampling", "4x"]);
+/// # assert_eq!(args.oversampling, 4);
+/// # assert_eq!(oversampling("32x"), Err(String::from("exceeds maximum of 16x")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown as multipliers, values exceeding the limits will show
+/// an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--oversampling <oversampling>': exceeds maximum of 16x
+/// ```
+pub fn multiplier_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: PartialOrd,
+    T: std::fmt::Display,
+{
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let val = multiplier(s)?;
+    check_range(val, min, max).map_err(|e| String::from(e.map(|bound| format!("{bound}x"))))
+}
+
+/// Validates a fraction, such as `1/3` or `22/7`, or a decimal value.
+///
+/// The numerator and denominator are decimal numbers separated by `/`, the
+/// result is the numerator divided by the denominator.
+///
+/// # Example
+///
+/// This allows for a sampling ratio.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::fraction;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=fraction)]
+///     ratio: f64,
+/// }
+/// # let args = Args::parse_from(&["", "--ratio", "1/4"]);
+/// # assert_eq!(args.ratio, 0.25);
+/// # let args = Args::parse_from(&["", "--ratio", "0.5"]);
+/// # assert_eq!(args.ratio, 0.5);
+/// ```
+///
+/// ## Error Messages
+///
+/// Fractions with a denominator of zero will show an error message similar
+/// to this:
+///
+/// ```text
+/// error: Invalid value for '--ratio <ratio>': division by zero in '1/0'
+/// ```
+pub fn fraction(s: &str) -> Result<f64, String> {
+    let val: f64 = match s.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator: f64 = parse_decimal(numerator)?;
+            let denominator: f64 = parse_decimal(denominator)?;
+            if denominator == 0.0 {
+                return Err(format!("division by zero in '{s}'"));
+            }
+            numerator / denominator
+        }
+        None => parse_decimal(s)?,
+    };
+
+    if val.is_nan() {
+        Err(String::from("fraction is not a number"))
+    } else {
+        Ok(val)
+    }
+}
+
+/// Validates a fraction, such as `1/3` or `22/7`, or a decimal value within a
+/// range.
+///
+/// This combines [`fraction`] and [`number_range`], see the documentation
+/// for those functions for details.
+///
+/// # Example
+///
+/// This allows for a sampling ratio between 0 and 1.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::fraction_range;
+///
+/// fn ratio(s: &str) -> Result<f64, String> {
+///     fraction_range(s, 0.0, 1.0)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=ratio)]
+///     ratio: f64,
+/// }
+/// # let args = Args::parse_from(&["", "--ratio", "1/3"]);
+/// # assert_eq!(args.ratio, 1.0 / 3.0);
+/// # assert_eq!(ratio("4/3"), Err(String::from("exceeds maximum of 1")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--ratio <ratio>': exceeds maximum of 1
+/// ```
+pub fn fraction_range(s: &str, min: f64, max: f64) -> Result<f64, String> {
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let val = fraction(s)?;
+    check_range(val, min, max).map_err(String::from)
+}
+
+/// Validates a gain, either as a linear multiplier, such as `2x` or `0.5`, or
+/// in decibels, such as `+6dB` or `-20dB`.
+///
+/// The gain is returned as a linear amplitude factor, decibels are converted
+/// with 10<sup>dB/20</sup>, so `+6dB` is about `2x` and `-20dB` is `0.1x`.
+///
+/// Linear gains can be followed by an optional `x`, `X`, or `×`, as with
+/// [`multiplier`], and cannot be negative.
+///
+/// # Example
+///
+/// This allows for a volume adjustment in whichever notation the user thinks
+/// in.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::gain;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=gain, allow_hyphen_values=true)]
+///     volume: f64,
+/// }
+/// # let args = Args::parse_from(&["", "--volume", "-20dB"]);
+/// # assert!((args.volume - 0.1).abs() < 1e-12);
+/// # let args = Args::parse_from(&["", "--volume", "0.5x"]);
+/// # assert_eq!(args.volume, 0.5);
+/// ```
+///
+/// ## Error Messages
+///
+/// Negative linear gains will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--volume <volume>': linear gain cannot be negative, use dB for attenuation
+/// ```
+pub fn gain(s: &str) -> Result<f64, String> {
+    let val: f64 = match s.strip_suffix("dB").or_else(|| s.strip_suffix("db")) {
+        Some(db) => 10f64.powf(parse_decimal::<f64>(db)? / 20.0),
+        None => {
+            let linear: f64 = parse_decimal(s.strip_suffix(['x', 'X', '×']).unwrap_or(s))?;
+            if linear < 0.0 {
+                return Err(String::from(
+                    "linear gain cannot be negative, use dB for attenuation",
+                ));
+            }
+            linear
+        }
+    };
+
+    if val.is_nan() {
+        Err(String::from("gain is not a number"))
+    } else {
+        Ok(val)
+    }
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-16.
+///
+/// The number is assumed to be base-10 by default, it is parsed as hex if the
+/// number is prefixed with `0x`, case insensitive.
+///
+/// Underscores can be used as digit separators, for example `0xDEAD_BEEF`.
+///
+/// # Example
+///
+/// This allows base-10 addresses to be passed normally, or base-16 values to
+/// be passed when prefixed with `0x`.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_hex;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=maybe_hex::<u32>)]
+///     address: u32,
+/// }
+/// # let args = Args::parse_from(&["", "-a", "0x10"]);
+/// # assert_eq!(args.address, 16);
+/// ```
+pub fn maybe_hex<T: Num + sign::Unsigned>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    maybe_radix(s, Base::Hexadecimal)
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-16, and
+/// returns the base that was used.
+///
+/// This accepts the same values as [`maybe_hex`], the base allows
+/// applications to show the value back to the user in the same base.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::{maybe_hex_with_base, Base};
+///
+/// assert_eq!(maybe_hex_with_base::<u8>("0x10"), Ok((16, Base::Hexadecimal)));
+/// assert_eq!(maybe_hex_with_base::<u8>("10"), Ok((10, Base::Decimal)));
+/// ```
+pub fn maybe_hex_with_base<T: Num + sign::Unsigned>(s: &str) -> Result<(T, Base), String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    maybe_radix_with_base(s, Base::Hexadecimal)
+}
+
+/// Base of a number, returned by parsers such as [`maybe_hex_with_base`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base {
+    /// Base-2, with a `0b` prefix.
+    Binary,
+    /// Base-8, with a `0o` prefix.
+    Octal,
+    /// Base-10, without a prefix.
+    Decimal,
+    /// Base-16, with a `0x` prefix.
+    Hexadecimal,
+}
+
+impl Base {
+    /// Returns the radix of the base, such as `16` for
+    /// [`Hexadecimal`](Self::Hexadecimal).
+    pub const fn radix(self) -> u32 {
+        match self {
+            Self::Binary => 2,
+            Self::Octal => 8,
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+        }
+    }
+
+    /// Returns the prefix of the base, such as `0x` for
+    /// [`Hexadecimal`](Self::Hexadecimal), or an empty string for
+    /// [`Decimal`](Self::Decimal).
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Binary => "0b",
+            Self::Octal => "0o",
+            Self::Decimal => "",
+            Self::Hexadecimal => "0x",
+        }
+    }
+
+    // symbol following the zero of the prefix
+    const fn symbol(self) -> char {
+        match self {
+            Self::Binary => 'b',
+            Self::Octal => 'o',
+            Self::Decimal => 'd',
+            Self::Hexadecimal => 'x',
+        }
+    }
+}
+
+// parses a base-10 value, or a value in `base` when prefixed
+fn maybe_radix<T: Num>(s: &str, base: Base) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    maybe_radix_with_base(s, base).map(|(val, _)| val)
+}
+
+// parses a base-10 value, or a value in `base` when prefixed, returning the
+// base that was used
+fn maybe_radix_with_base<T: Num>(s: &str, base: Base) -> Result<(T, Base), String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    parse_prefixed(s, [(base.prefix(), base.radix())])
+        .map(|(val, prefix, _)| match prefix {
+            "" => (val, Base::Decimal),
+            _ => (val, base),
+        })
+        .map_err(|e| reject_radix(s, e, Some(base.symbol())))
+}
+
+// parses a base-10 value, or a value in the radix of the longest prefix of
+// `prefixes` that it starts with (case insensitive), returning the prefix
+// and radix that were used
+fn parse_prefixed<'p, T, P>(s: &str, prefixes: P) -> Result<(T, &'p str, u32), String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: Num,
+    P: IntoIterator<Item = (&'p str, u32)> + Clone,
+{
+    let parse = |s: &str| match split_prefix(s, prefixes.clone()) {
+        Some((prefix, radix, digits)) => {
+            T::from_str_radix(&strip_underscores(digits), radix).map(|val| (val, prefix, radix))
+        }
+        None => T::from_str_radix(&strip_underscores(s), 10).map(|val| (val, "", 10)),
+    };
+
+    parse(s)
+        .map_err(stringify)
+        .map_err(|e| reject_negative(s, e, |m| parse(m).map_err(stringify)))
+}
+
+// splits the longest of `prefixes` that a value starts with (case
+// insensitive), returning the prefix and radix along with the digits
+fn split_prefix<'a, 'p>(
+    s: &'a str,
+    prefixes: impl IntoIterator<Item = (&'p str, u32)>,
+) -> Option<(&'p str, u32, &'a str)> {
+    prefixes
+        .into_iter()
+        .filter(|(prefix, _)| {
+            !prefix.is_empty()
+                && s.get(..prefix.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+        })
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(prefix, radix)| (prefix, radix, &s[prefix.len()..]))
+}
+
+// strips a zero followed by `symbol` (case insensitive), such as `0x`,
+// returning `None` if there is no prefix
+fn strip_radix_prefix(s: &str, symbol: char) -> Option<&str> {
+    let digits = s.strip_prefix('0')?;
+    digits
+        .strip_prefix(symbol.to_ascii_lowercase())
+        .or_else(|| digits.strip_prefix(symbol.to_ascii_uppercase()))
+}
+
+// strips a `0x` or `0X` prefix, returning `None` if there is no prefix
+fn strip_hex_prefix(s: &str) -> Option<&str> {
+    strip_radix_prefix(s, 'x')
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-16 within
+/// a range.
+///
+/// This combines [`maybe_hex`] and [`number_range`], see the
+/// documentation for those functions for details.
+///
+/// # Example
+///
+/// This extends the example in [`maybe_hex`], and only allows a range of
+/// addresses from `0x100` to `0x200`.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_hex_range;
+///
+/// fn address_in_range(s: &str) -> Result<u32, String> {
+///     maybe_hex_range(s, 0x100, 0x200)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=address_in_range)]
+///     address: u32,
+/// }
+/// # let args = Args::parse_from(&["", "-a", "300"]);
+/// # assert_eq!(args.address, 300);
+/// # assert_eq!(address_in_range("0x201"), Err(String::from("exceeds maximum of 0x200")));
+/// # assert_eq!(address_in_range("513"), Err(String::from("exceeds maximum of 512")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown in the same base as the value, values exceeding the
+/// limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--address <address>': exceeds maximum of 0x200
+/// ```
+pub fn maybe_hex_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: std::fmt::Display,
+    T: Ord,
+    T: Num,
+    T: sign::Unsigned,
+{
+    let val = maybe_hex(s)?;
+    check_range(val, min, max).map_err(|e| radix_range_error(e, s, Base::Hexadecimal))
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-16 with a
+/// minimum.
+///
+/// This is [`maybe_hex_range`] without a maximum.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_hex_at_least;
+///
+/// fn above_vectors(s: &str) -> Result<u32, String> {
+///     maybe_hex_at_least(s, 0x100)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=above_vectors)]
+///     address: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--address", "0x8000"]);
+/// # assert_eq!(args.address, 0x8000);
+/// ```
+pub fn maybe_hex_at_least<T>(s: &str, min: T) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: std::fmt::Display,
+    T: Ord,
+    T: Num,
+    T: sign::Unsigned,
+{
+    let val = maybe_hex(s)?;
+    check_min(val, min).map_err(|e| radix_range_error(e, s, Base::Hexadecimal))
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-16 with a
+/// maximum.
+///
+/// This is [`maybe_hex_range`] without a minimum.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_hex_at_most;
+///
+/// fn in_flash(s: &str) -> Result<u32, String> {
+///     maybe_hex_at_most(s, 0xFFFF)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=in_flash)]
+///     address: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--address", "0x8000"]);
+/// # assert_eq!(args.address, 0x8000);
+/// ```
+pub fn maybe_hex_at_most<T>(s: &str, max: T) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: std::fmt::Display,
+    T: Ord,
+    T: Num,
+    T: sign::Unsigned,
+{
+    let val = maybe_hex(s)?;
+    check_max(val, max).map_err(|e| radix_range_error(e, s, Base::Hexadecimal))
+}
+
+/// Configurable parser for unsigned integer values that can be base-10 or
+/// base-16.
+///
+/// The default configuration is identical to [`maybe_hex`], additional
+/// prefixes for base-16 values can be accepted with
+/// [`prefix`](Self::prefix).
+///
+/// # Example
+///
+/// This accepts base-16 addresses in retro assembly syntax, such as `$C000`.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::HexParser;
+///
+/// fn address(s: &str) -> Result<u16, String> {
+///     HexParser::new().prefix("$").parse(s)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=address)]
+///     address: u16,
+/// }
+/// # let args = Args::parse_from(&["", "-a", "$C000"]);
+/// # assert_eq!(args.address, 0xC000);
+/// # assert_eq!(address("0xC000"), Ok(0xC000));
+/// # assert_eq!(address("49152"), Ok(0xC000));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HexParser {
+    prefixes: Vec<&'static str>,
+}
+
+impl HexParser {
+    /// Create a new parser with the default configuration.
+    pub const fn new() -> Self {
+        Self {
+            prefixes: Vec::new(),
+        }
+    }
+
+    /// Accept `prefix` for base-16 values in addition to `0x`, such as `$` or
+    /// `#`.
+    ///
+    /// Prefixes are case insensitive, if several prefixes match a value the
+    /// longest is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `prefix` is empty.
+    #[must_use]
+    pub fn prefix(mut self, prefix: &'static str) -> Self {
+        debug_assert!(!prefix.is_empty(), "prefix is empty");
+        if !self.prefixes.contains(&prefix) {
+            self.prefixes.push(prefix);
+        }
+        self
+    }
+
+    // prefixes and the radix of the values that follow them
+    fn prefixes(&self) -> impl Iterator<Item = (&'static str, u32)> + Clone + '_ {
+        core::iter::once(Base::Hexadecimal.prefix())
+            .chain(self.prefixes.iter().copied())
+            .map(|prefix| (prefix, 16))
+    }
+
+    // parses a value, returning the prefix that was used
+    fn parse_with_prefix<T>(&self, s: &str) -> Result<(T, &'static str), String>
+    where
+        <T as Num>::FromStrRadixErr: std::fmt::Display,
+        T: Num,
+    {
+        parse_prefixed(s, self.prefixes())
+            .map(|(val, prefix, _)| (val, prefix))
+            .map_err(|e| reject_radix(s, e, Some(Base::Hexadecimal.symbol())))
+    }
+
+    /// Validates an unsigned integer value that can be base-10 or base-16.
+    ///
+    /// See [`maybe_hex`] for details.
+    pub fn parse<T>(&self, s: &str) -> Result<T, String>
+    where
+        <T as Num>::FromStrRadixErr: std::fmt::Display,
+        T: Num,
+        T: sign::Unsigned,
+    {
+        self.parse_with_prefix(s).map(|(val, _)| val)
+    }
+
+    /// Validates an unsigned integer value that can be base-10 or base-16
+    /// within a range.
+    ///
+    /// The bounds in range errors are shown with the prefix of the value,
+    /// such as `$FF`.
+    /// See [`maybe_hex_range`] for details.
+    pub fn parse_range<T>(&self, s: &str, min: T, max: T) -> Result<T, String>
+    where
+        <T as Num>::FromStrRadixErr: std::fmt::Display,
+        T: std::fmt::Display,
+        T: Ord,
+        T: Num,
+        T: sign::Unsigned,
+    {
+        let (val, prefix) = self.parse_with_prefix(s)?;
+        check_range(val, min, max).map_err(|e| prefixed_range_error(e, prefix, 16))
+    }
+}
+
+/// Configurable parser for unsigned integer values that can be base-10 or in
+/// the radix of a prefix.
+///
+/// This allows the literal syntax of a toolchain to be parsed, such as `%`
+/// for binary values, with the overflow checks and range errors of the other
+/// parsers in this crate.
+/// Values without a prefix are base-10.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::RadixParser;
+///
+/// fn word(s: &str) -> Result<u32, String> {
+///     RadixParser::new()
+///         .prefix("$", 16)
+///         .prefix("%", 2)
+///         .prefix("0y", 32)
+///         .parse(s)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=word)]
+///     word: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--word", "%1010"]);
+/// # assert_eq!(args.word, 0b1010);
+/// # assert_eq!(word("$FF"), Ok(0xFF));
+/// # assert_eq!(word("0yV"), Ok(31));
+/// # assert_eq!(word("10"), Ok(10));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RadixParser {
+    prefixes: Vec<(&'static str, u32)>,
+}
+
+impl RadixParser {
+    /// Create a new parser without prefixes, accepting base-10 values only.
+    pub const fn new() -> Self {
+        Self {
+            prefixes: Vec::new(),
+        }
+    }
+
+    /// Accept values in `radix` when prefixed with `prefix`, such as `0x` for
+    /// base-16 or `%` for base-2.
+    ///
+    /// Prefixes are case insensitive, if several prefixes match a value the
+    /// longest is used.
+    /// Adding a prefix that already exists replaces its radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36, or in debug builds if
+    /// `prefix` is empty.
+    #[must_use]
+    pub fn prefix(mut self, prefix: &'static str, radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2 to 36, got {radix}"
+        );
+        debug_assert!(!prefix.is_empty(), "prefix is empty");
+        match self.prefixes.iter_mut().find(|(p, _)| *p == prefix) {
+            Some((_, r)) => *r = radix,
+            None => self.prefixes.push((prefix, radix)),
+        }
+        self
+    }
+
+    /// Validates an unsigned integer value that can be base-10 or in the
+    /// radix of a prefix.
+    pub fn parse<T>(&self, s: &str) -> Result<T, String>
+    where
+        <T as Num>::FromStrRadixErr: std::fmt::Display,
+        T: Num,
+        T: sign::Unsigned,
+    {
+        parse_prefixed(s, self.prefixes.iter().copied()).map(|(val, _, _)| val)
+    }
+
+    /// Validates an unsigned integer value that can be base-10 or in the
+    /// radix of a prefix within a range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::RadixParser;
+    ///
+    /// let parser = RadixParser::new().prefix("%", 2);
+    /// assert_eq!(
+    ///     parser.parse_range("%1000", 0u8, 0b111),
+    ///     Err(String::from("exceeds maximum of %111"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse_range("8", 0u8, 0b111),
+    ///     Err(String::from("exceeds maximum of 7"))
+    /// );
+    /// ```
+    ///
+    /// ## Error Messages
+    ///
+    /// The bounds are shown in the same radix and with the same prefix as the
+    /// value, values exceeding the limits will show an error message similar
+    /// to this:
+    ///
+    /// ```text
+    /// error: Invalid value for '--word <word>': exceeds maximum of %111
+    /// ```
+    pub fn parse_range<T>(&self, s: &str, min: T, max: T) -> Result<T, String>
+    where
+        <T as Num>::FromStrRadixErr: std::fmt::Display,
+        T: std::fmt::Display,
+        T: Ord,
+        T: Num,
+        T: sign::Unsigned,
+    {
+        let (val, prefix, radix) = parse_prefixed(s, self.prefixes.iter().copied())?;
+        check_range(val, min, max).map_err(|e| prefixed_range_error(e, prefix, radix))
+    }
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-2.
+///
+/// The number is assumed to be base-10 by default, it is parsed as binary if
+/// the number is prefixed with `0b`, case insensitive.
+///
+/// Underscores can be used as digit separators, for example `0b1010_1010`.
+///
+/// # Example
+///
+/// This allows base-10 masks to be passed normally, or base-2 values to be
+/// passed when prefixed with `0b`.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_bin;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=maybe_bin::<u8>)]
+///     mask: u8,
+/// }
+/// # let args = Args::parse_from(&["", "-m", "0b1010_1010"]);
+/// # assert_eq!(args.mask, 0xAA);
+/// ```
+pub fn maybe_bin<T: Num + sign::Unsigned>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    maybe_radix(s, Base::Binary)
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-2, and
+/// returns the base that was used.
+///
+/// This accepts the same values as [`maybe_bin`], see
+/// [`maybe_hex_with_base`] for details.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::{maybe_bin_with_base, Base};
+///
+/// assert_eq!(maybe_bin_with_base::<u8>("0b101"), Ok((5, Base::Binary)));
+/// assert_eq!(maybe_bin_with_base::<u8>("10"), Ok((10, Base::Decimal)));
+/// ```
+pub fn maybe_bin_with_base<T: Num + sign::Unsigned>(s: &str) -> Result<(T, Base), String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    maybe_radix_with_base(s, Base::Binary)
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-2 within
+/// a range.
+///
+/// This combines [`maybe_bin`] and [`number_range`], see the
+/// documentation for those functions for details.
+///
+/// # Example
+///
+/// This extends the example in [`maybe_bin`], and only allows masks up to
+/// `0b1111`.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_bin_range;
+///
+/// fn nibble(s: &str) -> Result<u8, String> {
+///     maybe_bin_range(s, 0, 0b1111)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=nibble)]
+///     mask: u8,
+/// }
+/// # let args = Args::parse_from(&["", "-m", "0b101"]);
+/// # assert_eq!(args.mask, 5);
+/// ```
+pub fn maybe_bin_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: std::fmt::Display,
+    T: Ord,
+    T: Num,
+    T: sign::Unsigned,
+{
+    let val = maybe_bin(s)?;
+    check_range(val, min, max).map_err(|e| radix_range_error(e, s, Base::Binary))
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-8.
+///
+/// The number is assumed to be base-10 by default, it is parsed as octal if
+/// the number is prefixed with `0o`, case insensitive.
+///
+/// Underscores can be used as digit separators, for example `0o7_777`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_oct;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=maybe_oct::<u16>)]
+///     value: u16,
+/// }
+/// # let args = Args::parse_from(&["", "-v", "0o17"]);
+/// # assert_eq!(args.value, 15);
+/// ```
+pub fn maybe_oct<T: Num + sign::Unsigned>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    maybe_radix(s, Base::Octal)
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-8, and
+/// returns the base that was used.
+///
+/// This accepts the same values as [`maybe_oct`], see
+/// [`maybe_hex_with_base`] for details.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::{maybe_oct_with_base, Base};
+///
+/// assert_eq!(maybe_oct_with_base::<u8>("0o17"), Ok((15, Base::Octal)));
+/// assert_eq!(maybe_oct_with_base::<u8>("10"), Ok((10, Base::Decimal)));
+/// ```
+pub fn maybe_oct_with_base<T: Num + sign::Unsigned>(s: &str) -> Result<(T, Base), String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    maybe_radix_with_base(s, Base::Octal)
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-8 within
+/// a range.
+///
+/// This combines [`maybe_oct`] and [`number_range`], see the
+/// documentation for those functions for details.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::maybe_oct_range;
+///
+/// fn small(s: &str) -> Result<u16, String> {
+///     maybe_oct_range(s, 0, 0o777)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=small)]
+///     value: u16,
+/// }
+/// # let args = Args::parse_from(&["", "-v", "0o777"]);
+/// # assert_eq!(args.value, 511);
+/// ```
+pub fn maybe_oct_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: std::fmt::Display,
+    T: Ord,
+    T: Num,
+    T: sign::Unsigned,
+{
+    let val = maybe_oct(s)?;
+    check_range(val, min, max).map_err(|e| radix_range_error(e, s, Base::Octal))
+}
+
+/// Validates an unsigned integer value that is always base-16.
+///
+/// Unlike [`maybe_hex`] there is no base-10 fallback, the `0x` prefix (case
+/// insensitive) is optional and the digits are always parsed as hex.
+/// This prevents a value such as `10` from being silently read as ten.
+///
+/// Underscores can be used as digit separators, for example `DEAD_BEEF`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::hex_only;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=hex_only::<u32>)]
+///     register: u32,
+/// }
+/// # let args = Args::parse_from(&["", "-r", "10"]);
+/// # assert_eq!(args.register, 16);
+/// ```
+pub fn hex_only<T: Num + sign::Unsigned>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    let parse = |s: &str| {
+        let digits = strip_hex_prefix(s).unwrap_or(s);
+        T::from_str_radix(&strip_underscores(digits), 16)
+    };
+
+    parse(s)
+        .map_err(stringify)
+        .map_err(|e| reject_negative(s, e, |m| parse(m).map_err(stringify)))
+}
+
+/// Validates an unsigned integer value that is always base-16 within a
+/// range.
+///
+/// This combines [`hex_only`] and [`number_range`], see the
+/// documentation for those functions for details.
+///
+/// # Example
+///
+/// This extends the example in [`hex_only`], and only allows register
+/// addresses from `0x00` to `0x7F`.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::hex_only_range;
+///
+/// fn register(s: &str) -> Result<u8, String> {
+///     hex_only_range(s, 0x00, 0x7F)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=register)]
+///     register: u8,
+/// }
+/// # let args = Args::parse_from(&["", "-r", "7F"]);
+/// # assert_eq!(args.register, 0x7F);
+/// ```
+pub fn hex_only_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: std::fmt::Display,
+    T: Ord,
+    T: Num,
+    T: sign::Unsigned,
+{
+    let val = hex_only(s)?;
+    check_range(val, min, max).map_err(|e| {
+        String::from(e.map(|bound| RadixBound {
+            bound,
+            prefix: Base::Hexadecimal.prefix(),
+            radix: 16,
+        }))
+    })
+}
+
+// parses an integer that can be base-10 or base-16 with an optional sign
+fn signed_maybe_hex<T: Num>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    let (negative, magnitude) = split_sign(s);
+    let sign = if negative { "-" } else { "" };
+    if magnitude.starts_with(['+', '-']) {
+        return Err(String::from(INVALID_DIGIT_MSG));
+    }
+
+    let result = if let Some(digits) = strip_hex_prefix(magnitude) {
+        T::from_str_radix(&format!("{sign}{}", strip_underscores(digits)), 16)
+    } else {
+        T::from_str_radix(&format!("{sign}{}", strip_underscores(magnitude)), 10)
+    };
+
+    result.map_err(stringify)
+}
+
+/// Validates a relative offset that can be base-10 or base-16, with a
+/// mandatory sign.
+///
+/// | String   | Value |
+/// |----------|-------|
+/// | `+0x10`  | `16`  |
+/// | `-4`     | `-4`  |
+/// | `+0`     | `0`   |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::relative_offset;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=relative_offset::<i64>, allow_hyphen_values=true)]
+///     seek: i64,
+/// }
+/// # let args = Args::parse_from(&["", "--seek", "-0x10"]);
+/// # assert_eq!(args.seek, -16);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values without a sign will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--seek <seek>': expected offset to start with '+' or '-'
+/// ```
+pub fn relative_offset<T: Num + sign::Signed>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    if !s.starts_with(['+', '-']) {
+        return Err(String::from("expected offset to start with '+' or '-'"));
+    }
+    signed_maybe_hex(s)
+}
+
+/// An absolute position or a relative offset, returned by [`offset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Offset<T> {
+    /// Absolute position, written without a sign.
+    Absolute(T),
+    /// Offset relative to the current position, written with a sign.
+    Relative(T),
+}
+
+impl<T: CheckedAdd> Offset<T> {
+    /// Returns the absolute position, applying a relative offset to
+    /// `current`.
+    ///
+    /// Returns `None` on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::Offset;
+    ///
+    /// assert_eq!(Offset::Absolute(0x10).resolve(0x100), Some(0x10));
+    /// assert_eq!(Offset::Relative(-0x10).resolve(0x100), Some(0xF0));
+    /// ```
+    pub fn resolve(self, current: T) -> Option<T> {
+        match self {
+            Self::Absolute(position) => Some(position),
+            Self::Relative(offset) => current.checked_add(&offset),
+        }
+    }
+}
+
+/// Validates an absolute position or a relative offset that can be base-10
+/// or base-16.
+///
+/// Values with a sign are relative, values without a sign are absolute.
+///
+/// | String   | Value                  |
+/// |----------|------------------------|
+/// | `0x10`   | `Offset::Absolute(16)` |
+/// | `+0x10`  | `Offset::Relative(16)` |
+/// | `-4`     | `Offset::Relative(-4)` |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{offset, Offset};
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=offset::<i64>, allow_hyphen_values=true)]
+///     seek: Offset<i64>,
+/// }
+/// # let args = Args::parse_from(&["", "--seek", "+0x10"]);
+/// # assert_eq!(args.seek, Offset::Relative(16));
+/// # let args = Args::parse_from(&["", "--seek", "0x10"]);
+/// # assert_eq!(args.seek, Offset::Absolute(16));
+/// ```
+pub fn offset<T: Num + sign::Signed>(s: &str) -> Result<Offset<T>, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    if s.starts_with(['+', '-']) {
+        signed_maybe_hex(s).map(Offset::Relative)
+    } else {
+        signed_maybe_hex(s).map(Offset::Absolute)
+    }
+}
+
+/// Validates an unsigned integer value encoded as packed [binary-coded decimal].
+///
+/// The input is read as hexadecimal nibbles, optionally prefixed with `0x`
+/// (case insensitive), where each nibble must be a decimal digit.
+/// The decoded decimal value is returned.
+///
+/// | String | Value |
+/// |--------|-------|
+/// | 0x59   | 59    |
+/// | 1234   | 1234  |
+/// | 0x0001 | 1     |
+///
+/// # Example
+///
+/// This allows the seconds register of an RTC to be passed exactly as it is
+/// stored on the chip.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::bcd;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=bcd::<u8>)]
+///     seconds: u8,
+/// }
+/// # let args = Args::parse_from(&["", "--seconds", "0x59"]);
+/// # assert_eq!(args.seconds, 59);
+/// ```
+///
+/// ## Error Messages
+///
+/// Nibbles that are not decimal digits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--seconds <seconds>': invalid BCD nibble 'A'
+/// ```
+///
+/// [binary-coded decimal]: https://en.wikipedia.org/wiki/Binary-coded_decimal
+pub fn bcd<T: Num + sign::Unsigned>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    let digits = strip_underscores(strip_hex_prefix(s).unwrap_or(s));
+
+    if let Some(nibble) = digits
+        .chars()
+        .find(|c| c.is_ascii_hexdigit() && !c.is_ascii_digit())
+    {
+        return Err(format!("invalid BCD nibble '{nibble}'"));
+    }
+
+    T::from_str_radix(&digits, 10).map_err(stringify)
+}
+
+/// Validates a [Gray-coded] unsigned integer value that can be base-10 or
+/// base-16.
+///
+/// The input is parsed with [`maybe_hex`], and the Gray-coded value is
+/// converted to its binary equivalent with [`gray_decode`].
+///
+/// # Example
+///
+/// This allows the raw reading of an absolute rotary encoder to be passed
+/// directly, and converted to a position.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::gray_code;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=gray_code::<u8>)]
+///     position: u8,
+/// }
+/// # let args = Args::parse_from(&["", "--position", "0x6"]);
+/// # assert_eq!(args.position, 4);
+/// ```
+///
+/// [Gray-coded]: https://en.wikipedia.org/wiki/Gray_code
+pub fn gray_code<T>(s: &str) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+    T: PrimInt,
+    T: sign::Unsigned,
+{
+    maybe_hex(s).map(gray_decode)
+}
+
+/// Converts a binary value to its [Gray code] equivalent.
+///
+/// This is the inverse of [`gray_decode`].
+///
+/// # Example
+///
+/// ```
+/// use clap_num::gray_encode;
+///
+/// assert_eq!(gray_encode(4u8), 0b110);
+/// ```
+///
+/// [Gray code]: https://en.wikipedia.org/wiki/Gray_code
+pub fn gray_encode<T: PrimInt + sign::Unsigned>(value: T) -> T {
+    value ^ (value >> 1)
+}
+
+/// Converts a [Gray code] value to its binary equivalent.
+///
+/// This is the inverse of [`gray_encode`].
+///
+/// # Example
+///
+/// ```
+/// use clap_num::gray_decode;
+///
+/// assert_eq!(gray_decode(0b110u8), 4);
+/// ```
+///
+/// [Gray code]: https://en.wikipedia.org/wiki/Gray_code
+pub fn gray_decode<T: PrimInt + sign::Unsigned>(value: T) -> T {
+    let mut binary = value;
+    let mut shifted = value >> 1;
+    while !shifted.is_zero() {
+        binary = binary ^ shifted;
+        shifted = shifted >> 1;
+    }
+    binary
+}
+
+/// Validates an IPv4 address in dotted-quad or integer form.
+///
+/// The address is returned as a `u32` in host byte order, for example
+/// `192.168.1.1` is returned as `0xC0A80101`.
+/// Values without dots are parsed with [`maybe_hex`].
+///
+/// | String      | Value      |
+/// |-------------|------------|
+/// | 192.168.1.1 | 0xC0A80101 |
+/// | 0xC0A80101  | 0xC0A80101 |
+/// | 3232235777  | 0xC0A80101 |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::ipv4;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=ipv4)]
+///     addr: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--addr", "192.168.1.1"]);
+/// # assert_eq!(args.addr, 0xC0A80101);
+/// ```
+///
+/// ## Error Messages
+///
+/// Malformed dotted-quad addresses will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--addr <addr>': invalid IPv4 address syntax
+/// ```
+pub fn ipv4(s: &str) -> Result<u32, String> {
+    if s.contains('.') {
+        s.parse::<Ipv4Addr>().map(u32::from).map_err(stringify)
+    } else {
+        maybe_hex(s)
+    }
+}
+
+/// Validates an IPv4 address in dotted-quad or integer form, returning it in
+/// network byte order.
+///
+/// This is identical to [`ipv4`], except the bytes of the returned value are
+/// in network (big endian) order, suitable for passing to APIs expecting an
+/// `in_addr`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::ipv4_network_order;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=ipv4_network_order)]
+///     addr: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--addr", "192.168.1.1"]);
+/// # assert_eq!(args.addr.to_ne_bytes(), [192, 168, 1, 1]);
+/// ```
+pub fn ipv4_network_order(s: &str) -> Result<u32, String> {
+    ipv4(s).map(u32::to_be)
+}
+
+// strips the optional leading slash from a CIDR prefix length
+fn strip_prefix_slash(s: &str) -> &str {
+    s.strip_prefix('/').unwrap_or(s)
+}
+
+/// Validates an IPv4 CIDR prefix length.
+///
+/// The prefix length can be provided with or without the leading slash, for
+/// example `/24` or `24`, and must be within the range of 0 to 32
+/// (inclusive).
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::ipv4_prefix_len;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=ipv4_prefix_len)]
+///     prefix: u8,
+/// }
+/// # let args = Args::parse_from(&["", "--prefix", "/24"]);
+/// # assert_eq!(args.prefix, 24);
+/// ```
+///
+/// ## Error Messages
+///
+/// Prefix lengths exceeding the width of the address will show an error
+/// message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--prefix <prefix>': exceeds maximum of 32
+/// ```
+pub fn ipv4_prefix_len(s: &str) -> Result<u8, String> {
+    number_range(strip_prefix_slash(s), 0, 32)
+}
+
+/// Validates an IPv6 CIDR prefix length.
+///
+/// This is identical to [`ipv4_prefix_len`], except the prefix length must be
+/// within the range of 0 to 128 (inclusive).
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::ipv6_prefix_len;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=ipv6_prefix_len)]
+///     prefix: u8,
+/// }
+/// # let args = Args::parse_from(&["", "--prefix", "/64"]);
+/// # assert_eq!(args.prefix, 64);
+/// ```
+pub fn ipv6_prefix_len(s: &str) -> Result<u8, String> {
+    number_range(strip_prefix_slash(s), 0, 128)
+}
+
+/// Validates an IPv4 CIDR prefix length, and converts it to a netmask.
+///
+/// The prefix length is validated with [`ipv4_prefix_len`], and the netmask
+/// is returned as a `u32` in host byte order, for example `/24` is returned as
+/// `0xFFFFFF00`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::ipv4_netmask;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=ipv4_netmask)]
+///     netmask: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--netmask", "/24"]);
+/// # assert_eq!(args.netmask, 0xFFFF_FF00);
+/// ```
+pub fn ipv4_netmask(s: &str) -> Result<u32, String> {
+    let len: u8 = ipv4_prefix_len(s)?;
+    Ok(u32::MAX.checked_shl(32 - u32::from(len)).unwrap_or(0))
+}
+
+/// Validates an IPv6 CIDR prefix length, and converts it to a netmask.
+///
+/// The prefix length is validated with [`ipv6_prefix_len`], and the netmask
+/// is returned as a `u128` in host byte order.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::ipv6_netmask;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=ipv6_netmask)]
+///     netmask: u128,
+/// }
+/// # let args = Args::parse_from(&["", "--netmask", "/64"]);
+/// # assert_eq!(args.netmask, 0xFFFF_FFFF_FFFF_FFFF_0000_0000_0000_0000);
+/// ```
+pub fn ipv6_netmask(s: &str) -> Result<u128, String> {
+    let len: u8 = ipv6_prefix_len(s)?;
+    Ok(u128::MAX.checked_shl(128 - u32::from(len)).unwrap_or(0))
+}
+
+/// Validates a chmod-style octal file permission mode.
+///
+/// The mode is always parsed as octal, with an optional `0o` prefix (case
+/// insensitive), and must not exceed `0o7777`.
+///
+/// | String | Value  |
+/// |--------|--------|
+/// | 644    | 0o644  |
+/// | 0755   | 0o755  |
+/// | 0o4755 | 0o4755 |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::file_mode;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=file_mode)]
+///     mode: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--mode", "0755"]);
+/// # assert_eq!(args.mode, 0o755);
+/// ```
+///
+/// ## Error Messages
+///
+/// Digits that are not octal will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--mode <mode>': invalid octal digit '8'
+/// ```
+///
+/// Modes with bits above the permission, setuid, setgid, and sticky bits will
+/// show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--mode <mode>': exceeds maximum of 0o7777
+/// ```
+pub fn file_mode(s: &str) -> Result<u32, String> {
+    const MAX: u32 = 0o7777;
+
+    let digits = strip_radix_prefix(s, 'o').unwrap_or(s);
+
+    if let Some(digit) = digits.chars().find(|c| !('0'..='7').contains(c)) {
+        return Err(format!("invalid octal digit '{digit}'"));
+    }
+
+    match u32::from_str_radix(digits, 8) {
+        Ok(mode) if mode <= MAX => Ok(mode),
+        Err(e) if digits.is_empty() => Err(stringify(e)),
+        _ => Err(String::from(RangeError::AboveMaximum(format!("{MAX:#o}")))),
+    }
+}
+
+/// Validates a [UUID] in hyphenated, bare, or hex integer form.
+///
+/// The UUID is returned as a `u128`, with the first hex digit of the UUID as
+/// the most significant nibble.
+///
+/// | String                                 | Value                                |
+/// |----------------------------------------|--------------------------------------|
+/// | `67e55044-10b1-426f-9247-bb680e5fe0c8` | `0x67e5504410b1426f9247bb680e5fe0c8` |
+/// | `67e5504410b1426f9247bb680e5fe0c8`     | `0x67e5504410b1426f9247bb680e5fe0c8` |
+/// | `0x1234`                               | `0x1234`                             |
+///
+/// Hyphenated and bare UUIDs must contain exactly 32 hex digits, values
+/// prefixed with `0x` (case insensitive) may contain fewer.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::uuid;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=uuid)]
+///     id: u128,
+/// }
+/// # let args = Args::parse_from(&["", "--id", "67e55044-10b1-426f-9247-bb680e5fe0c8"]);
+/// # assert_eq!(args.id, 0x67e5504410b1426f9247bb680e5fe0c8);
+/// ```
+///
+/// ## Error Messages
+///
+/// UUIDs with hyphens in non-canonical positions will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--id <id>': invalid UUID hyphen placement
+/// ```
+///
+/// UUIDs with the wrong number of digits will show an error message similar
+/// to this:
+///
+/// ```text
+/// error: Invalid value for '--id <id>': expected 32 hex digits in UUID, found 31
+/// ```
+///
+/// [UUID]: https://en.wikipedia.org/wiki/Universally_unique_identifier
+pub fn uuid(s: &str) -> Result<u128, String> {
+    const HYPHENATED_LEN: usize = 36;
+    const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+    const DIGITS: usize = 32;
+
+    if let Some(digits) = strip_hex_prefix(s) {
+        return u128::from_str_radix(digits, 16).map_err(stringify);
+    }
+
+    if s.contains('-')
+        && (s.len() != HYPHENATED_LEN
+            || s.char_indices()
+                .any(|(idx, c)| (c == '-') != HYPHENS.contains(&idx)))
+    {
+        return Err(String::from("invalid UUID hyphen placement"));
+    }
+
+    let digits: String = s.chars().filter(|&c| c != '-').collect();
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        Err(String::from("invalid digit found in string"))
+    } else if digits.len() != DIGITS {
+        Err(format!(
+            "expected {DIGITS} hex digits in UUID, found {}",
+            digits.len()
+        ))
+    } else {
+        u128::from_str_radix(&digits, 16).map_err(stringify)
+    }
+}
+
+/// Validates a hex color code, returning the red, green, blue, and alpha
+/// channels.
+///
+/// The color must be prefixed with `#` or `0x` (case insensitive), followed
+/// by 3, 4, 6, or 8 hex digits.
+/// Short forms expand each digit to both nibbles of the channel, and the
+/// alpha channel is `0xFF` when omitted.
+///
+/// | String    | Value                    |
+/// |-----------|--------------------------|
+/// | #F80      | (0xFF, 0x88, 0x00, 0xFF) |
+/// | #F808     | (0xFF, 0x88, 0x00, 0x88) |
+/// | #FF8800   | (0xFF, 0x88, 0x00, 0xFF) |
+/// | #FF880080 | (0xFF, 0x88, 0x00, 0x80) |
+/// | 0xFF8800  | (0xFF, 0x88, 0x00, 0xFF) |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::hex_color_rgba;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=hex_color_rgba)]
+///     color: (u8, u8, u8, u8),
+/// }
+/// # let args = Args::parse_from(&["", "--color", "#FF8800"]);
+/// # assert_eq!(args.color, (0xFF, 0x88, 0x00, 0xFF));
+/// ```
+///
+/// ## Error Messages
+///
+/// Colors with the wrong number of digits will show an error message similar
+/// to this:
+///
+/// ```text
+/// error: Invalid value for '--color <color>': expected 3, 4, 6, or 8 hex digits in color, found 5
+/// ```
+pub fn hex_color_rgba(s: &str) -> Result<(u8, u8, u8, u8), String> {
+    let digits = s
+        .strip_prefix('#')
+        .or_else(|| strip_hex_prefix(s))
+        .ok_or("expected color to start with '#' or '0x'")?;
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(String::from("invalid digit found in string"));
+    }
+
+    let channel = |hex: &str| u8::from_str_radix(hex, 16).map_err(stringify);
+    let short = |idx: usize| channel(&digits[idx..=idx]).map(|nibble| nibble * 0x11);
+
+    match digits.len() {
+        3 => Ok((short(0)?, short(1)?, short(2)?, 0xFF)),
+        4 => Ok((short(0)?, short(1)?, short(2)?, short(3)?)),
+        6 => Ok((
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+            0xFF,
+        )),
+        8 => Ok((
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+            channel(&digits[6..8])?,
+        )),
+        len => Err(format!(
+            "expected 3, 4, 6, or 8 hex digits in color, found {len}"
+        )),
+    }
+}
+
+/// Validates a hex color code, returning the channels packed into a `u32`.
+///
+/// This accepts the same formats as [`hex_color_rgba`], the channels are
+/// packed as `0xRRGGBBAA`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::hex_color;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=hex_color)]
+///     color: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--color", "#F80"]);
+/// # assert_eq!(args.color, 0xFF8800FF);
+/// ```
+pub fn hex_color(s: &str) -> Result<u32, String> {
+    let (r, g, b, a) = hex_color_rgba(s)?;
+    Ok(u32::from_be_bytes([r, g, b, a]))
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-16, and
+/// converts it to an enum with [`TryFrom`].
+///
+/// This is intended for `#[repr(u8)]` style enums, such as command bytes
+/// and opcodes, where `R` is the representation of the enum.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::repr_enum;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// #[repr(u8)]
+/// enum Command {
+///     Reset = 0x01,
+///     Read = 0x03,
+///     Write = 0x04,
+/// }
+///
+/// impl TryFrom<u8> for Command {
+///     type Error = ();
+///
+///     fn try_from(value: u8) -> Result<Self, Self::Error> {
+///         match value {
+///             0x01 => Ok(Self::Reset),
+///             0x03 => Ok(Self::Read),
+///             0x04 => Ok(Self::Write),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=repr_enum::<Command, u8>)]
+///     command: Command,
+/// }
+/// # let args = Args::parse_from(&["", "--command", "0x03"]);
+/// # assert_eq!(args.command, Command::Read);
+/// # assert_eq!(
+/// #     repr_enum::<Command, u8>("2"),
+/// #     Err(String::from("invalid discriminant 0x02, expected one of 0x01, 0x03-0x04"))
+/// # );
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that do not convert to the enum will show an error message
+/// listing the valid discriminants, similar to this:
+///
+/// ```text
+/// error: Invalid value for '--command <command>': invalid discriminant 0x02, expected one of 0x01, 0x03-0x04
+/// ```
+///
+/// The valid discriminants are only listed for representations of 16 bits
+/// or less.
+pub fn repr_enum<T, R>(s: &str) -> Result<T, String>
+where
+    <R as Num>::FromStrRadixErr: std::fmt::Display,
+    R: PrimInt,
+    R: sign::Unsigned,
+    R: std::fmt::UpperHex,
+    T: TryFrom<R>,
+{
+    let value: R = maybe_hex(s)?;
+    T::try_from(value).map_err(|_| {
+        let width = 2 * core::mem::size_of::<R>();
+        let hex = |v: R| format!("0x{v:0width$X}");
+        let mut msg = format!("invalid discriminant {}", hex(value));
+
+        if R::max_value().count_ones() <= 16 {
+            let mut ranges: Vec<(R, R)> = Vec::new();
+            let mut v = R::zero();
+            loop {
+                if T::try_from(v).is_ok() {
+                    match ranges.last_mut() {
+                        Some((_, end)) if *end + R::one() == v => *end = v,
+                        _ => ranges.push((v, v)),
+                    }
+                }
+                if v == R::max_value() {
+                    break;
+                }
+                v = v + R::one();
+            }
+
+            let valid: Vec<String> = ranges
+                .into_iter()
+                .map(|(start, end)| {
+                    if start == end {
+                        hex(start)
+                    } else {
+                        format!("{}-{}", hex(start), hex(end))
+                    }
+                })
+                .collect();
+            if !valid.is_empty() {
+                msg.push_str(", expected one of ");
+                msg.push_str(&valid.join(", "));
+            }
+        }
+
+        msg
+    })
+}
+
+/// Validates a 7-bit [I2C] address that can be base-10 or base-16.
+///
+/// This accepts the reserved addresses, see [`i2c_address_unreserved`] to
+/// reject them.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::i2c_address;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=i2c_address)]
+///     address: u8,
+/// }
+/// # let args = Args::parse_from(&["", "--address", "0x50"]);
+/// # assert_eq!(args.address, 0x50);
+/// ```
+///
+/// ## Error Messages
+///
+/// Addresses that do not fit in 7 bits will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--address <address>': exceeds maximum of 0x7F
+/// ```
+///
+/// [I2C]: https://en.wikipedia.org/wiki/I%C2%B2C
+pub fn i2c_address(s: &str) -> Result<u8, String> {
+    const MAX: u32 = 0x7F;
+
+    let address: u32 = maybe_hex(s)?;
+    u8::try_from(address)
+        .ok()
+        .filter(|&address| u32::from(address) <= MAX)
+        .ok_or_else(|| String::from(RangeError::AboveMaximum(format!("{MAX:#X}"))))
+}
+
+/// Validates a 7-bit [I2C] address that can be base-10 or base-16, rejecting
+/// the reserved addresses.
+///
+/// | Address       | Reserved for                   |
+/// |---------------|--------------------------------|
+/// | `0x00`        | general call or START byte     |
+/// | `0x01`        | CBUS                           |
+/// | `0x02`        | a different bus format         |
+/// | `0x03`        | future purposes                |
+/// | `0x04`-`0x07` | Hs-mode controller codes       |
+/// | `0x78`-`0x7B` | 10-bit addressing              |
+/// | `0x7C`-`0x7F` | device ID                      |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::i2c_address_unreserved;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=i2c_address_unreserved)]
+///     address: u8,
+/// }
+/// # let args = Args::parse_from(&["", "--address", "0x50"]);
+/// # assert_eq!(args.address, 0x50);
+/// ```
+///
+/// ## Error Messages
+///
+/// Reserved addresses will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--address <address>': address 0x78 is reserved for 10-bit addressing
+/// ```
+///
+/// [I2C]: https://en.wikipedia.org/wiki/I%C2%B2C
+pub fn i2c_address_unreserved(s: &str) -> Result<u8, String> {
+    let address = i2c_address(s)?;
+
+    let reason = match address {
+        0x00 => "general call or START byte",
+        0x01 => "CBUS",
+        0x02 => "a different bus format",
+        0x03 => "future purposes",
+        0x04..=0x07 => "Hs-mode controller codes",
+        0x78..=0x7B => "10-bit addressing",
+        0x7C..=0x7F => "device ID",
+        _ => return Ok(address),
+    };
+
+    Err(format!("address {address:#04X} is reserved for {reason}"))
+}
+
+/// Validates a UTC offset in `±HH:MM` or `±HHMM` form, such as `+05:30` or
+/// `-0800`, and converts it to signed minutes.
+///
+/// The sign is required, and `Z` (case insensitive) is accepted for UTC.
+/// Offsets are limited to ±14:00, the range of offsets in use, and the
+/// minutes must be less than 60.
+///
+/// | String   | Value |
+/// |----------|-------|
+/// | `+05:30` | 330   |
+/// | `-0800`  | -480  |
+/// | `+00:00` | 0     |
+/// | `Z`      | 0     |
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::utc_offset;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=utc_offset, allow_hyphen_values=true)]
+///     offset: i32,
+/// }
+/// # let args = Args::parse_from(&["", "--offset", "+05:30"]);
+/// # assert_eq!(args.offset, 330);
+/// # let args = Args::parse_from(&["", "--offset", "-0800"]);
+/// # assert_eq!(args.offset, -480);
+/// ```
+///
+/// ## Error Messages
+///
+/// Offsets beyond ±14:00 will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--offset <offset>': exceeds maximum of +14:00
+/// ```
+///
+/// Offsets that are not in either form will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--offset <offset>': expected an offset such as +05:30 or -0800
+/// ```
+pub fn utc_offset(s: &str) -> Result<i32, String> {
+    const MAX: i32 = 14 * 60;
+    const FORMAT_MSG: &str = "expected an offset such as +05:30 or -0800";
+
+    if s.eq_ignore_ascii_case("z") {
+        return Ok(0);
+    }
+
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').ok_or(FORMAT_MSG)?),
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some(split) => split,
+        None if rest.len() == 4 && rest.is_char_boundary(2) => rest.split_at(2),
+        None => return Err(String::from(FORMAT_MSG)),
+    };
+
+    let field = |digits: &str| -> Result<i32, String> {
+        if digits.len() != 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(String::from(FORMAT_MSG));
+        }
+        digits.parse().map_err(stringify)
+    };
+    let hours: i32 = field(hours)?;
+    let minutes: i32 = field(minutes)?;
+
+    if minutes >= 60 {
+        return Err(format!("minutes must be less than 60, found {minutes}"));
+    }
+
+    let total: i32 = hours * 60 + minutes;
+    if total > MAX && negative {
+        Err(String::from(RangeError::BelowMinimum("-14:00")))
+    } else if total > MAX {
+        Err(String::from(RangeError::AboveMaximum("+14:00")))
+    } else if negative {
+        Ok(-total)
+    } else {
+        Ok(total)
+    }
+}
+
+/// Validates a taskset-style list of CPUs, such as `0-3,8,10-11`.
+///
+/// Each element is a base-10 CPU index or an inclusive range of indices,
+/// every index must be at most `max`.
+/// The CPUs are returned in ascending order without duplicates, see
+/// [`cpu_mask`] for the CPUs as a bitmask.
+/// At most 65536 CPUs are returned.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::cpu_list;
+///
+/// fn cpus(s: &str) -> Result<Vec<usize>, String> {
+///     cpu_list(s, 15)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     // the fully qualified path stops clap from parsing each value
+///     // separately
+///     #[clap(long, value_parser=cpus)]
+///     cpus: ::std::vec::Vec<usize>,
+/// }
+/// # let args = Args::parse_from(&["", "--cpus", "0-3,8,10-11"]);
+/// # assert_eq!(args.cpus, [0, 1, 2, 3, 8, 10, 11]);
+/// ```
+///
+/// ## Error Messages
+///
+/// CPUs above the maximum will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--cpus <cpus>': invalid CPU '16': exceeds maximum of 15
+/// ```
+///
+/// Ranges with the first CPU after the last will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--cpus <cpus>': invalid CPU range '3-0', 3 is greater than 0
+/// ```
+///
+/// Lists with more than 65536 CPUs will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--cpus <cpus>': CPU list has more than 65536 CPUs
+/// ```
+pub fn cpu_list(s: &str, max: usize) -> Result<Vec<usize>, String> {
+    let ranges = cpu_ranges(s, max)?;
+
+    let count = ranges.iter().fold(0usize, |count, (first, last)| {
+        count.saturating_add(last - first).saturating_add(1)
+    });
+    if count > MAX_CPUS {
+        return Err(format!("CPU list has more than {MAX_CPUS} CPUs"));
+    }
+
+    Ok(ranges
+        .into_iter()
+        .flat_map(|(first, last)| first..=last)
+        .collect())
+}
+
+// Upper limit on the number of CPUs returned by `cpu_list`, so that a range
+// such as `0-18446744073709551615` does not exhaust memory.
+const MAX_CPUS: usize = 1 << 16;
+
+// parses a list of CPUs to inclusive ranges, in ascending order without
+// overlaps
+fn cpu_ranges(s: &str, max: usize) -> Result<Vec<(usize, usize)>, String> {
+    if s.trim().is_empty() {
+        return Err(String::from("empty CPU list"));
+    }
+
+    let cpu = |s: &str| {
+        let s = s.trim();
+        number_range(s, 0, max).map_err(|e| format!("invalid CPU '{s}': {e}"))
+    };
+
+    let mut ranges = Vec::new();
+    for element in s.split(',').map(str::trim) {
+        match element.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (cpu(first)?, cpu(last)?);
+                if first > last {
+                    return Err(format!(
+                        "invalid CPU range '{element}', {first} is greater than {last}"
+                    ));
+                }
+                ranges.push((first, last));
+            }
+            None => {
+                let cpu = cpu(element)?;
+                ranges.push((cpu, cpu));
+            }
+        }
+    }
+
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (first, last) in ranges {
+        match merged.last_mut() {
+            Some((_, prev)) if first <= prev.saturating_add(1) => *prev = last.max(*prev),
+            _ => merged.push((first, last)),
+        }
+    }
+    Ok(merged)
+}
+
+/// Validates a taskset-style list of CPUs, such as `0-3,8,10-11`, and
+/// converts it to a bitmask.
+///
+/// Bit `n` of the mask is set for CPU `n`, the list is parsed as with
+/// [`cpu_list`].
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::cpu_mask;
+///
+/// fn affinity(s: &str) -> Result<u64, String> {
+///     cpu_mask(s, 63)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=affinity)]
+///     affinity: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--affinity", "0-3,8,10-11"]);
+/// # assert_eq!(args.affinity, 0b1101_0000_1111);
+/// ```
+///
+/// ## Error Messages
+///
+/// CPUs that do not fit in the mask will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--affinity <affinity>': CPU 64 does not fit in a 64-bit mask
+/// ```
+pub fn cpu_mask<T: PrimInt + sign::Unsigned>(s: &str, max: usize) -> Result<T, String> {
+    let bits = T::zero().count_zeros() as usize;
+
+    cpu_ranges(s, max)?
+        .into_iter()
+        .try_fold(T::zero(), |mask, (first, last)| {
+            if last >= bits {
+                let cpu = first.max(bits);
+                Err(format!("CPU {cpu} does not fit in a {bits}-bit mask"))
+            } else {
+                Ok((first..=last).fold(mask, |mask, cpu| mask | T::one() << cpu))
+            }
+        })
+}
+
+/// Validates a `NAME=VALUE` pair, parsing the value with `value`.
+///
+/// The pair is split at the first `=`, the name must not be empty.
+///
+/// # Example
+///
+/// This allows for build-tool style definitions, with base-10 or base-16
+/// values.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{key_value, maybe_hex};
+///
+/// fn define(s: &str) -> Result<(String, u32), String> {
+///     key_value(s, maybe_hex)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short = 'D', long, value_parser=define)]
+///     define: Vec<(String, u32)>,
+/// }
+/// # let args = Args::parse_from(&["", "-D", "FOO=0x10", "-D", "BAR=3"]);
+/// # assert_eq!(args.define, [(String::from("FOO"), 16), (String::from("BAR"), 3)]);
+/// ```
+///
+/// Range checks are applied to the value by passing a range parser:
+///
+/// ```
+/// use clap_num::{key_value, si_number_range};
+///
+/// fn limit(s: &str) -> Result<(String, u64), String> {
+///     key_value(s, |v| si_number_range(v, 0, 1_000_000))
+/// }
+/// # assert_eq!(limit("rate=1M"), Ok((String::from("rate"), 1_000_000)));
+/// # assert_eq!(limit("rate=2M"), Err(String::from("exceeds maximum of 1M")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values without a `=` will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--define <define>': expected NAME=VALUE
+/// ```
+///
+/// Values with an empty name will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--define <define>': empty name before '='
+/// ```
+pub fn key_value<T, F>(s: &str, value: F) -> Result<(String, T), String>
+where
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    let (name, val) = s
+        .split_once('=')
+        .ok_or_else(|| String::from("expected NAME=VALUE"))?;
+
+    if name.is_empty() {
+        return Err(String::from("empty name before '='"));
+    }
+
+    Ok((String::from(name), value(val)?))
+}
+
+/// Validates a named value from `names`, or any other value with `value`.
+///
+/// Names are case insensitive, and take precedence over `value`.
+///
+/// # Example
+///
+/// This allows for a sample rate to be passed as a preset name, or as a
+/// number with an SI symbol.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{named_or, si_number_range};
+///
+/// const RATES: [(&str, u32); 2] = [("cd", 44_100), ("dvd", 48_000)];
+///
+/// fn sample_rate(s: &str) -> Result<u32, String> {
+///     named_or(s, &RATES, |v| si_number_range(v, 8_000, 192_000))
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=sample_rate)]
+///     sample_rate: u32,
+/// }
+/// # let args = Args::parse_from(&["", "--sample-rate", "cd"]);
+/// # assert_eq!(args.sample_rate, 44_100);
+/// # assert_eq!(sample_rate("96k"), Ok(96_000));
+/// # assert_eq!(sample_rate("1M"), Err(String::from("exceeds maximum of 192k")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are neither a name nor a number will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--sample-rate <sample-rate>': unknown name 'bluray', expected a number or one of cd, dvd
+/// ```
+pub fn named_or<T, F>(s: &str, names: &[(&str, T)], value: F) -> Result<T, String>
+where
+    T: Clone,
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    if let Some((_, val)) = names.iter().find(|(name, _)| name.eq_ignore_ascii_case(s)) {
+        return Ok(val.clone());
+    }
+
+    value(s).map_err(|e| {
+        // errors for values that look like numbers are more specific
+        if s.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.')) {
+            e
+        } else {
+            let known: Vec<&str> = names.iter().map(|(name, _)| *name).collect();
+            format!(
+                "unknown name '{s}', expected a number or one of {}",
+                known.join(", ")
+            )
+        }
+    })
+}
+
+/// Validates a value with `parse`, keeping the string it was parsed from.
+///
+/// This allows applications to show a value exactly as the user wrote it,
+/// such as `3k3`, while the validation is done by `parse`.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{si_number_range, verbatim, Verbatim};
+///
+/// fn resistance(s: &str) -> Result<Verbatim<u32>, String> {
+///     verbatim(s, |v| si_number_range(v, 1_000, 999_999))
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long, value_parser=resistance)]
+///     resistance: Verbatim<u32>,
+/// }
+/// # let args = Args::parse_from(&["", "-r", "3k3"]);
+/// # assert_eq!(args.resistance.value, 3_300);
+/// # assert_eq!(args.resistance.input, "3k3");
+/// # assert_eq!(resistance("1M"), Err(String::from("exceeds maximum of 999.999k")));
+/// ```
+pub fn verbatim<T, F>(s: &str, parse: F) -> Result<Verbatim<T>, String>
+where
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    Ok(Verbatim {
+        value: parse(s)?,
+        input: String::from(s),
+    })
 }
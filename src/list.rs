@@ -0,0 +1,461 @@
+use crate::NumberParser;
+use core::cmp::Ordering;
+use core::fmt::{self, Display};
+use num_traits::{CheckedAdd, Float, Zero};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+type SumCheck<T> = Arc<dyn Fn(&[T]) -> Result<(), String> + Send + Sync>;
+
+/// Builder for a parser of delimited lists of numbers.
+///
+/// Each element is parsed with a [`NumberParser`], then the constraints on
+/// the whole list are checked.
+/// For arguments that take multiple values, see [`check`](Self::check) to
+/// check the values collected by clap.
+/// Whitespace around each element is ignored, and an empty string is an
+/// empty list.
+///
+/// # Example
+///
+/// This allows for up to 4 hex or decimal channels to be passed as one
+/// comma-separated value.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{maybe_hex, ListParser, NumberParser};
+///
+/// fn channels(s: &str) -> Result<Vec<u8>, String> {
+///     ListParser::new(NumberParser::new(maybe_hex::<u8>).max(0x0F))
+///         .max_len(4)
+///         .parse(s)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     // the fully qualified path stops clap from parsing each value
+///     // separately
+///     #[clap(long, value_parser=channels)]
+///     channels: ::std::vec::Vec<u8>,
+/// }
+/// # let args = Args::parse_from(&["", "--channels", "1,0x2, 15"]);
+/// # assert_eq!(args.channels, [1, 2, 15]);
+/// # assert!(Args::try_parse_from(&["", "--channels", "1,2,3,4,5"]).is_err());
+/// ```
+///
+/// ## Error Messages
+///
+/// Invalid elements will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--channels <channels>': invalid element '0x10': exceeds maximum of 15
+/// ```
+///
+/// Lists with too many elements will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--channels <channels>': expected at most 4 values, got 5
+/// ```
+#[derive(Clone)]
+pub struct ListParser<T> {
+    item: NumberParser<T>,
+    delimiter: char,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    sort: bool,
+    dedup: bool,
+    require_sorted: bool,
+    require_increasing: bool,
+    require_unique: bool,
+    sum: Option<SumCheck<T>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for ListParser<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListParser")
+            .field("item", &self.item)
+            .field("delimiter", &self.delimiter)
+            .field("min_len", &self.min_len)
+            .field("max_len", &self.max_len)
+            .field("sort", &self.sort)
+            .field("dedup", &self.dedup)
+            .field("require_sorted", &self.require_sorted)
+            .field("require_increasing", &self.require_increasing)
+            .field("require_unique", &self.require_unique)
+            .field("sum", &self.sum.is_some())
+            .finish()
+    }
+}
+
+impl<T> ListParser<T> {
+    /// Creates a new parser for comma-separated lists, parsing each element
+    /// with `item`.
+    pub fn new(item: NumberParser<T>) -> Self {
+        Self {
+            item,
+            delimiter: ',',
+            min_len: None,
+            max_len: None,
+            sort: false,
+            dedup: false,
+            require_sorted: false,
+            require_increasing: false,
+            require_unique: false,
+            sum: None,
+        }
+    }
+
+    /// Sets the delimiter between elements, `,` by default.
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the minimum number of elements, inclusive.
+    #[must_use]
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.min_len = Some(min_len);
+        self
+    }
+
+    /// Sets the maximum number of elements, inclusive.
+    #[must_use]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Sets the exact number of elements, such as 3 for an RGB triple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ListParser, NumberParser};
+    ///
+    /// let parser = ListParser::new(NumberParser::<u8>::decimal()).len(3);
+    /// assert_eq!(parser.parse("255,128,0"), Ok(vec![255, 128, 0]));
+    /// assert_eq!(
+    ///     parser.parse("255,128"),
+    ///     Err(String::from("expected exactly 3 values, got 2"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn len(self, len: usize) -> Self {
+        self.min_len(len).max_len(len)
+    }
+
+    /// Sorts the list in ascending order.
+    #[must_use]
+    pub fn sort(mut self) -> Self {
+        self.sort = true;
+        self
+    }
+
+    /// Removes duplicate elements, keeping the first of each.
+    #[must_use]
+    pub fn dedup(mut self) -> Self {
+        self.dedup = true;
+        self
+    }
+
+    /// Rejects lists that are not in ascending order.
+    ///
+    /// Equal elements are allowed, use
+    /// [`require_increasing`](Self::require_increasing) for strictly
+    /// ascending lists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ListParser, NumberParser};
+    ///
+    /// let parser = ListParser::new(NumberParser::<u32>::decimal()).require_sorted();
+    /// assert_eq!(parser.parse("1,5,9"), Ok(vec![1, 5, 9]));
+    /// assert_eq!(
+    ///     parser.parse("1,9,5"),
+    ///     Err(String::from("element '5' is less than the previous element '9'"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn require_sorted(mut self) -> Self {
+        self.require_sorted = true;
+        self
+    }
+
+    /// Rejects lists that are not in strictly ascending order, such as
+    /// histogram bucket edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ListParser, NumberParser};
+    ///
+    /// let parser = ListParser::new(NumberParser::<f64>::decimal()).require_increasing();
+    /// assert_eq!(parser.parse("0,0.5,1"), Ok(vec![0.0, 0.5, 1.0]));
+    /// assert_eq!(
+    ///     parser.parse("0,0.5,0.5"),
+    ///     Err(String::from(
+    ///         "element '0.5' is not greater than the previous element '0.5'"
+    ///     ))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn require_increasing(mut self) -> Self {
+        self.require_increasing = true;
+        self
+    }
+
+    /// Rejects lists with duplicate elements.
+    ///
+    /// Elements are compared by value, the error names the earlier element
+    /// when it is written differently, such as `1` and `0x1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{maybe_hex, ListParser, NumberParser};
+    ///
+    /// let parser = ListParser::new(NumberParser::new(maybe_hex::<u8>)).require_unique();
+    /// assert_eq!(parser.parse("1,2,3"), Ok(vec![1, 2, 3]));
+    /// assert_eq!(
+    ///     parser.parse("1,2,0x1"),
+    ///     Err(String::from("duplicate element '0x1', equal to '1'"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn require_unique(mut self) -> Self {
+        self.require_unique = true;
+        self
+    }
+
+    /// Rejects lists with elements that do not sum to `target`, such as
+    /// percentages that must total 100.
+    ///
+    /// The sum is of the returned list, after [`dedup`](Self::dedup) removes
+    /// duplicate elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ListParser, NumberParser};
+    ///
+    /// let parser = ListParser::new(NumberParser::<u8>::decimal()).require_sum(100);
+    /// assert_eq!(parser.parse("50,30,20"), Ok(vec![50, 30, 20]));
+    /// assert_eq!(
+    ///     parser.parse("50,30,10"),
+    ///     Err(String::from("values sum to 90, expected 100"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn require_sum(mut self, target: T) -> Self
+    where
+        T: CheckedAdd + Zero + PartialEq + Display + Send + Sync + 'static,
+    {
+        self.sum = Some(Arc::new(move |values: &[T]| {
+            match values
+                .iter()
+                .try_fold(T::zero(), |sum, val| sum.checked_add(val))
+            {
+                Some(sum) if sum == target => Ok(()),
+                Some(sum) => Err(format!("values sum to {sum}, expected {target}")),
+                None => Err(format!("sum of values overflows, expected {target}")),
+            }
+        }));
+        self
+    }
+
+    /// Rejects lists with elements that do not sum to `target` within
+    /// `epsilon`, such as weights that must total 1.0.
+    ///
+    /// As with [`require_sum`](Self::require_sum), the sum is of the returned
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ListParser, NumberParser};
+    ///
+    /// let parser = ListParser::new(NumberParser::<f64>::decimal()).require_sum_within(1.0, 1e-9);
+    /// assert_eq!(parser.parse("0.1,0.2,0.7"), Ok(vec![0.1, 0.2, 0.7]));
+    /// assert_eq!(
+    ///     parser.parse("0.5,0.25"),
+    ///     Err(String::from("values sum to 0.75, expected 1±0.000000001"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn require_sum_within(mut self, target: T, epsilon: T) -> Self
+    where
+        T: Float + Display + Send + Sync + 'static,
+    {
+        self.sum = Some(Arc::new(move |values: &[T]| {
+            let sum = values.iter().fold(T::zero(), |sum, &val| sum + val);
+            if (sum - target).abs() <= epsilon {
+                Ok(())
+            } else {
+                Err(format!("values sum to {sum}, expected {target}±{epsilon}"))
+            }
+        }));
+        self
+    }
+
+    fn check_len(&self, len: usize) -> Result<(), String> {
+        let values = |n: usize| if n == 1 { "value" } else { "values" };
+        match (self.min_len, self.max_len) {
+            (Some(min), Some(max)) if min == max && len != min => {
+                Err(format!("expected exactly {min} {}, got {len}", values(min)))
+            }
+            (Some(min), _) if len < min => Err(format!(
+                "expected at least {min} {}, got {len}",
+                values(min)
+            )),
+            (_, Some(max)) if len > max => {
+                Err(format!("expected at most {max} {}, got {len}", values(max)))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: PartialOrd + Display> ListParser<T> {
+    /// Parses a list.
+    pub fn parse(&self, s: &str) -> Result<Vec<T>, String> {
+        let elements = if s.trim().is_empty() {
+            Vec::new()
+        } else {
+            s.split(self.delimiter)
+                .map(str::trim)
+                .map(|e| {
+                    self.item
+                        .parse(e)
+                        .map(|val| (val, Cow::Borrowed(e)))
+                        .map_err(|msg| format!("invalid element '{e}': {msg}"))
+                })
+                .collect::<Result<Vec<(T, Cow<str>)>, String>>()?
+        };
+
+        self.check_elements(elements)
+    }
+
+    /// Parses a single element of a list with the element parser.
+    ///
+    /// See [`check`](Self::check) for details.
+    pub fn parse_element(&self, s: &str) -> Result<T, String> {
+        self.item.parse(s.trim())
+    }
+
+    /// Checks the constraints on the whole list for values that were
+    /// already parsed, such as the values collected by clap for an argument
+    /// that takes multiple values.
+    ///
+    /// The list is sorted and deduplicated if set, as with
+    /// [`parse`](Self::parse).
+    ///
+    /// # Example
+    ///
+    /// This allows for 1 to 4 channels, passed as separate values or
+    /// delimited with `,`.
+    ///
+    /// ```
+    /// use clap::{CommandFactory, Parser};
+    /// use clap_num::{maybe_hex, ListParser, NumberParser};
+    ///
+    /// fn channel_list() -> ListParser<u8> {
+    ///     ListParser::new(NumberParser::new(maybe_hex::<u8>).max(0x0F))
+    ///         .max_len(4)
+    ///         .require_unique()
+    /// }
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     #[clap(long, num_args=1.., value_delimiter=',', value_parser=|s: &str| channel_list().parse_element(s))]
+    ///     channels: Vec<u8>,
+    /// }
+    ///
+    /// let args = Args::parse_from(&["", "--channels", "1,0x2", "15"]);
+    /// let channels = channel_list().check(args.channels).unwrap_or_else(|e| {
+    ///     Args::command()
+    ///         .error(clap::error::ErrorKind::ValueValidation, e)
+    ///         .exit()
+    /// });
+    /// assert_eq!(channels, [1, 2, 15]);
+    /// # let args = Args::parse_from(&["", "--channels", "1", "2", "0x1"]);
+    /// # assert_eq!(
+    /// #     channel_list().check(args.channels),
+    /// #     Err(String::from("duplicate element '1'"))
+    /// # );
+    /// ```
+    ///
+    /// ## Error Messages
+    ///
+    /// Lists with too many elements will show an error message similar to
+    /// this:
+    ///
+    /// ```text
+    /// error: expected at most 4 values, got 5
+    /// ```
+    pub fn check(&self, values: Vec<T>) -> Result<Vec<T>, String> {
+        let elements = values
+            .into_iter()
+            .map(|val| {
+                let e = Cow::Owned(val.to_string());
+                (val, e)
+            })
+            .collect();
+        self.check_elements(elements)
+    }
+
+    // checks the constraints on the whole list, with the text of each
+    // element for error messages
+    fn check_elements(&self, elements: Vec<(T, Cow<str>)>) -> Result<Vec<T>, String> {
+        self.check_len(elements.len())?;
+
+        for (idx, (val, e)) in elements.iter().enumerate() {
+            let previous = &elements[..idx];
+            let duplicate = if self.require_unique {
+                previous.iter().find(|(prev, _)| prev == val)
+            } else {
+                None
+            };
+            if let Some((_, prev_e)) = duplicate {
+                return Err(if prev_e == e {
+                    format!("duplicate element '{e}'")
+                } else {
+                    format!("duplicate element '{e}', equal to '{prev_e}'")
+                });
+            }
+            if let Some((prev, prev_e)) = previous.last() {
+                if self.require_increasing && val <= prev {
+                    return Err(format!(
+                        "element '{e}' is not greater than the previous element '{prev_e}'"
+                    ));
+                }
+                if self.require_sorted && val < prev {
+                    return Err(format!(
+                        "element '{e}' is less than the previous element '{prev_e}'"
+                    ));
+                }
+            }
+        }
+
+        let mut values: Vec<T> = elements.into_iter().map(|(val, _)| val).collect();
+        if self.sort {
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        }
+        if self.dedup {
+            let mut unique: Vec<T> = Vec::with_capacity(values.len());
+            for val in values {
+                if !unique.contains(&val) {
+                    unique.push(val);
+                }
+            }
+            values = unique;
+        }
+        // the sum is of the returned list, without the removed duplicates
+        if let Some(sum) = &self.sum {
+            sum(&values)?;
+        }
+
+        Ok(values)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{maybe_bin, maybe_hex, maybe_oct, number_range, si_number};
+use core::str::FromStr;
+use num_traits::identities::Zero;
+use num_traits::{sign, CheckedAdd, CheckedMul, CheckedSub, Num};
+use std::ffi::OsStr;
+
+/// Validates an argument that may not be valid UTF-8 with any parser, such
+/// as in a custom [`TypedValueParser`].
+///
+/// Arguments that are not valid UTF-8 are rejected with an error showing the
+/// argument with the invalid sequences replaced by `�`.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::{parse_os, si_number};
+/// use std::ffi::OsStr;
+///
+/// assert_eq!(parse_os(OsStr::new("4k7"), si_number::<u32>), Ok(4_700));
+/// ```
+///
+/// ## Error Messages
+///
+/// Arguments that are not valid UTF-8 will show an error message similar to
+/// this:
+///
+/// ```text
+/// error: Invalid value for '--address <address>': argument was not valid UTF-8: '0x1�'
+/// ```
+///
+/// [`TypedValueParser`]: https://docs.rs/clap/latest/clap/builder/trait.TypedValueParser.html
+pub fn parse_os<T, F>(s: &OsStr, parse: F) -> Result<T, String>
+where
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    match s.to_str() {
+        Some(s) => parse(s),
+        None => Err(format!(
+            "argument was not valid UTF-8: '{}'",
+            s.to_string_lossy()
+        )),
+    }
+}
+
+/// Validates a signed or unsigned integer value that may not be valid UTF-8,
+/// see [`number_range`].
+pub fn number_range_os<T>(s: &OsStr, min: T, max: T) -> Result<T, String>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: Ord,
+    T: PartialOrd,
+    T: std::fmt::Display,
+{
+    parse_os(s, |s| number_range(s, min, max))
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-16 and
+/// may not be valid UTF-8, see [`maybe_hex`].
+pub fn maybe_hex_os<T: Num + sign::Unsigned>(s: &OsStr) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    parse_os(s, maybe_hex)
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-2 and may
+/// not be valid UTF-8, see [`maybe_bin`].
+pub fn maybe_bin_os<T: Num + sign::Unsigned>(s: &OsStr) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    parse_os(s, maybe_bin)
+}
+
+/// Validates an unsigned integer value that can be base-10 or base-8 and may
+/// not be valid UTF-8, see [`maybe_oct`].
+pub fn maybe_oct_os<T: Num + sign::Unsigned>(s: &OsStr) -> Result<T, String>
+where
+    <T as Num>::FromStrRadixErr: std::fmt::Display,
+{
+    parse_os(s, maybe_oct)
+}
+
+/// Validates a signed or unsigned integer value with a metric prefix that
+/// may not be valid UTF-8, see [`si_number`].
+pub fn si_number_os<T>(s: &OsStr) -> Result<T, String>
+where
+    <T as TryFrom<u128>>::Error: std::fmt::Display,
+    <T as FromStr>::Err: std::fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    parse_os(s, si_number)
+}
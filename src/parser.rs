@@ -0,0 +1,1282 @@
+use crate::{
+    Bounds, RangeError, SiParser, EMPTY_MSG, INVALID_DIGIT_MSG, NEGATIVE_MSG, NOT_AN_INTEGER_MSG,
+    OVERFLOW_MSG, UNDERFLOW_MSG,
+};
+use core::fmt::{self, Display};
+use core::ops::Range;
+use std::borrow::Cow;
+use std::io::Read;
+use std::sync::Arc;
+
+static TOO_PRECISE_MSG: &str = "too many decimal places";
+static SEPARATOR_MSG: &str = "digit separator";
+
+// characters commonly used to group digits
+const SEPARATORS: [char; 4] = ['_', ',', '\'', ' '];
+
+type Validator<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+type BoundFormatter<T> = Arc<dyn Fn(&T) -> String + Send + Sync>;
+type WarningHandler = Arc<dyn Fn(&str) + Send + Sync>;
+type DefaultValue<T> = Arc<dyn Fn() -> T + Send + Sync>;
+type NonFinite<T> = fn(&str) -> Option<T>;
+type BaseParser<T> = Arc<dyn Fn(&str) -> Result<T, ErrorMessage> + Send + Sync>;
+
+// A bound formatted with the formatter of the parser, if any.
+struct Bound<'a, T> {
+    bound: &'a T,
+    formatter: Option<&'a BoundFormatter<T>>,
+}
+
+impl<T: Display> Display for Bound<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.formatter {
+            Some(formatter) => f.write_str(&formatter(self.bound)),
+            None => self.bound.fmt(f),
+        }
+    }
+}
+
+/// Kinds of errors returned by the parsers in this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The value is empty.
+    Empty,
+    /// The value contains an invalid digit.
+    InvalidDigit,
+    /// The value is too large to fit in the target type.
+    PosOverflow,
+    /// The value is too small to fit in the target type.
+    NegOverflow,
+    /// The value has a fractional part.
+    NotAnInteger,
+    /// The value is negative, and the target type is unsigned.
+    Negative,
+    /// The value exceeds the maximum of the range.
+    AboveMaximum,
+    /// The value is less than the minimum of the range.
+    BelowMinimum,
+    /// The value has more decimal places than allowed.
+    TooPrecise,
+    /// The value contains a digit separator that is not allowed.
+    Separator,
+    /// Any other error.
+    Other,
+}
+
+impl ErrorKind {
+    /// Returns the kind of an error message returned by a parser in this
+    /// crate.
+    ///
+    /// Only the messages that do not depend on the value are recognized,
+    /// such as "invalid digit found in string", other messages, such as
+    /// range errors, are [`ErrorKind::Other`].
+    /// [`NumberParser::try_parse`] returns the kind reported by the parser
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{maybe_hex, maybe_hex_range, ErrorKind};
+    ///
+    /// let err = maybe_hex::<u8>("0x100").unwrap_err();
+    /// assert_eq!(ErrorKind::of(&err), ErrorKind::PosOverflow);
+    ///
+    /// let err = maybe_hex_range::<u8>("0x80", 0, 0x7F).unwrap_err();
+    /// assert_eq!(ErrorKind::of(&err), ErrorKind::Other);
+    /// ```
+    pub fn of(message: &str) -> Self {
+        match message {
+            m if m == EMPTY_MSG => Self::Empty,
+            m if m == INVALID_DIGIT_MSG => Self::InvalidDigit,
+            m if m == OVERFLOW_MSG => Self::PosOverflow,
+            m if m == UNDERFLOW_MSG => Self::NegOverflow,
+            m if m == NOT_AN_INTEGER_MSG => Self::NotAnInteger,
+            m if m == NEGATIVE_MSG => Self::Negative,
+            _ => Self::Other,
+        }
+    }
+}
+
+// An error message with its kind, and the span of the part of the value that
+// caused it, if the parser knows it.
+//
+// This is returned by the parsers that report the kind and span to
+// `NumberParser`, and displays as the message of the parsers that return a
+// `String`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ErrorMessage {
+    kind: ErrorKind,
+    message: String,
+    span: Option<Range<usize>>,
+}
+
+impl ErrorMessage {
+    pub(crate) fn new(kind: ErrorKind, message: impl Display) -> Self {
+        Self {
+            kind,
+            message: message.to_string(),
+            span: None,
+        }
+    }
+
+    // sets the span of the part of the value that caused the error
+    pub(crate) fn at(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub(crate) fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ErrorMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+// messages of parsers that return a `String` have the kind of the message
+impl From<String> for ErrorMessage {
+    fn from(message: String) -> Self {
+        Self::new(ErrorKind::of(&message), message)
+    }
+}
+
+impl From<ErrorMessage> for String {
+    fn from(e: ErrorMessage) -> Self {
+        e.message
+    }
+}
+
+/// Placement of the digit separators accepted by
+/// [`NumberParser::separators`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// Separators are accepted between any two digits, such as `1_0_0`.
+    Anywhere,
+    /// Separators must split the integer part into groups of this many
+    /// digits, counting from the right, such as `1_000_000` for 3 or
+    /// `0xDEAD_BEEF` for 4.
+    ///
+    /// Separators are not accepted after the integer part, such as in the
+    /// decimal places.
+    Every(usize),
+}
+
+/// An error with the kind of error and the span of the value that caused it.
+///
+/// The span is the byte range of the offending part of the value, such as
+/// an invalid digit, the decimal places beyond the limit, or an unknown
+/// suffix, which allows front-ends to underline exactly where the problem is.
+/// Errors that apply to the whole value, such as range errors, span the
+/// whole value.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::{maybe_hex, ErrorKind, ParseError};
+///
+/// let input = "0x1G";
+/// let err = ParseError::new(input, maybe_hex::<u8>(input).unwrap_err());
+/// assert_eq!(err.kind(), ErrorKind::InvalidDigit);
+/// assert_eq!(err.span(), 3..4);
+/// assert_eq!(&input[err.span()], "G");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ErrorKind,
+    message: String,
+    input: String,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    /// Creates an error from a value and the error message a parser in this
+    /// crate returned for it.
+    ///
+    /// The kind is determined with [`ErrorKind::of`], and the span is
+    /// located in the value from the kind and the message.
+    /// Use [`NumberParser::try_parse`] for the kind and span reported by the
+    /// parser, such as the span of an invalid digit in a value with a metric
+    /// prefix.
+    pub fn new(input: &str, message: impl Into<String>) -> Self {
+        let message = message.into();
+        let kind = ErrorKind::of(&message);
+        let span = locate(input, kind, &message);
+        Self {
+            kind,
+            message,
+            input: String::from(input),
+            span,
+        }
+    }
+
+    /// Returns the kind of error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the value that caused the error.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the byte range of the value that caused the error.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Reports the value as the source code, with a label on the span of the
+/// value that caused the error.
+///
+/// This is only available with the `miette` feature.
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for ParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(format!("clap_num::{:?}", self.kind)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let help = match self.kind {
+            ErrorKind::Empty => "pass a value",
+            ErrorKind::InvalidDigit => "remove the characters that are not digits",
+            ErrorKind::NotAnInteger => "pass a whole number",
+            ErrorKind::Negative => "remove the minus sign",
+            ErrorKind::TooPrecise => "remove the extra decimal places",
+            ErrorKind::Separator => "remove the digit separators",
+            _ => return None,
+        };
+        Some(Box::new(help))
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        Some(&self.input)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let label = match self.kind {
+            ErrorKind::Empty => "empty value",
+            ErrorKind::InvalidDigit => "invalid digit",
+            ErrorKind::PosOverflow | ErrorKind::AboveMaximum => "too large",
+            ErrorKind::NegOverflow | ErrorKind::BelowMinimum => "too small",
+            ErrorKind::NotAnInteger => "not an integer",
+            ErrorKind::Negative => "negative",
+            ErrorKind::TooPrecise => "too many decimal places",
+            ErrorKind::Separator => "separator",
+            _ => "here",
+        };
+        let span = miette::LabeledSpan::at(self.span(), label);
+        Some(Box::new(core::iter::once(span)))
+    }
+}
+
+impl From<ParseError> for String {
+    fn from(e: ParseError) -> Self {
+        e.message
+    }
+}
+
+// Locates the part of a value that caused an error, for parsers that do not
+// report it.
+//
+// Invalid digits are found from the radix prefix of the value, separators and
+// other errors span the first quoted part of the message found in the value,
+// such as the suffix in "unknown suffix 'q'", or the whole value.
+fn locate(input: &str, kind: ErrorKind, message: &str) -> Range<usize> {
+    let whole = 0..input.len();
+    match kind {
+        ErrorKind::Empty => 0..0,
+        ErrorKind::Negative if input.starts_with('-') => 0..1,
+        ErrorKind::InvalidDigit => invalid_digit(input).unwrap_or(whole),
+        ErrorKind::Separator | ErrorKind::Other => message
+            .split('\'')
+            .nth(1)
+            .filter(|quoted| !quoted.is_empty())
+            .and_then(|quoted| input.find(quoted).map(|start| start..start + quoted.len()))
+            .unwrap_or(whole),
+        _ => whole,
+    }
+}
+
+// Returns the span of the first character that is not a digit in the radix
+// of the value, skipping the sign, radix prefix, and separators.
+fn invalid_digit(input: &str) -> Option<Range<usize>> {
+    let start = usize::from(input.starts_with(['+', '-']));
+    let prefix = input[start..].get(..2).map(str::to_ascii_lowercase);
+    let (radix, start) = match prefix.as_deref() {
+        Some("0x") => (16, start + 2),
+        Some("0b") => (2, start + 2),
+        Some("0o") => (8, start + 2),
+        _ => (10, start),
+    };
+
+    input[start..]
+        .char_indices()
+        .find(|&(_, c)| c != '_' && !c.is_digit(radix))
+        .map(|(i, c)| start + i..start + i + c.len_utf8())
+}
+
+// Returns the span of the decimal places of a value beyond the limit.
+fn excess_places(input: &str, places: u32) -> Option<Range<usize>> {
+    let point = input.find('.')?;
+    let frac = &input[point + 1..];
+    let end = frac
+        .find(|c: char| !(c.is_ascii_digit() || c == '_'))
+        .unwrap_or(frac.len());
+    let start = frac[..end]
+        .char_indices()
+        .filter(|&(_, c)| c != '_')
+        .nth(places as usize)
+        .map(|(i, _)| i)?;
+    Some(point + 1 + start..point + 1 + end)
+}
+
+/// Builder for a parser with optional bounds and custom error messages.
+///
+/// The value is parsed with any of the parsers in this crate, or with a
+/// custom parser, then checked against the bounds.
+/// The message of each [`ErrorKind`] can be replaced while keeping the
+/// parsing logic.
+///
+/// # Example
+///
+/// This allows for a number of cents to be passed in the range of 0-99
+/// (inclusive), with a friendlier error message.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{ErrorKind, NumberParser};
+///
+/// fn cents(s: &str) -> Result<u8, String> {
+///     NumberParser::decimal()
+///         .range(0, 99)
+///         .message(ErrorKind::AboveMaximum, "cents must be 0-99")
+///         .parse(s)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Change {
+///     #[clap(long, value_parser=cents)]
+///     cents: u8,
+/// }
+/// # let args = Change::parse_from(&["", "--cents", "99"]);
+/// # assert_eq!(args.cents, 99);
+/// # assert_eq!(cents("100"), Err(String::from("cents must be 0-99")));
+/// ```
+///
+/// Any parser can be used as the base parser:
+///
+/// ```
+/// use clap_num::{maybe_hex, NumberParser};
+///
+/// let parser = NumberParser::new(maybe_hex::<u16>).max(0x3FF);
+/// assert_eq!(parser.parse("0x3FF"), Ok(0x3FF));
+/// assert_eq!(parser.parse("0x400"), Err(String::from("exceeds maximum of 1023")));
+/// ```
+#[derive(Clone)]
+pub struct NumberParser<T> {
+    parser: BaseParser<T>,
+    min: Option<T>,
+    max: Option<T>,
+    messages: Vec<(ErrorKind, Cow<'static, str>)>,
+    validators: Vec<(Validator<T>, Cow<'static, str>)>,
+    warnings: Vec<(Validator<T>, Cow<'static, str>)>,
+    warning_handler: Option<WarningHandler>,
+    bound_formatter: Option<BoundFormatter<T>>,
+    max_decimal_places: Option<u32>,
+    files: bool,
+    stdin: bool,
+    default: Option<DefaultValue<T>>,
+    separators: Option<(&'static [char], Grouping)>,
+    non_finite: Option<NonFinite<T>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for NumberParser<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumberParser")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("messages", &self.messages)
+            .field("validators", &self.validators.len())
+            .field("warnings", &self.warnings.len())
+            .field("warning_handler", &self.warning_handler.is_some())
+            .field("bound_formatter", &self.bound_formatter.is_some())
+            .field("max_decimal_places", &self.max_decimal_places)
+            .field("files", &self.files)
+            .field("stdin", &self.stdin)
+            .field("default", &self.default.is_some())
+            .field("separators", &self.separators)
+            .field("non_finite", &self.non_finite.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> NumberParser<T> {
+    /// Creates a new parser from a base parser, without bounds.
+    ///
+    /// The kind of the errors of the base parser is determined with
+    /// [`ErrorKind::of`].
+    pub fn new(parser: fn(&str) -> Result<T, String>) -> Self
+    where
+        T: 'static,
+    {
+        Self::with_base(Arc::new(move |s| parser(s).map_err(ErrorMessage::from)))
+    }
+
+    fn with_base(parser: BaseParser<T>) -> Self {
+        Self {
+            parser,
+            min: None,
+            max: None,
+            messages: Vec::new(),
+            validators: Vec::new(),
+            warnings: Vec::new(),
+            warning_handler: None,
+            bound_formatter: None,
+            max_decimal_places: None,
+            files: false,
+            stdin: false,
+            default: None,
+            separators: None,
+            non_finite: None,
+        }
+    }
+
+    /// Sets the minimum value, inclusive.
+    #[must_use]
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value, inclusive.
+    #[must_use]
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the minimum and maximum values, inclusive.
+    #[must_use]
+    pub fn range(self, min: T, max: T) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Sets the minimum and maximum values from [`Bounds`], which are
+    /// checked when they are created.
+    #[must_use]
+    pub fn bounds(self, bounds: Bounds<T>) -> Self {
+        let (min, max) = bounds.into_inner();
+        self.range(min, max)
+    }
+
+    /// Replaces the message for errors of the given kind.
+    #[must_use]
+    pub fn message(mut self, kind: ErrorKind, message: impl Into<Cow<'static, str>>) -> Self {
+        let message = message.into();
+        match self.messages.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, m)) => *m = message,
+            None => self.messages.push((kind, message)),
+        }
+        self
+    }
+
+    /// Adds a predicate that the value must satisfy, with the message for
+    /// values that do not.
+    ///
+    /// Predicates run after parsing and range checking, in the order they
+    /// were added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ipv4, NumberParser};
+    ///
+    /// let parser = NumberParser::new(ipv4)
+    ///     .validate(|&addr| addr != u32::MAX, "must not equal the broadcast address");
+    /// assert_eq!(parser.parse("10.0.0.1"), Ok(0x0A00_0001));
+    /// assert_eq!(
+    ///     parser.parse("255.255.255.255"),
+    ///     Err(String::from("must not equal the broadcast address"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn validate<F>(mut self, predicate: F, message: impl Into<Cow<'static, str>>) -> Self
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.validators.push((Arc::new(predicate), message.into()));
+        self
+    }
+
+    /// Adds a predicate for values that are accepted with a warning, such as
+    /// deprecated values.
+    ///
+    /// Values matching the predicate are not rejected, the message is passed
+    /// to the handler set with [`on_warning`](Self::on_warning), or returned
+    /// by [`parse_with_warnings`](Self::parse_with_warnings).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::NumberParser;
+    ///
+    /// let parser = NumberParser::<u32>::decimal()
+    ///     .warn(|&baud| baud < 1200, "baud rates below 1200 are deprecated");
+    /// assert_eq!(
+    ///     parser.parse_with_warnings("300"),
+    ///     Ok((300, vec!["baud rates below 1200 are deprecated"]))
+    /// );
+    /// assert_eq!(parser.parse_with_warnings("9600"), Ok((9600, vec![])));
+    /// ```
+    #[must_use]
+    pub fn warn<F>(mut self, predicate: F, message: impl Into<Cow<'static, str>>) -> Self
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.warnings.push((Arc::new(predicate), message.into()));
+        self
+    }
+
+    /// Sets the handler for warnings of values accepted by
+    /// [`parse`](NumberParser::parse).
+    ///
+    /// The handler is called once for each warning, in the order the
+    /// warnings were added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap::Parser;
+    /// use clap_num::NumberParser;
+    ///
+    /// fn baud(s: &str) -> Result<u32, String> {
+    ///     NumberParser::decimal()
+    ///         .warn(|&baud| baud < 1200, "baud rates below 1200 are deprecated")
+    ///         .on_warning(|warning| eprintln!("warning: {warning}"))
+    ///         .parse(s)
+    /// }
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     #[clap(long, value_parser=baud)]
+    ///     baud: u32,
+    /// }
+    /// # let args = Args::parse_from(&["", "--baud", "300"]);
+    /// # assert_eq!(args.baud, 300);
+    /// ```
+    #[must_use]
+    pub fn on_warning<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.warning_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sets the formatter for the bounds in range errors.
+    ///
+    /// By default bounds are formatted with [`Display`], this allows the
+    /// error to use the same notation as the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{maybe_hex, NumberParser};
+    ///
+    /// let parser = NumberParser::new(maybe_hex::<u16>)
+    ///     .max(0x3FF)
+    ///     .format_bounds(|bound| format!("{bound:#X}"));
+    /// assert_eq!(parser.parse("0x400"), Err(String::from("exceeds maximum of 0x3FF")));
+    /// ```
+    #[must_use]
+    pub fn format_bounds<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        self.bound_formatter = Some(Arc::new(formatter));
+        self
+    }
+
+    /// Limits the number of decimal places of the value.
+    ///
+    /// Values with more decimal places are rejected instead of being rounded
+    /// by the base parser, like values with a fractional part are rejected
+    /// with "not an integer" by [`si_number`](crate::si_number).
+    /// Trailing zeros and exponents are taken into account, `1.50` and
+    /// `150e-2` have 1 decimal place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::NumberParser;
+    ///
+    /// let parser = NumberParser::<f64>::decimal().max_decimal_places(2);
+    /// assert_eq!(parser.parse("19.99"), Ok(19.99));
+    /// assert_eq!(
+    ///     parser.parse("19.999"),
+    ///     Err(String::from("too many decimal places, expected at most 2"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn max_decimal_places(mut self, places: u32) -> Self {
+        self.max_decimal_places = Some(places);
+        self
+    }
+
+    /// Accept `@path` to read the value from a file, such as `@key.txt`.
+    ///
+    /// The file must contain a single value, whitespace around the value is
+    /// ignored.
+    /// The value is validated the same way as values passed directly, this is
+    /// useful for secrets and for values too long to pass as an argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{maybe_hex, NumberParser};
+    ///
+    /// # let path = std::env::temp_dir().join("clap-num-doc-allow-file");
+    /// # std::fs::write(&path, "0xDEAD_BEEF\n").unwrap();
+    /// # let path = path.display();
+    /// let parser = NumberParser::new(maybe_hex::<u32>).allow_file();
+    /// assert_eq!(parser.parse(&format!("@{path}")), Ok(0xDEAD_BEEF));
+    /// assert_eq!(parser.parse("0x10"), Ok(0x10));
+    /// ```
+    ///
+    /// ## Error Messages
+    ///
+    /// Files that cannot be read will show an error message similar to this:
+    ///
+    /// ```text
+    /// error: Invalid value for '--key <key>': cannot read 'key.txt': No such file or directory (os error 2)
+    /// ```
+    #[must_use]
+    pub fn allow_file(mut self) -> Self {
+        self.files = true;
+        self
+    }
+
+    /// Accept `-` to read the value from stdin.
+    ///
+    /// Stdin must contain a single value, whitespace around the value is
+    /// ignored.
+    /// The value is validated the same way as values passed directly, this
+    /// allows long or computed values to be piped into an argument.
+    ///
+    /// Stdin is read to the end, so only one argument can be read from it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use clap::Parser;
+    /// use clap_num::{maybe_hex, NumberParser};
+    ///
+    /// fn key(s: &str) -> Result<u128, String> {
+    ///     NumberParser::new(maybe_hex).allow_stdin().parse(s)
+    /// }
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     #[clap(long, value_parser=key)]
+    ///     key: u128,
+    /// }
+    ///
+    /// // echo 0xDEAD_BEEF | program --key -
+    /// let args = Args::parse_from(&["", "--key", "-"]);
+    /// ```
+    ///
+    /// ## Error Messages
+    ///
+    /// Stdin that cannot be read will show an error message similar to this:
+    ///
+    /// ```text
+    /// error: Invalid value for '--key <key>': cannot read stdin: stream did not contain valid UTF-8
+    /// ```
+    #[must_use]
+    pub fn allow_stdin(mut self) -> Self {
+        self.stdin = true;
+        self
+    }
+
+    /// Returns `value` for an empty string instead of an error.
+    ///
+    /// This is useful for environment variables that are set but blank.
+    /// Strings with only whitespace are also empty, the default value is not
+    /// checked against the bounds or validators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{maybe_hex, NumberParser};
+    ///
+    /// let parser = NumberParser::new(maybe_hex::<u16>).default_on_empty(0x1F);
+    /// assert_eq!(parser.parse(""), Ok(0x1F));
+    /// assert_eq!(parser.parse("0x20"), Ok(0x20));
+    /// ```
+    #[must_use]
+    pub fn default_on_empty(mut self, value: T) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.default = Some(Arc::new(move || value.clone()));
+        self
+    }
+
+    /// Reject digit separators, such as `1_000`, for protocols that require
+    /// canonical numbers.
+    ///
+    /// The separators rejected are `_`, `,`, `'`, and spaces, which are
+    /// rejected with a message naming the separator instead of the message
+    /// of the base parser.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{maybe_hex, NumberParser};
+    ///
+    /// let parser = NumberParser::new(maybe_hex::<u32>).reject_separators();
+    /// assert_eq!(parser.parse("0xDEADBEEF"), Ok(0xDEAD_BEEF));
+    /// assert_eq!(
+    ///     parser.parse("0xDEAD_BEEF"),
+    ///     Err(String::from("digit separator '_' is not allowed"))
+    /// );
+    /// ```
+    ///
+    /// ## Error Messages
+    ///
+    /// Values with a separator will show an error message similar to this:
+    ///
+    /// ```text
+    /// error: Invalid value for '--address <address>': digit separator '_' is not allowed
+    /// ```
+    #[must_use]
+    pub fn reject_separators(self) -> Self {
+        self.separators(&[], Grouping::Anywhere)
+    }
+
+    /// Accept only the given digit separators, placed as described by the
+    /// grouping.
+    ///
+    /// The other separators of [`reject_separators`](Self::reject_separators)
+    /// are rejected, and the accepted separators are removed before the value
+    /// is passed to the base parser, so the base parser does not need to
+    /// accept them.
+    /// Values without separators are always accepted.
+    ///
+    /// # Example
+    ///
+    /// This accepts values with thousands separated by commas, such as
+    /// `1,000,000`.
+    ///
+    /// ```
+    /// use clap_num::{Grouping, NumberParser};
+    ///
+    /// let parser = NumberParser::<u32>::decimal().separators(&[','], Grouping::Every(3));
+    /// assert_eq!(parser.parse("1,000,000"), Ok(1_000_000));
+    /// assert_eq!(parser.parse("1000000"), Ok(1_000_000));
+    /// assert_eq!(
+    ///     parser.parse("10,00,000"),
+    ///     Err(String::from("digit separators must split the integer part into groups of 3 digits"))
+    /// );
+    /// assert_eq!(
+    ///     parser.parse("1_000_000"),
+    ///     Err(String::from("digit separator '_' is not allowed"))
+    /// );
+    /// ```
+    ///
+    /// ## Error Messages
+    ///
+    /// Values with separators in the wrong place will show an error message
+    /// similar to this:
+    ///
+    /// ```text
+    /// error: Invalid value for '--count <count>': digit separator '_' must be between two digits
+    /// ```
+    #[must_use]
+    pub fn separators(mut self, chars: &'static [char], grouping: Grouping) -> Self {
+        debug_assert!(grouping != Grouping::Every(0), "groups cannot be empty");
+        self.separators = Some((chars, grouping));
+        self
+    }
+
+    // reads the value from a file or stdin if it is enabled and the value is
+    // a path or `-`
+    fn read_input<'a>(&self, s: &'a str) -> Result<Cow<'a, str>, String> {
+        if self.stdin && s == "-" {
+            let mut contents = String::new();
+            return std::io::stdin()
+                .read_to_string(&mut contents)
+                .map(|_| Cow::Owned(String::from(contents.trim())))
+                .map_err(|e| format!("cannot read stdin: {e}"));
+        }
+
+        match s.strip_prefix('@') {
+            Some(path) if self.files => std::fs::read_to_string(path)
+                .map(|contents| Cow::Owned(String::from(contents.trim())))
+                .map_err(|e| format!("cannot read '{path}': {e}")),
+            _ => Ok(Cow::Borrowed(s)),
+        }
+    }
+
+    fn bound<'a>(&'a self, bound: &'a T) -> Bound<'a, T> {
+        Bound {
+            bound,
+            formatter: self.bound_formatter.as_ref(),
+        }
+    }
+
+    // returns the message for an error, formatting the default message only
+    // if it has not been replaced
+    fn error(&self, kind: ErrorKind, default: impl Display) -> String {
+        match self.messages.iter().find(|(k, _)| *k == kind) {
+            Some((_, m)) => m.to_string(),
+            None => default.to_string(),
+        }
+    }
+}
+
+impl<T> NumberParser<T>
+where
+    T: core::str::FromStr,
+    <T as core::str::FromStr>::Err: Display,
+{
+    /// Creates a new parser for signed or unsigned base-10 integers, without
+    /// bounds.
+    ///
+    /// Underscores can be used as digit separators, for example `1_000`.
+    ///
+    /// The `nan`, `inf`, and `-inf` keywords are rejected, see
+    /// [`allow_non_finite`](NumberParser::allow_non_finite).
+    pub fn decimal() -> Self
+    where
+        T: 'static,
+    {
+        Self::new(parse_finite_decimal)
+    }
+}
+
+impl<T> NumberParser<T>
+where
+    <T as TryFrom<u128>>::Error: Display,
+    <T as core::str::FromStr>::Err: Display,
+    T: num_traits::CheckedAdd,
+    T: num_traits::CheckedMul,
+    T: num_traits::CheckedSub,
+    T: core::str::FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: num_traits::Zero,
+    T: 'static,
+{
+    /// Creates a new parser for signed or unsigned integers with a metric
+    /// prefix, parsed with an [`SiParser`], without bounds.
+    ///
+    /// Unlike [`new`](NumberParser::new) with
+    /// [`si_number`](crate::si_number), the errors of the [`SiParser`] have
+    /// their kind and the span of the offending part of the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ErrorKind, NumberParser, SiParser};
+    ///
+    /// let parser = NumberParser::<u32>::si(SiParser::new().unit("Hz")).max(100_000);
+    /// assert_eq!(parser.parse("10kHz"), Ok(10_000));
+    ///
+    /// let err = parser.try_parse("1.5qHz").unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidDigit);
+    /// assert_eq!(err.span(), 3..4);
+    /// ```
+    pub fn si(parser: SiParser) -> Self {
+        Self::with_base(Arc::new(move |s| parser.try_parse(s)))
+    }
+}
+
+impl<T: num_traits::Bounded + Display> NumberParser<T> {
+    /// Names the target type and its limits in overflow errors.
+    ///
+    /// This replaces the messages for [`ErrorKind::PosOverflow`] and
+    /// [`ErrorKind::NegOverflow`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::NumberParser;
+    ///
+    /// let parser = NumberParser::<u8>::decimal().describe_overflow();
+    /// assert_eq!(
+    ///     parser.parse("256"),
+    ///     Err(String::from("number too large for u8 (max 255)"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn describe_overflow(self) -> Self {
+        let name = core::any::type_name::<T>();
+        let max = T::max_value();
+        let min = T::min_value();
+        self.message(
+            ErrorKind::PosOverflow,
+            format!("number too large for {name} (max {max})"),
+        )
+        .message(
+            ErrorKind::NegOverflow,
+            format!("number too small for {name} (min {min})"),
+        )
+    }
+}
+
+impl<T: num_traits::Float> NumberParser<T> {
+    /// Accept the `nan`, `inf`, and `-inf` keywords, case insensitive, for
+    /// sentinel values.
+    ///
+    /// [`decimal`](NumberParser::decimal) rejects these keywords by default,
+    /// other base parsers are given the keywords unless this is set.
+    /// `infinity` and `+inf` are accepted as well.
+    ///
+    /// The keywords are checked against the bounds and validators like any
+    /// other value, NaN is never within bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::NumberParser;
+    ///
+    /// let parser = NumberParser::<f64>::decimal().min(0.0).allow_non_finite();
+    /// assert_eq!(parser.parse("0.5"), Ok(0.5));
+    /// assert_eq!(parser.parse("inf"), Ok(f64::INFINITY));
+    /// assert_eq!(parser.parse("-inf"), Err(String::from("less than minimum of 0")));
+    /// assert!(NumberParser::<f64>::decimal().allow_non_finite().parse("NaN").unwrap().is_nan());
+    /// assert_eq!(
+    ///     NumberParser::<f64>::decimal().parse("inf"),
+    ///     Err(String::from("value is infinite"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn allow_non_finite(mut self) -> Self {
+        self.non_finite = Some(non_finite);
+        self
+    }
+}
+
+impl<T: PartialOrd + Display> NumberParser<T> {
+    /// Parses a value.
+    ///
+    /// Warnings for the value are passed to the handler set with
+    /// [`on_warning`](NumberParser::on_warning), if any.
+    pub fn parse(&self, s: &str) -> Result<T, String> {
+        self.try_parse(s).map_err(String::from)
+    }
+
+    /// Parses a value that may not be valid UTF-8, such as in a custom
+    /// clap `TypedValueParser`.
+    ///
+    /// See [`parse_os`](crate::parse_os) for details.
+    pub fn parse_os(&self, s: &std::ffi::OsStr) -> Result<T, String> {
+        crate::parse_os(s, |s| self.parse(s))
+    }
+
+    /// Parses a value, returning a [`ParseError`] with the span of the value
+    /// that caused the error.
+    ///
+    /// Values read from a file or stdin span the whole argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::{ErrorKind, NumberParser};
+    ///
+    /// let parser = NumberParser::<f64>::decimal().max_decimal_places(2);
+    /// let err = parser.try_parse("19.999").unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::TooPrecise);
+    /// assert_eq!(err.span(), 5..6);
+    /// ```
+    pub fn try_parse(&self, s: &str) -> Result<T, ParseError> {
+        let (val, warnings) = self.parse_spanned(s)?;
+        if let Some(handler) = &self.warning_handler {
+            warnings.into_iter().for_each(|warning| handler(warning));
+        }
+        Ok(val)
+    }
+
+    /// Parses a value, returning the warnings for the value along with it.
+    ///
+    /// See [`warn`](NumberParser::warn) for details.
+    pub fn parse_with_warnings(&self, s: &str) -> Result<(T, Vec<&str>), String> {
+        self.parse_spanned(s).map_err(String::from)
+    }
+
+    fn parse_spanned(&self, s: &str) -> Result<(T, Vec<&str>), ParseError> {
+        if let Some(default) = self.default.as_ref().filter(|_| s.trim().is_empty()) {
+            return Ok((default(), Vec::new()));
+        }
+
+        let whole = 0..s.len();
+        let input = self.read_input(s).map_err(|message| ParseError {
+            kind: ErrorKind::Other,
+            message,
+            input: String::from(s),
+            span: whole.clone(),
+        })?;
+        let input_ref = input.as_ref();
+        // spans of values read from a file or stdin cannot be shown in the
+        // argument
+        let read = matches!(input, Cow::Owned(_));
+        let error = |kind, span: Option<Range<usize>>, default: &dyn Display| ParseError {
+            kind,
+            message: self.error(kind, default),
+            input: String::from(s),
+            span: span.filter(|_| !read).unwrap_or(whole.clone()),
+        };
+
+        // separators are removed so the base parser does not need to accept
+        // them, which moves the spans of its errors
+        let digits = match self.separators {
+            Some((chars, grouping)) => {
+                check_separators(input_ref, chars, grouping)
+                    .map_err(|(span, message)| error(ErrorKind::Separator, Some(span), &message))?;
+                strip_separators(input_ref, chars)
+            }
+            None => Cow::Borrowed(input_ref),
+        };
+        let stripped = matches!(digits, Cow::Owned(_));
+
+        // keywords are only taken over from the base parser if accepted, and
+        // are checked like any other value
+        let keyword = self.non_finite.and_then(|f| f(input_ref));
+        let val = match keyword {
+            Some(val) => val,
+            None => (self.parser)(&digits).map_err(|e| {
+                let span = e
+                    .span
+                    .clone()
+                    .unwrap_or_else(|| locate(&digits, e.kind, &e.message));
+                error(e.kind, Some(span).filter(|_| !stripped), &e)
+            })?,
+        };
+
+        if let Some(places) = self.max_decimal_places {
+            if decimal_places(&digits).is_some_and(|n| n > u64::from(places)) {
+                return Err(error(
+                    ErrorKind::TooPrecise,
+                    excess_places(&digits, places).filter(|_| !stripped),
+                    &format_args!("{TOO_PRECISE_MSG}, expected at most {places}"),
+                ));
+            }
+        }
+
+        // values that are not ordered, such as NaN, are never within bounds
+        if (self.min.is_some() || self.max.is_some()) && val.partial_cmp(&val).is_none() {
+            return Err(error(ErrorKind::Other, None, &"value is not a number"));
+        }
+        if let Some(max) = &self.max {
+            if val > *max {
+                return Err(error(
+                    ErrorKind::AboveMaximum,
+                    None,
+                    &RangeError::AboveMaximum(self.bound(max)),
+                ));
+            }
+        }
+        if let Some(min) = &self.min {
+            if val < *min {
+                return Err(error(
+                    ErrorKind::BelowMinimum,
+                    None,
+                    &RangeError::BelowMinimum(self.bound(min)),
+                ));
+            }
+        }
+
+        if let Some((_, message)) = self.validators.iter().find(|(f, _)| !f(&val)) {
+            return Err(ParseError {
+                kind: ErrorKind::Other,
+                message: message.to_string(),
+                input: String::from(s),
+                span: whole,
+            });
+        }
+
+        let warnings = self
+            .warnings
+            .iter()
+            .filter(|(f, _)| f(&val))
+            .map(|(_, message)| message.as_ref())
+            .collect();
+
+        Ok((val, warnings))
+    }
+}
+
+// Returns whether a value is a keyword for a value that is not finite, such as
+// `-inf`, and whether the keyword is for NaN.
+fn non_finite_keyword(s: &str) -> Option<bool> {
+    let (_, keyword) = crate::split_sign(s);
+    if keyword.eq_ignore_ascii_case("nan") {
+        Some(true)
+    } else if keyword.eq_ignore_ascii_case("inf") || keyword.eq_ignore_ascii_case("infinity") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+// parses a base-10 value, rejecting the keywords for values that are not
+// finite that the target type accepts, such as `inf` for `f64`
+fn parse_finite_decimal<T>(s: &str) -> Result<T, String>
+where
+    T: core::str::FromStr,
+    <T as core::str::FromStr>::Err: Display,
+{
+    let val = crate::parse_decimal(s)?;
+    match non_finite_keyword(s) {
+        Some(true) => Err(String::from("value is not a number")),
+        Some(false) => Err(String::from("value is infinite")),
+        None => Ok(val),
+    }
+}
+
+// parses a keyword for a value that is not finite
+fn non_finite<T: num_traits::Float>(s: &str) -> Option<T> {
+    let (negative, _) = crate::split_sign(s);
+    match non_finite_keyword(s)? {
+        true => Some(T::nan()),
+        false if negative => Some(T::neg_infinity()),
+        false => Some(T::infinity()),
+    }
+}
+
+// Checks that a value only has the accepted separators, placed as described
+// by the grouping, returning the span of the offending part and the message
+// otherwise.
+fn check_separators(
+    s: &str,
+    chars: &[char],
+    grouping: Grouping,
+) -> Result<(), (Range<usize>, String)> {
+    let span = |idx: usize, c: char| idx..idx + c.len_utf8();
+    let is_separator = |c: char| chars.contains(&c);
+
+    if let Some((idx, c)) = s
+        .char_indices()
+        .find(|&(_, c)| SEPARATORS.contains(&c) && !is_separator(c))
+    {
+        return Err((
+            span(idx, c),
+            format!("{SEPARATOR_MSG} '{c}' is not allowed"),
+        ));
+    }
+
+    // digits start after the sign and radix prefix
+    let (_, unsigned) = crate::split_sign(s);
+    let (base, digits) = crate::raw::split_radix_prefix(unsigned);
+    let start = s.len() - digits.len();
+    let is_digit = |c: char| c.is_digit(base.radix());
+
+    match grouping {
+        Grouping::Anywhere => {
+            let mut prev = None;
+            let mut chars = s[start..].char_indices().peekable();
+            while let Some((idx, c)) = chars.next() {
+                let next = chars.peek().map(|&(_, c)| c);
+                if is_separator(c) && !(prev.is_some_and(is_digit) && next.is_some_and(is_digit)) {
+                    return Err((
+                        span(start + idx, c),
+                        format!("{SEPARATOR_MSG} '{c}' must be between two digits"),
+                    ));
+                }
+                prev = Some(c);
+            }
+            // separators before the digits, such as `_1`
+            match s[..start].char_indices().find(|&(_, c)| is_separator(c)) {
+                Some((idx, c)) => Err((
+                    span(idx, c),
+                    format!("{SEPARATOR_MSG} '{c}' must be between two digits"),
+                )),
+                None => Ok(()),
+            }
+        }
+        Grouping::Every(size) => {
+            let end = s[start..]
+                .find(|c: char| !(is_digit(c) || is_separator(c)))
+                .map_or(s.len(), |idx| start + idx);
+            let int = &s[start..end];
+            let mut groups = int.split(is_separator);
+            let first = groups.next().unwrap_or_default();
+            let grouped = !int.contains(is_separator)
+                || ((1..=size).contains(&first.len()) && groups.all(|group| group.len() == size));
+            let misplaced = s[..start].contains(is_separator) || s[end..].contains(is_separator);
+
+            if grouped && !misplaced {
+                Ok(())
+            } else {
+                Err((
+                    0..s.len(),
+                    format!(
+                        "{SEPARATOR_MSG}s must split the integer part into groups of {size} digits"
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+// removes the accepted separators from a value, only allocating if there are
+// separators
+fn strip_separators<'a>(s: &'a str, chars: &[char]) -> Cow<'a, str> {
+    if s.contains(chars) {
+        Cow::Owned(s.replace(chars, ""))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+// Returns the number of significant decimal places of the number at the
+// start of a value, such as 1 for "1.50", 3 for "15e-3", or 1 for "0.5x".
+fn decimal_places(s: &str) -> Option<u64> {
+    let s = s.trim_start_matches(['+', '-']);
+    let end = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_'))
+        .unwrap_or(s.len());
+    let (mantissa, rest) = s.split_at(end);
+
+    let exponent: i64 = match rest.strip_prefix(['e', 'E']) {
+        Some(exponent) => {
+            let end = exponent
+                .find(|c: char| !(c.is_ascii_digit() || c == '+' || c == '-'))
+                .unwrap_or(exponent.len());
+            exponent[..end].parse().ok()?
+        }
+        None => 0,
+    };
+
+    let frac = mantissa.split_once('.').map_or("", |(_, frac)| frac);
+    let frac_digits = frac.bytes().filter(|&b| b != b'_').count() as i64;
+    let trailing_zeros = mantissa
+        .bytes()
+        .rev()
+        .filter(|&b| b != b'_' && b != b'.')
+        .take_while(|&b| b == b'0')
+        .count() as i64;
+
+    let places = frac_digits
+        .saturating_sub(exponent)
+        .saturating_sub(trailing_zeros);
+    Some(places.max(0) as u64)
+}
@@ -0,0 +1,743 @@
+use crate::{
+    check_range, scale_decimal, split_sign, SiPrefix, EMPTY_MSG, INVALID_DIGIT_MSG, NEGATIVE_MSG,
+    NOT_AN_INTEGER_MSG, OVERFLOW_MSG, UNDERFLOW_MSG,
+};
+use core::fmt;
+use num_traits::identities::Zero;
+use num_traits::{sign, CheckedSub, PrimInt};
+
+/// Table of suffixes and the value they multiply by, used by
+/// [`prefixed_number`].
+///
+/// This allows applications to define their own suffixes, such as `Ki` for
+/// 1024, while reusing the decimal handling and overflow checks of this
+/// crate.
+///
+/// Tables are implemented for slices and arrays of `(suffix, multiplier)`
+/// pairs, which covers most uses.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::PrefixTable;
+///
+/// struct Binary;
+///
+/// impl PrefixTable for Binary {
+///     fn prefixes(&self) -> &[(&str, u128)] {
+///         &[("Ki", 1 << 10), ("Mi", 1 << 20), ("Gi", 1 << 30)]
+///     }
+/// }
+///
+/// assert_eq!(Binary.multiplier("Mi"), Some(1 << 20));
+/// assert_eq!(Binary.multiplier("M"), None);
+/// ```
+pub trait PrefixTable {
+    /// Returns the suffixes and the value they multiply by.
+    fn prefixes(&self) -> &[(&str, u128)];
+
+    /// Returns the value a suffix multiplies by, or `None` if the suffix is
+    /// not in the table.
+    ///
+    /// Suffixes are case sensitive.
+    fn multiplier(&self, suffix: &str) -> Option<u128> {
+        self.prefixes()
+            .iter()
+            .find(|(name, _)| *name == suffix)
+            .map(|&(_, multiplier)| multiplier)
+    }
+}
+
+impl PrefixTable for [(&str, u128)] {
+    fn prefixes(&self) -> &[(&str, u128)] {
+        self
+    }
+}
+
+impl<const N: usize> PrefixTable for [(&str, u128); N] {
+    fn prefixes(&self) -> &[(&str, u128)] {
+        self
+    }
+}
+
+impl<P: PrefixTable + ?Sized> PrefixTable for &P {
+    fn prefixes(&self) -> &[(&str, u128)] {
+        (**self).prefixes()
+    }
+
+    fn multiplier(&self, suffix: &str) -> Option<u128> {
+        (**self).multiplier(suffix)
+    }
+}
+
+/// Builder for a [`PrefixTable`] of units, such as sectors or pages.
+///
+/// This is an alternative to a constant array of `(suffix, multiplier)` pairs
+/// for units that are only known at runtime, such as the page size of the
+/// system.
+///
+/// # Example
+///
+/// This allows for an offset in bytes, sectors, or pages.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{prefixed_number, UnitTable};
+///
+/// fn offset(s: &str) -> Result<u64, String> {
+///     let units = UnitTable::new()
+///         .unit("s", 512)
+///         .unit("sect", 512)
+///         .unit("p", 4096);
+///     prefixed_number(s, &units)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=offset)]
+///     offset: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--offset", "8p"]);
+/// # assert_eq!(args.offset, 32_768);
+/// # assert_eq!(offset("3sect"), Ok(1_536));
+/// # assert_eq!(offset("1b"), Err(String::from("unknown suffix 'b', expected one of s, sect, p")));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct UnitTable {
+    units: Vec<(&'static str, u128)>,
+}
+
+impl UnitTable {
+    /// Create a new table without units.
+    pub const fn new() -> Self {
+        Self { units: Vec::new() }
+    }
+
+    /// Adds a unit, replacing the multiplier if the suffix is already in the
+    /// table.
+    ///
+    /// Suffixes are case sensitive, and must not contain digits, `.`, `_`,
+    /// `+`, or `-`.
+    #[must_use]
+    pub fn unit(mut self, suffix: &'static str, multiplier: u128) -> Self {
+        debug_assert!(
+            !suffix.is_empty()
+                && !suffix
+                    .contains(|c: char| c.is_ascii_digit() || matches!(c, '.' | '_' | '+' | '-')),
+            "invalid suffix '{suffix}'"
+        );
+        match self.units.iter_mut().find(|(name, _)| *name == suffix) {
+            Some((_, m)) => *m = multiplier,
+            None => self.units.push((suffix, multiplier)),
+        }
+        self
+    }
+}
+
+impl PrefixTable for UnitTable {
+    fn prefixes(&self) -> &[(&str, u128)] {
+        &self.units
+    }
+}
+
+// lists the suffixes of a table, such as "Ki, Mi, Gi"
+fn suffix_list(prefixes: &[(&str, u128)]) -> String {
+    prefixes
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// A bound formatted with the largest suffix of a table that divides it
+// exactly, such as `8p`.
+//
+// The magnitude is parsed from the decimal representation, which avoids
+// additional bounds on the range functions.
+pub(crate) struct PrefixBound<'a, T, P: ?Sized> {
+    pub(crate) bound: T,
+    pub(crate) table: &'a P,
+}
+
+impl<T: fmt::Display, P: PrefixTable + ?Sized> fmt::Display for PrefixBound<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimal = self.bound.to_string();
+        let (negative, digits) = split_sign(&decimal);
+        let Ok(magnitude) = digits.parse::<u128>() else {
+            return f.write_str(&decimal);
+        };
+
+        // reversed so that the first of several suffixes with the same
+        // multiplier is used
+        let prefix = self
+            .table
+            .prefixes()
+            .iter()
+            .rev()
+            .filter(|&&(_, multiplier)| {
+                multiplier > 1 && magnitude != 0 && magnitude % multiplier == 0
+            })
+            .max_by_key(|&&(_, multiplier)| multiplier);
+
+        match prefix {
+            Some((name, multiplier)) => {
+                let sign = if negative { "-" } else { "" };
+                write!(f, "{sign}{}{name}", magnitude / multiplier)
+            }
+            None => f.write_str(&decimal),
+        }
+    }
+}
+
+// converts a magnitude to a signed or unsigned value
+pub(crate) fn apply_sign<T>(negative: bool, magnitude: u128) -> Result<T, String>
+where
+    T: CheckedSub + TryFrom<u128> + Zero,
+{
+    if !negative {
+        return T::try_from(magnitude).map_err(|_| String::from(OVERFLOW_MSG));
+    } else if magnitude == 0 {
+        return Ok(T::zero());
+    }
+
+    let one = T::try_from(1).map_err(|_| String::from(OVERFLOW_MSG))?;
+    if T::zero().checked_sub(&one).is_none() {
+        return Err(String::from(NEGATIVE_MSG));
+    }
+
+    // subtracting in two steps allows for the minimum of signed types
+    T::try_from(magnitude - 1)
+        .ok()
+        .and_then(|m| T::zero().checked_sub(&m))
+        .and_then(|val| val.checked_sub(&one))
+        .ok_or_else(|| String::from(UNDERFLOW_MSG))
+}
+
+/// Validate a signed or unsigned integer value with a suffix from a
+/// [`PrefixTable`].
+///
+/// The value is a decimal number, optionally followed by one of the
+/// suffixes in the table, which multiplies the number.
+/// Values with a fractional part are accepted if the result is an integer,
+/// for example `1.5Ki` is 1536.
+///
+/// Underscores can be used as digit separators, for example `1_000Ki`.
+///
+/// # Example
+///
+/// This allows for a buffer size with binary prefixes.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::prefixed_number;
+///
+/// const BINARY: [(&str, u128); 3] = [("Ki", 1 << 10), ("Mi", 1 << 20), ("Gi", 1 << 30)];
+///
+/// fn buffer(s: &str) -> Result<u64, String> {
+///     prefixed_number(s, &BINARY)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=buffer)]
+///     buffer: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--buffer", "1.5Ki"]);
+/// # assert_eq!(args.buffer, 1536);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with a suffix that is not in the table will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--buffer <buffer>': unknown suffix 'K', expected one of Ki, Mi, Gi
+/// ```
+pub fn prefixed_number<T, P>(s: &str, table: &P) -> Result<T, String>
+where
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+    P: PrefixTable + ?Sized,
+{
+    let value_len = s
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '_' | '+' | '-')))
+        .unwrap_or(s.len());
+    let (value, suffix) = s.split_at(value_len);
+
+    let multiplier: u128 = if suffix.is_empty() {
+        1
+    } else {
+        table.multiplier(suffix).ok_or_else(|| {
+            format!(
+                "unknown suffix '{suffix}', expected one of {}",
+                suffix_list(table.prefixes())
+            )
+        })?
+    };
+
+    if value.is_empty() && !suffix.is_empty() {
+        return Err(format!("no value found before suffix '{suffix}'"));
+    } else if value.is_empty() {
+        return Err(String::from(EMPTY_MSG));
+    }
+
+    let (negative, unsigned) = split_sign(value);
+    if unsigned.is_empty() {
+        return Err(String::from(INVALID_DIGIT_MSG));
+    }
+    let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let frac_digits = frac.bytes().filter(|&b| b != b'_').count();
+
+    // the digits without the decimal point, multiplied before dividing by
+    // the power of ten removed from the fraction
+    let digits: u128 = scale_decimal(int, frac, frac_digits as i64, None)?;
+    let scaled = digits.checked_mul(multiplier).ok_or_else(|| {
+        String::from(if negative {
+            UNDERFLOW_MSG
+        } else {
+            OVERFLOW_MSG
+        })
+    })?;
+    let divisor = u32::try_from(frac_digits)
+        .ok()
+        .and_then(|exp| 10u128.checked_pow(exp))
+        .ok_or_else(|| String::from(NOT_AN_INTEGER_MSG))?;
+    if scaled % divisor != 0 {
+        return Err(String::from(NOT_AN_INTEGER_MSG));
+    }
+
+    apply_sign(negative, scaled / divisor)
+}
+
+/// Validate a signed or unsigned integer value with a suffix from a
+/// [`PrefixTable`] within a range.
+///
+/// This combines [`prefixed_number`] and [`number_range`](crate::number_range),
+/// see the documentation for those functions for details.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::prefixed_number_range;
+///
+/// const BINARY: [(&str, u128); 2] = [("Ki", 1 << 10), ("Mi", 1 << 20)];
+///
+/// fn buffer(s: &str) -> Result<u32, String> {
+///     prefixed_number_range(s, &BINARY, 4 << 10, 16 << 20)
+/// }
+///
+/// assert_eq!(buffer("64Ki"), Ok(64 << 10));
+/// assert_eq!(buffer("32Mi"), Err(String::from("exceeds maximum of 16Mi")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown with the largest suffix that divides them exactly,
+/// values exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--buffer <buffer>': exceeds maximum of 16Mi
+/// ```
+pub fn prefixed_number_range<T, P>(s: &str, table: &P, min: T, max: T) -> Result<T, String>
+where
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Ord,
+    T: fmt::Display,
+    P: PrefixTable + ?Sized,
+{
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let val = prefixed_number(s, table)?;
+    check_range(val, min, max)
+        .map_err(|e| String::from(e.map(|bound| PrefixBound { bound, table })))
+}
+
+// units of a memory size, with SI and binary prefixes
+const MEM_SIZE_UNITS: [(&str, u128); 13] = [
+    ("B", 1),
+    ("kB", 1_000),
+    ("MB", 1_000_000),
+    ("GB", 1_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("PB", 1_000_000_000_000_000),
+    ("EB", 1_000_000_000_000_000_000),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+    ("PiB", 1 << 50),
+    ("EiB", 1 << 60),
+];
+
+// units that could be either an SI or a binary unit, with their SI prefix
+const AMBIGUOUS_MEM_SIZE_UNITS: [(&str, SiPrefix); 8] = [
+    ("k", SiPrefix::Kilo),
+    ("K", SiPrefix::Kilo),
+    ("KB", SiPrefix::Kilo),
+    ("M", SiPrefix::Mega),
+    ("G", SiPrefix::Giga),
+    ("T", SiPrefix::Tera),
+    ("P", SiPrefix::Peta),
+    ("E", SiPrefix::Exa),
+];
+
+// rejects a memory size with a unit that does not state if it is an SI or a
+// binary unit, such as `4k`
+fn reject_ambiguous_unit(s: &str) -> Result<(), String> {
+    let suffix =
+        s.trim_start_matches(|c: char| c.is_ascii_digit() || matches!(c, '.' | '_' | '+' | '-'));
+
+    match AMBIGUOUS_MEM_SIZE_UNITS
+        .iter()
+        .find(|&&(unit, _)| unit == suffix)
+        .and_then(|&(_, si_prefix)| crate::ambiguous_unit_error(si_prefix))
+    {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Validate a memory size in bytes, such as `4KiB` or `1.5GB`.
+///
+/// The size is a decimal number, optionally followed by a unit.
+///
+/// | Unit  | Value  | Unit  | Value |
+/// |-------|--------|-------|-------|
+/// | `B`   | 1      |       |       |
+/// | `kB`  | 1000¹  | `KiB` | 1024¹ |
+/// | `MB`  | 1000²  | `MiB` | 1024² |
+/// | `GB`  | 1000³  | `GiB` | 1024³ |
+/// | `TB`  | 1000⁴  | `TiB` | 1024⁴ |
+/// | `PB`  | 1000⁵  | `PiB` | 1024⁵ |
+/// | `EB`  | 1000⁶  | `EiB` | 1024⁶ |
+///
+/// Values with a fractional part are accepted if the result is a whole
+/// number of bytes, for example `1.5KiB` is 1536.
+///
+/// Units must state if they are SI or binary units, values with a bare SI
+/// symbol such as `4k`, or with `KB`, are rejected instead of picking one of
+/// the conventions.
+/// This is always the case, as these units are not in the table above, and
+/// the error names both of the units that can be used instead of reporting
+/// an unknown suffix.
+/// Use [`SiParser::reject_ambiguous`](crate::SiParser::reject_ambiguous) for
+/// the same error with an [`SiParser`](crate::SiParser).
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::mem_size;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=mem_size::<u64>)]
+///     cache: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--cache", "1.5KiB"]);
+/// # assert_eq!(args.cache, 1536);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with an unknown unit will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--cache <cache>': unknown suffix 'Mb', expected one of B, kB, MB, GB, TB, PB, EB, KiB, MiB, GiB, TiB, PiB, EiB
+/// ```
+///
+/// Values with an ambiguous unit will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--cache <cache>': ambiguous unit: use kB (1000) or KiB (1024)
+/// ```
+pub fn mem_size<T>(s: &str) -> Result<T, String>
+where
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    reject_ambiguous_unit(s)?;
+    prefixed_number(s, &MEM_SIZE_UNITS)
+}
+
+/// Validate a memory size in bytes within a range.
+///
+/// This combines [`mem_size`] and [`number_range`](crate::number_range), see
+/// the documentation for those functions for details.
+///
+/// # Example
+///
+/// This allows for a memory limit between 1MiB and 64GiB.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::mem_size_range;
+///
+/// fn limit(s: &str) -> Result<u64, String> {
+///     mem_size_range(s, 1 << 20, 64 << 30)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=limit)]
+///     limit: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--limit", "512MiB"]);
+/// # assert_eq!(args.limit, 512 << 20);
+/// # assert_eq!(limit("100GB"), Err(String::from("exceeds maximum of 64GiB")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown with the largest unit that divides them exactly,
+/// values exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--limit <limit>': less than minimum of 1MiB
+/// ```
+pub fn mem_size_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Ord,
+    T: fmt::Display,
+{
+    reject_ambiguous_unit(s)?;
+    prefixed_number_range(s, &MEM_SIZE_UNITS, min, max)
+}
+
+// units of a bit rate, with the `bps` spelling first so that it is used for
+// range bounds
+const BIT_RATE_UNITS: [(&str, u128); 10] = [
+    ("bps", 1),
+    ("kbps", 1_000),
+    ("Mbps", 1_000_000),
+    ("Gbps", 1_000_000_000),
+    ("Tbps", 1_000_000_000_000),
+    ("bit/s", 1),
+    ("kbit/s", 1_000),
+    ("Mbit/s", 1_000_000),
+    ("Gbit/s", 1_000_000_000),
+    ("Tbit/s", 1_000_000_000_000),
+];
+
+/// Validate a bit rate in bits per second, such as `100Mbps` or
+/// `1.5Gbit/s`.
+///
+/// The rate is a decimal number, optionally followed by a unit.
+///
+/// | Unit   | Alternative | Value |
+/// |--------|-------------|-------|
+/// | `bps`  | `bit/s`     | 1     |
+/// | `kbps` | `kbit/s`    | 1000¹ |
+/// | `Mbps` | `Mbit/s`    | 1000² |
+/// | `Gbps` | `Gbit/s`    | 1000³ |
+/// | `Tbps` | `Tbit/s`    | 1000⁴ |
+///
+/// Values with a fractional part are accepted if the result is a whole
+/// number of bits per second, for example `1.5kbps` is 1500.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::bit_rate;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=bit_rate::<u64>)]
+///     bandwidth: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--bandwidth", "1.5Gbps"]);
+/// # assert_eq!(args.bandwidth, 1_500_000_000);
+/// ```
+pub fn bit_rate<T>(s: &str) -> Result<T, String>
+where
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    prefixed_number(s, &BIT_RATE_UNITS)
+}
+
+/// Validate a bit rate in bits per second within a range.
+///
+/// This combines [`bit_rate`] and [`number_range`](crate::number_range), see
+/// the documentation for those functions for details.
+///
+/// # Example
+///
+/// This allows for a bandwidth between 1kbps and 10Gbps.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::bit_rate_range;
+///
+/// fn bandwidth(s: &str) -> Result<u64, String> {
+///     bit_rate_range(s, 1_000, 10_000_000_000)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=bandwidth)]
+///     bandwidth: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--bandwidth", "100Mbps"]);
+/// # assert_eq!(args.bandwidth, 100_000_000);
+/// # assert_eq!(bandwidth("40Gbit/s"), Err(String::from("exceeds maximum of 10Gbps")));
+/// ```
+///
+/// ## Error Messages
+///
+/// The bounds are shown with the largest unit that divides them exactly,
+/// values exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--bandwidth <bandwidth>': exceeds maximum of 10Gbps
+/// ```
+pub fn bit_rate_range<T>(s: &str, min: T, max: T) -> Result<T, String>
+where
+    T: CheckedSub,
+    T: TryFrom<u128>,
+    T: Zero,
+    T: Ord,
+    T: fmt::Display,
+{
+    prefixed_number_range(s, &BIT_RATE_UNITS, min, max)
+}
+
+/// Returns the page size of the system in bytes.
+///
+/// On Unix this is queried from the system at runtime, on other platforms,
+/// or if the query fails, this is 4096.
+///
+/// This requires the `page-size` feature.
+#[cfg(feature = "page-size")]
+pub fn page_size() -> u64 {
+    const DEFAULT: u64 = 4096;
+
+    #[cfg(unix)]
+    {
+        // SAFETY: sysconf has no preconditions
+        let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        u64::try_from(size).unwrap_or(DEFAULT)
+    }
+    #[cfg(not(unix))]
+    DEFAULT
+}
+
+/// Validates a size or address that is a multiple of the system page size,
+/// parsing the value with `parse`.
+///
+/// The page size is queried at runtime with [`page_size`], see
+/// [`page_aligned_to`] to use a different page size, such as the size of
+/// huge pages.
+///
+/// This requires the `page-size` feature.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{mem_size, page_aligned};
+///
+/// fn length(s: &str) -> Result<u64, String> {
+///     page_aligned(s, mem_size)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=length)]
+///     length: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--length", "1MiB"]);
+/// # assert_eq!(args.length, 1 << 20);
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are not a multiple of the page size will show an error
+/// message with the page size similar to this:
+///
+/// ```text
+/// error: Invalid value for '--length <length>': not a multiple of the page size of 4KiB
+/// ```
+#[cfg(feature = "page-size")]
+pub fn page_aligned<T, F>(s: &str, parse: F) -> Result<T, String>
+where
+    T: PrimInt + sign::Unsigned,
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    page_aligned_to(s, page_size(), parse)
+}
+
+/// Validates a size or address that is a multiple of `page_size`, parsing
+/// the value with `parse`.
+///
+/// # Panics
+///
+/// Panics if `page_size` is not a power of two.
+///
+/// # Example
+///
+/// This allows for an address aligned to 2MiB huge pages, that can be
+/// base-10 or base-16.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{maybe_hex, page_aligned_to};
+///
+/// fn address(s: &str) -> Result<u64, String> {
+///     page_aligned_to(s, 2 << 20, maybe_hex)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=address)]
+///     address: u64,
+/// }
+/// # let args = Args::parse_from(&["", "--address", "0x7F0000000000"]);
+/// # assert_eq!(args.address, 0x7F00_0000_0000);
+/// # assert_eq!(
+/// #     address("0x1000"),
+/// #     Err(String::from("not a multiple of the page size of 2MiB"))
+/// # );
+/// ```
+///
+/// ## Error Messages
+///
+/// Values that are not a multiple of the page size will show an error
+/// message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--address <address>': not a multiple of the page size of 2MiB
+/// ```
+pub fn page_aligned_to<T, F>(s: &str, page_size: u64, parse: F) -> Result<T, String>
+where
+    T: PrimInt + sign::Unsigned,
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    assert!(
+        page_size.is_power_of_two(),
+        "page size must be a power of two"
+    );
+
+    let value = parse(s)?;
+    if value
+        .to_u128()
+        .is_some_and(|v| v % u128::from(page_size) == 0)
+    {
+        Ok(value)
+    } else {
+        let page_size = PrefixBound {
+            bound: page_size,
+            table: &MEM_SIZE_UNITS,
+        };
+        Err(format!("not a multiple of the page size of {page_size}"))
+    }
+}
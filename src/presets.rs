@@ -0,0 +1,102 @@
+//! Ready-made parsers for common bounded quantities.
+//!
+//! These are compositions of the parsers in the crate root, for values that
+//! come up in many applications.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use clap_num::presets;
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[clap(long, value_parser=presets::pwm_duty_cycle)]
+//!     duty: u8,
+//!     #[clap(long, value_parser=presets::dac_12bit)]
+//!     code: u16,
+//! }
+//! # let args = Args::parse_from(&["", "--duty", "50%", "--code", "0xFFF"]);
+//! # assert_eq!(args.duty, 50);
+//! # assert_eq!(args.code, 0xFFF);
+//! ```
+
+use crate::{check_range, maybe_hex_range, number_range, parse_decimal};
+
+// strips the optional trailing percent sign
+fn strip_percent(s: &str) -> &str {
+    s.strip_suffix('%').unwrap_or(s)
+}
+
+/// Validates a PWM duty cycle in percent, from 0 to 100.
+///
+/// The value may be followed by a `%` sign, for example `50%`.
+pub fn pwm_duty_cycle(s: &str) -> Result<u8, String> {
+    percent(s)
+}
+
+/// Validates a percentage, such as a volume, from 0 to 100.
+///
+/// The value may be followed by a `%` sign, for example `75%`.
+pub fn percent(s: &str) -> Result<u8, String> {
+    number_range(strip_percent(s), 0, 100)
+}
+
+/// Validates a probability, from 0 to 1.
+///
+/// The value may also be a percentage followed by a `%` sign, for example
+/// `15%` for 0.15.
+pub fn probability(s: &str) -> Result<f64, String> {
+    let (value, scale) = match s.strip_suffix('%') {
+        Some(percent) => (percent, 100.0),
+        None => (s, 1.0),
+    };
+    let value: f64 = parse_decimal(value)?;
+    if value.is_nan() {
+        return Err(String::from("probability is not a number"));
+    }
+    check_range(value, 0.0, scale)
+        .map(|value| value / scale)
+        .map_err(String::from)
+}
+
+/// Validates an 8-bit DAC code that can be base-10 or base-16.
+pub fn dac_8bit(s: &str) -> Result<u8, String> {
+    maybe_hex_range(s, 0, 0xFF)
+}
+
+/// Validates a 10-bit DAC code that can be base-10 or base-16.
+pub fn dac_10bit(s: &str) -> Result<u16, String> {
+    maybe_hex_range(s, 0, 0x3FF)
+}
+
+/// Validates a 12-bit DAC code that can be base-10 or base-16.
+pub fn dac_12bit(s: &str) -> Result<u16, String> {
+    maybe_hex_range(s, 0, 0xFFF)
+}
+
+/// Validates a 16-bit DAC code that can be base-10 or base-16.
+pub fn dac_16bit(s: &str) -> Result<u16, String> {
+    maybe_hex_range(s, 0, 0xFFFF)
+}
+
+/// Validates an 8-bit RGB color channel that can be base-10 or base-16.
+pub fn rgb_channel(s: &str) -> Result<u8, String> {
+    maybe_hex_range(s, 0, 0xFF)
+}
+
+/// Validates an ASCII code point that can be base-10 or base-16, from 0 to
+/// 127.
+pub fn ascii(s: &str) -> Result<u8, String> {
+    maybe_hex_range(s, 0, 0x7F)
+}
+
+/// Validates a MIDI note number or velocity, from 0 to 127.
+pub fn midi(s: &str) -> Result<u8, String> {
+    number_range(s, 0, 127)
+}
+
+/// Validates a TCP or UDP port number, from 1 to 65535.
+pub fn port(s: &str) -> Result<u16, String> {
+    number_range(s, 1, u16::MAX)
+}
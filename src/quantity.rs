@@ -0,0 +1,144 @@
+use crate::{check_range, parse_decimal, OVERFLOW_MSG};
+
+/// Builder for a parser of quantities with units, such as `12.5cm` or `3in`,
+/// converted to a base unit.
+///
+/// Each unit has a factor that converts it to the base unit, for example
+/// `0.01` for centimetres when the base unit is metres.
+/// Values without a unit are in the base unit.
+///
+/// Unlike [`UnitTable`](crate::UnitTable), factors can be fractional and the
+/// result is an `f64`.
+///
+/// # Example
+///
+/// This allows for a length in metres, centimetres, or inches.
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::QuantityParser;
+///
+/// fn length(s: &str) -> Result<f64, String> {
+///     QuantityParser::new("m")
+///         .unit("cm", 0.01)
+///         .unit("in", 0.0254)
+///         .parse_range(s, 0.0, 10.0)
+/// }
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser=length)]
+///     length: f64,
+/// }
+/// # let args = Args::parse_from(&["", "--length", "12.5cm"]);
+/// # assert_eq!(args.length, 0.125);
+/// # assert_eq!(length("11m"), Err(String::from("exceeds maximum of 10m")));
+/// ```
+///
+/// ## Error Messages
+///
+/// Values with a unit that is not in the table will show an error message
+/// similar to this:
+///
+/// ```text
+/// error: Invalid value for '--length <length>': unknown unit 'ft', expected one of m, cm, in
+/// ```
+///
+/// The bounds are shown in the base unit, values exceeding the limits will
+/// show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--length <length>': exceeds maximum of 10m
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantityParser {
+    base: &'static str,
+    units: Vec<(&'static str, f64)>,
+}
+
+impl QuantityParser {
+    /// Creates a new parser with a base unit, such as `m`.
+    pub fn new(base: &'static str) -> Self {
+        Self {
+            base,
+            units: Vec::new(),
+        }
+        .unit(base, 1.0)
+    }
+
+    /// Adds a unit with the factor that converts it to the base unit,
+    /// replacing the factor if the unit is already in the table.
+    ///
+    /// Units are case sensitive, and must not contain digits, `.`, `_`, `+`,
+    /// or `-`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the factor is not a positive finite number.
+    #[must_use]
+    pub fn unit(mut self, unit: &'static str, factor: f64) -> Self {
+        debug_assert!(
+            !unit.is_empty()
+                && !unit
+                    .contains(|c: char| c.is_ascii_digit() || matches!(c, '.' | '_' | '+' | '-')),
+            "invalid unit '{unit}'"
+        );
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "factor of {factor} for unit '{unit}' must be a positive finite number"
+        );
+        match self.units.iter_mut().find(|(name, _)| *name == unit) {
+            Some((_, f)) => *f = factor,
+            None => self.units.push((unit, factor)),
+        }
+        self
+    }
+
+    /// Parses a quantity, converted to the base unit.
+    pub fn parse(&self, s: &str) -> Result<f64, String> {
+        let value_len = s
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '_' | '+' | '-')))
+            .unwrap_or(s.len());
+        let (value, unit) = s.split_at(value_len);
+
+        let factor = if unit.is_empty() {
+            1.0
+        } else {
+            self.factor(unit).ok_or_else(|| {
+                let units: Vec<&str> = self.units.iter().map(|(name, _)| *name).collect();
+                format!(
+                    "unknown unit '{unit}', expected one of {}",
+                    units.join(", ")
+                )
+            })?
+        };
+
+        if value.is_empty() && !unit.is_empty() {
+            return Err(format!("no value found before unit '{unit}'"));
+        }
+
+        let val = parse_decimal::<f64>(value)? * factor;
+        if val.is_finite() {
+            Ok(val)
+        } else {
+            Err(String::from(OVERFLOW_MSG))
+        }
+    }
+
+    /// Parses a quantity, converted to the base unit, within a range.
+    ///
+    /// The bounds are in the base unit.
+    pub fn parse_range(&self, s: &str, min: f64, max: f64) -> Result<f64, String> {
+        debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+        let val = self.parse(s)?;
+        check_range(val, min, max)
+            .map_err(|e| String::from(e.map(|bound| format!("{bound}{}", self.base))))
+    }
+
+    fn factor(&self, unit: &str) -> Option<f64> {
+        self.units
+            .iter()
+            .find(|(name, _)| *name == unit)
+            .map(|&(_, factor)| factor)
+    }
+}
@@ -0,0 +1,251 @@
+//! Lower-level building blocks for parsers that stay consistent with the
+//! rest of this crate.
+//!
+//! The parsers in this module accept the same values as the parsers of the
+//! same name in the crate root, but return the original error of the
+//! standard library or of [num-traits] instead of an error message, leaving
+//! the presentation of errors to the caller.
+//! The errors are not rewritten, for example a negative value passed to a
+//! parser of an unsigned type is an invalid digit rather than
+//! [`NEGATIVE_MSG`].
+//!
+//! The helpers in this module split values into the same tokens as the
+//! parsers in this crate, and the message constants and [`check_range`]
+//! produce the same errors, so custom parsers can be combined with the
+//! parsers in this crate without differences in syntax or wording.
+//!
+//! For the other parsers in this crate, [`ParseError`](crate::ParseError)
+//! provides the kind and span of an error message, and
+//! [`NumberParser::try_parse`](crate::NumberParser::try_parse) returns it
+//! directly.
+//!
+//! # Example
+//!
+//! ```
+//! use clap_num::raw;
+//! use core::num::IntErrorKind;
+//!
+//! let err = raw::number::<u8>("256").unwrap_err();
+//! assert_eq!(err.kind(), &IntErrorKind::PosOverflow);
+//!
+//! match raw::maybe_hex_range::<u16>("0x200", 0, 0x1FF) {
+//!     Err(raw::Error::AboveMaximum(max)) => assert_eq!(max, 0x1FF),
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! A custom parser of values in units of 100, such as `3h` for 300:
+//!
+//! ```
+//! use clap_num::raw;
+//!
+//! fn hundreds(s: &str) -> Result<u32, String> {
+//!     let digits = s.strip_suffix('h').ok_or("expected a value such as 3h")?;
+//!     let val = raw::number::<u32>(digits).map_err(|e| e.to_string())?;
+//!     let val = val.checked_mul(100).ok_or(raw::OVERFLOW_MSG)?;
+//!     raw::check_range(val, 100, 1_000)
+//! }
+//!
+//! assert_eq!(hundreds("3h"), Ok(300));
+//! assert_eq!(hundreds("11h"), Err(String::from("exceeds maximum of 1000")));
+//! ```
+//!
+//! [num-traits]: https://docs.rs/num-traits
+
+use crate::{strip_hex_prefix, strip_radix_prefix, Base, RangeError, SiPrefix};
+use core::fmt;
+use core::str::FromStr;
+use num_traits::{sign, Num};
+use std::borrow::Cow;
+
+/// Error message for values that are too large for the target type.
+pub const OVERFLOW_MSG: &str = "number too large to fit in target type";
+/// Error message for values that are too small for the target type.
+pub const UNDERFLOW_MSG: &str = "number too small to fit in target type";
+/// Error message for values with a character that is not a digit.
+pub const INVALID_DIGIT_MSG: &str = "invalid digit found in string";
+/// Error message for empty values.
+pub const EMPTY_MSG: &str = "cannot parse integer from empty string";
+/// Error message for negative values of unsigned types.
+pub const NEGATIVE_MSG: &str = "value cannot be negative";
+/// Error message for values with a fractional part where an integer is
+/// expected.
+pub const NOT_AN_INTEGER_MSG: &str = "not an integer";
+
+/// An error from a parser in this module with bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E, T> {
+    /// The value could not be parsed, with the original error.
+    Parse(E),
+    /// The value exceeds the maximum of the range, with the maximum.
+    AboveMaximum(T),
+    /// The value is less than the minimum of the range, with the minimum.
+    BelowMinimum(T),
+}
+
+impl<E, T> From<RangeError<T>> for Error<E, T> {
+    fn from(e: RangeError<T>) -> Self {
+        match e {
+            RangeError::AboveMaximum(max) => Self::AboveMaximum(max),
+            RangeError::BelowMinimum(min) => Self::BelowMinimum(min),
+        }
+    }
+}
+
+/// Formats the error with the same message as the parsers in the crate root.
+impl<E: fmt::Display, T: fmt::Display> fmt::Display for Error<E, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => e.fmt(f),
+            Self::AboveMaximum(max) => write!(f, "exceeds maximum of {max}"),
+            Self::BelowMinimum(min) => write!(f, "less than minimum of {min}"),
+        }
+    }
+}
+
+impl<E, T> std::error::Error for Error<E, T>
+where
+    E: std::error::Error + 'static,
+    T: fmt::Debug + fmt::Display,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a base-10 value, returning the error of [`FromStr`].
+///
+/// Underscores can be used as digit separators.
+pub fn number<T: FromStr>(s: &str) -> Result<T, T::Err> {
+    crate::strip_underscores(s).parse()
+}
+
+/// Parses a base-10 value within a range, see
+/// [`number_range`](crate::number_range).
+pub fn number_range<T>(s: &str, min: T, max: T) -> Result<T, Error<T::Err, T>>
+where
+    T: FromStr,
+    T: PartialOrd,
+    T: fmt::Display,
+{
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let val = number(s).map_err(Error::Parse)?;
+    Ok(crate::check_range(val, min, max)?)
+}
+
+// parses the digits following a radix prefix in `radix`, or the whole value
+// in base-10 if there is no prefix
+fn maybe_radix<T: Num>(digits: Option<&str>, s: &str, radix: u32) -> Result<T, T::FromStrRadixErr> {
+    match digits {
+        Some(digits) => T::from_str_radix(&crate::strip_underscores(digits), radix),
+        None => T::from_str_radix(&crate::strip_underscores(s), 10),
+    }
+}
+
+/// Parses an unsigned value that can be base-10 or base-16, returning the
+/// error of [`Num::from_str_radix`], see [`maybe_hex`](crate::maybe_hex).
+pub fn maybe_hex<T: Num + sign::Unsigned>(s: &str) -> Result<T, T::FromStrRadixErr> {
+    maybe_radix(strip_hex_prefix(s), s, 16)
+}
+
+/// Parses an unsigned value that can be base-10 or base-2, returning the
+/// error of [`Num::from_str_radix`], see [`maybe_bin`](crate::maybe_bin).
+pub fn maybe_bin<T: Num + sign::Unsigned>(s: &str) -> Result<T, T::FromStrRadixErr> {
+    maybe_radix(strip_radix_prefix(s, 'b'), s, 2)
+}
+
+/// Parses an unsigned value that can be base-10 or base-8, returning the
+/// error of [`Num::from_str_radix`], see [`maybe_oct`](crate::maybe_oct).
+pub fn maybe_oct<T: Num + sign::Unsigned>(s: &str) -> Result<T, T::FromStrRadixErr> {
+    maybe_radix(strip_radix_prefix(s, 'o'), s, 8)
+}
+
+/// Parses an unsigned value that can be base-10 or base-16 within a range,
+/// see [`maybe_hex_range`](crate::maybe_hex_range).
+///
+/// Unlike [`maybe_hex_range`](crate::maybe_hex_range), the message of the
+/// error shows the bounds in base-10.
+pub fn maybe_hex_range<T>(s: &str, min: T, max: T) -> Result<T, Error<T::FromStrRadixErr, T>>
+where
+    T: Num + sign::Unsigned,
+    T: PartialOrd,
+    T: fmt::Display,
+{
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    let val = maybe_hex(s).map_err(Error::Parse)?;
+    Ok(crate::check_range(val, min, max)?)
+}
+
+/// Checks that a value is within an inclusive range, with the same error
+/// messages as the range parsers in this crate, such as
+/// `exceeds maximum of 99`.
+pub fn check_range<T>(val: T, min: T, max: T) -> Result<T, String>
+where
+    T: PartialOrd,
+    T: fmt::Display,
+{
+    debug_assert!(min <= max, "minimum of {min} exceeds maximum of {max}");
+    crate::check_range(val, min, max).map_err(String::from)
+}
+
+/// Removes underscore digit separators, only allocating if there are
+/// separators.
+///
+/// ```
+/// assert_eq!(clap_num::raw::strip_underscores("1_000"), "1000");
+/// ```
+pub fn strip_underscores(s: &str) -> Cow<'_, str> {
+    crate::strip_underscores(s)
+}
+
+/// Splits the sign from a value, returning `true` if the value is negative.
+///
+/// A leading `+` is removed as well.
+///
+/// ```
+/// assert_eq!(clap_num::raw::split_sign("-0x10"), (true, "0x10"));
+/// assert_eq!(clap_num::raw::split_sign("+10"), (false, "10"));
+/// ```
+pub fn split_sign(s: &str) -> (bool, &str) {
+    crate::split_sign(s)
+}
+
+/// Splits a `0x`, `0b`, or `0o` prefix (case insensitive) from a value,
+/// returning the base and the digits.
+///
+/// Values without a prefix are [`Decimal`](Base::Decimal), and the sign must
+/// be split first with [`split_sign`].
+///
+/// ```
+/// use clap_num::{raw, Base};
+///
+/// assert_eq!(raw::split_radix_prefix("0XFF"), (Base::Hexadecimal, "FF"));
+/// assert_eq!(raw::split_radix_prefix("255"), (Base::Decimal, "255"));
+/// ```
+pub fn split_radix_prefix(s: &str) -> (Base, &str) {
+    [Base::Hexadecimal, Base::Binary, Base::Octal]
+        .into_iter()
+        .find_map(|base| strip_radix_prefix(s, base.symbol()).map(|digits| (base, digits)))
+        .unwrap_or((Base::Decimal, s))
+}
+
+/// Splits a value at the first SI symbol, such as `k` or `M`, returning the
+/// digits before the symbol, the prefix, and the digits after the symbol.
+///
+/// This returns `None` if there is no SI symbol.
+/// As with [`si_number`](crate::si_number), only the prefixes in
+/// [`SiPrefix::ALL`] are split, not the decimal prefixes.
+///
+/// ```
+/// use clap_num::{raw, SiPrefix};
+///
+/// assert_eq!(raw::split_si_symbol("4k7"), Some(("4", SiPrefix::Kilo, "7")));
+/// assert_eq!(raw::split_si_symbol("1.5M"), Some(("1.5", SiPrefix::Mega, "")));
+/// assert_eq!(raw::split_si_symbol("100"), None);
+/// ```
+pub fn split_si_symbol(s: &str) -> Option<(&str, SiPrefix, &str)> {
+    crate::split_si_symbol(s, false)
+}
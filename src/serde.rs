@@ -0,0 +1,173 @@
+//! Deserialization helpers for use with [serde].
+//!
+//! This module is only available with the `serde` feature.
+//!
+//! Each function deserializes a value with the same syntax as the parser of
+//! the same name, for use with `#[serde(deserialize_with = "...")]`.
+//! This allows configuration files to accept the same values as the command
+//! line.
+//!
+//! Integers are accepted in addition to strings, and are parsed from their
+//! decimal representation.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(deserialize_with = "clap_num::serde::deserialize_si_number")]
+//!     size: u32,
+//!     #[serde(deserialize_with = "clap_num::serde::deserialize_maybe_hex")]
+//!     addr: u32,
+//! }
+//!
+//! let config: Config = toml::from_str(
+//!     r#"
+//!     size = "4k"
+//!     addr = "0x1F"
+//!     "#,
+//! )
+//! .unwrap();
+//! assert_eq!(config.size, 4_000);
+//! assert_eq!(config.addr, 0x1F);
+//! ```
+//!
+//! [serde]: https://serde.rs
+
+use ::serde::de::{self, Deserializer, Visitor};
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use num_traits::{sign, CheckedAdd, CheckedMul, CheckedSub, Num, Zero};
+
+struct ParseVisitor<T> {
+    parse: fn(&str) -> Result<T, String>,
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> ParseVisitor<T> {
+    fn new(parse: fn(&str) -> Result<T, String>, expecting: &'static str) -> Self {
+        Self {
+            parse,
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Visitor<'_> for ParseVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        (self.parse)(v).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+}
+
+/// Deserializes a decimal integer, with the syntax of
+/// [`number_range`](crate::number_range).
+pub fn deserialize_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    <T as FromStr>::Err: fmt::Display,
+    T: FromStr,
+{
+    deserializer.deserialize_any(ParseVisitor::new(crate::parse_decimal, "an integer"))
+}
+
+/// Deserializes an integer with an optional SI symbol, with the syntax of
+/// [`si_number`](crate::si_number).
+pub fn deserialize_si_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    <T as TryFrom<u128>>::Error: fmt::Display,
+    <T as FromStr>::Err: fmt::Display,
+    T: CheckedAdd,
+    T: CheckedMul,
+    T: CheckedSub,
+    T: FromStr,
+    T: PartialOrd,
+    T: TryFrom<u128>,
+    T: Zero,
+{
+    deserializer.deserialize_any(ParseVisitor::new(
+        crate::si_number,
+        "an integer with an optional SI symbol",
+    ))
+}
+
+/// Deserializes a decimal or `0x` prefixed hexadecimal integer, with the
+/// syntax of [`maybe_hex`](crate::maybe_hex).
+pub fn deserialize_maybe_hex<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    <T as Num>::FromStrRadixErr: fmt::Display,
+    T: Num + sign::Unsigned,
+{
+    deserializer.deserialize_any(ParseVisitor::new(
+        crate::maybe_hex,
+        "a decimal or hexadecimal integer",
+    ))
+}
+
+/// Deserializes a decimal or `0b` prefixed binary integer, with the syntax
+/// of [`maybe_bin`](crate::maybe_bin).
+pub fn deserialize_maybe_bin<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    <T as Num>::FromStrRadixErr: fmt::Display,
+    T: Num + sign::Unsigned,
+{
+    deserializer.deserialize_any(ParseVisitor::new(
+        crate::maybe_bin,
+        "a decimal or binary integer",
+    ))
+}
+
+/// Deserializes a decimal or `0o` prefixed octal integer, with the syntax
+/// of [`maybe_oct`](crate::maybe_oct).
+pub fn deserialize_maybe_oct<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    <T as Num>::FromStrRadixErr: fmt::Display,
+    T: Num + sign::Unsigned,
+{
+    deserializer.deserialize_any(ParseVisitor::new(
+        crate::maybe_oct,
+        "a decimal or octal integer",
+    ))
+}
+
+/// Deserializes a chmod-style octal permission mode, with the syntax of
+/// [`file_mode`](crate::file_mode).
+pub fn deserialize_file_mode<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(ParseVisitor::new(
+        crate::file_mode,
+        "an octal permission mode",
+    ))
+}
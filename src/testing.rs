@@ -0,0 +1,863 @@
+//! Property testing support for the syntax accepted by each parser.
+//!
+//! This module is only available with the `testing` feature.
+//!
+//! The `valid_*` functions return [proptest] strategies generating a value and
+//! a string that the corresponding parser accepts and parses to that value.
+//! The `invalid_*` functions return strategies generating strings that the
+//! corresponding parser rejects for every target type.
+//! Strategies for [`SiParser`](crate::SiParser) options are named after the
+//! option, such as [`valid_si_names`] for
+//! [`allow_names`](crate::SiParser::allow_names).
+//!
+//! The `*Input` types implement [`arbitrary::Arbitrary`] with the same valid
+//! strings, for use with fuzzers.
+//!
+//! [`check_invariants`] runs every parser of a [`Grammar`] on any string,
+//! panicking if a parser panics or returns a value that violates its
+//! contract.
+//! The `fuzz` directory of the repository has [cargo-fuzz] targets for each
+//! grammar.
+//!
+//! # Example
+//!
+//! ```
+//! use clap_num::{maybe_hex, testing};
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::default();
+//! runner
+//!     .run(&testing::valid_maybe_hex::<u32>(), |(value, s)| {
+//!         assert_eq!(maybe_hex::<u32>(&s), Ok(value));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! runner
+//!     .run(&testing::invalid_maybe_hex(), |s| {
+//!         assert!(maybe_hex::<u32>(&s).is_err());
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+//!
+//! [cargo-fuzz]: https://github.com/rust-fuzz/cargo-fuzz
+//! [proptest]: https://docs.rs/proptest
+
+use core::fmt;
+use num_traits::sign::Unsigned;
+use num_traits::PrimInt;
+use proptest::prelude::*;
+use std::net::Ipv4Addr;
+
+mod invariants;
+
+pub use invariants::{check_invariants, Grammar};
+
+const SI_SYMBOLS: [(char, usize, &str); 8] = [
+    ('k', 3, "kilo"),
+    ('M', 6, "mega"),
+    ('G', 9, "giga"),
+    ('T', 12, "tera"),
+    ('P', 15, "peta"),
+    ('E', 18, "exa"),
+    ('Z', 21, "zetta"),
+    ('Y', 24, "yotta"),
+];
+
+// inserts an underscore separator somewhere after `prefix_len` bytes
+fn with_separator(mut s: String, prefix_len: usize, separator: Option<usize>) -> String {
+    if let Some(position) = separator {
+        let digits = s.len() - prefix_len;
+        s.insert(prefix_len + position % (digits + 1), '_');
+    }
+    s
+}
+
+fn format_number<T: fmt::Display>(value: T, separator: Option<usize>) -> String {
+    let s = value.to_string();
+    let sign_len = usize::from(s.starts_with('-'));
+    with_separator(s, sign_len, separator)
+}
+
+fn format_maybe_hex<T>(value: T, style: u8, separator: Option<usize>) -> String
+where
+    T: fmt::Display + fmt::LowerHex + fmt::UpperHex,
+{
+    match style % 3 {
+        0 => format_number(value, separator),
+        1 => with_separator(format!("0x{value:x}"), 2, separator),
+        _ => with_separator(format!("0X{value:X}"), 2, separator),
+    }
+}
+
+fn format_maybe_bin<T>(value: T, style: u8, separator: Option<usize>) -> String
+where
+    T: fmt::Display + fmt::Binary,
+{
+    match style % 3 {
+        0 => format_number(value, separator),
+        1 => with_separator(format!("0b{value:b}"), 2, separator),
+        _ => with_separator(format!("0B{value:b}"), 2, separator),
+    }
+}
+
+fn format_maybe_oct<T>(value: T, style: u8, separator: Option<usize>) -> String
+where
+    T: fmt::Display + fmt::Octal,
+{
+    match style % 3 {
+        0 => format_number(value, separator),
+        1 => with_separator(format!("0o{value:o}"), 2, separator),
+        _ => with_separator(format!("0O{value:o}"), 2, separator),
+    }
+}
+
+fn format_hex_only<T>(value: T, style: u8, separator: Option<usize>) -> String
+where
+    T: fmt::LowerHex + fmt::UpperHex,
+{
+    match style % 3 {
+        0 => with_separator(format!("{value:x}"), 0, separator),
+        1 => with_separator(format!("0x{value:x}"), 2, separator),
+        _ => with_separator(format!("0X{value:X}"), 2, separator),
+    }
+}
+
+fn format_si_number<T: fmt::Display>(value: T, style: u8, prefix: u8) -> String {
+    let decimal = value.to_string();
+    let (sign, digits) = decimal.split_at(usize::from(decimal.starts_with('-')));
+    let (symbol, scale, _) = SI_SYMBOLS[usize::from(prefix) % SI_SYMBOLS.len()];
+
+    match style % 4 {
+        // plain, in the format of "1234"
+        0 => decimal,
+        // in the format of "1.234k" or "1k234"
+        1 | 2 => {
+            let padded = format!("{digits:0>scale$}");
+            let (int, frac) = if digits.len() > scale {
+                digits.split_at(digits.len() - scale)
+            } else {
+                ("0", padded.as_str())
+            };
+            let frac = frac.trim_end_matches('0');
+
+            if frac.is_empty() {
+                format!("{sign}{int}{symbol}")
+            } else if style % 4 == 1 {
+                format!("{sign}{int}.{frac}{symbol}")
+            } else {
+                format!("{sign}{int}{symbol}{frac}")
+            }
+        }
+        // exponent, in the format of "1234e3"
+        _ => {
+            let mantissa = digits.trim_end_matches('0');
+            if mantissa.is_empty() {
+                String::from("0e0")
+            } else {
+                let exponent = digits.len() - mantissa.len();
+                format!("{sign}{mantissa}e{exponent}")
+            }
+        }
+    }
+}
+
+// returns the index and entry of the SI symbol in a value, if any
+fn find_si_symbol(s: &str) -> Option<(usize, &'static (char, usize, &'static str))> {
+    s.char_indices().find_map(|(idx, c)| {
+        SI_SYMBOLS
+            .iter()
+            .find(|(symbol, _, _)| *symbol == c)
+            .map(|entry| (idx, entry))
+    })
+}
+
+fn format_si_whitespace<T: fmt::Display>(value: T, style: u8, prefix: u8, spaces: u8) -> String {
+    let mut s = format_si_number(value, style, prefix);
+    if let Some((idx, _)) = find_si_symbol(&s) {
+        s.insert_str(idx, &" ".repeat(usize::from(spaces % 3)));
+    }
+    s
+}
+
+fn format_si_names<T: fmt::Display>(value: T, style: u8, prefix: u8, case: u8) -> String {
+    let s = format_si_number(value, style, prefix);
+    match find_si_symbol(&s) {
+        // names must follow the numeric value, in the format of "1.5kilo"
+        Some((idx, (_, _, name))) if idx + 1 == s.len() => {
+            let name = match case % 3 {
+                0 => String::from(*name),
+                1 => name.to_ascii_uppercase(),
+                _ => name[..1].to_ascii_uppercase() + &name[1..],
+            };
+            format!("{}{name}", &s[..idx])
+        }
+        _ => s,
+    }
+}
+
+fn format_bcd<T: fmt::Display>(value: T, style: u8, separator: Option<usize>) -> String {
+    let digits = value.to_string();
+    match style % 3 {
+        0 => with_separator(digits, 0, separator),
+        1 => with_separator(format!("0x{digits}"), 2, separator),
+        _ => with_separator(format!("0X{digits}"), 2, separator),
+    }
+}
+
+fn format_gray_code<T>(value: T, style: u8, separator: Option<usize>) -> String
+where
+    T: PrimInt + Unsigned + fmt::Display + fmt::LowerHex + fmt::UpperHex,
+{
+    format_maybe_hex(crate::gray_encode(value), style, separator)
+}
+
+fn format_ipv4(addr: u32, style: u8, separator: Option<usize>) -> (u32, String) {
+    match style % 2 {
+        0 => (addr, Ipv4Addr::from(addr).to_string()),
+        _ => (addr, format_maybe_hex(addr, style / 2, separator)),
+    }
+}
+
+fn format_prefix_len(len: u8, width: u8, slash: bool) -> (u8, String) {
+    let len = len % (width + 1);
+    if slash {
+        (len, format!("/{len}"))
+    } else {
+        (len, len.to_string())
+    }
+}
+
+fn format_ipv4_prefix_len(len: u8, slash: bool) -> (u8, String) {
+    format_prefix_len(len, 32, slash)
+}
+
+fn format_ipv6_prefix_len(len: u8, slash: bool) -> (u8, String) {
+    format_prefix_len(len, 128, slash)
+}
+
+fn format_ipv4_netmask(len: u8, slash: bool) -> (u32, String) {
+    let (len, s) = format_ipv4_prefix_len(len, slash);
+    (u32::MAX.checked_shl(32 - u32::from(len)).unwrap_or(0), s)
+}
+
+fn format_ipv6_netmask(len: u8, slash: bool) -> (u128, String) {
+    let (len, s) = format_ipv6_prefix_len(len, slash);
+    (u128::MAX.checked_shl(128 - u32::from(len)).unwrap_or(0), s)
+}
+
+fn format_file_mode(mode: u16, style: u8) -> (u32, String) {
+    let mode = u32::from(mode) & 0o7777;
+    match style % 4 {
+        0 => (mode, format!("{mode:o}")),
+        1 => (mode, format!("0{mode:o}")),
+        2 => (mode, format!("0o{mode:o}")),
+        _ => (mode, format!("0O{mode:o}")),
+    }
+}
+
+fn format_uuid(id: u128, style: u8) -> (u128, String) {
+    let hex = format!("{id:032x}");
+    let hyphenated = format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    );
+    match style % 4 {
+        0 => (id, hyphenated),
+        1 => (id, hyphenated.to_ascii_uppercase()),
+        2 => (id, hex),
+        _ => (id, format!("0x{id:x}")),
+    }
+}
+
+fn format_hex_color(color: u32, style: u8) -> (u32, String) {
+    let [r, g, b, a] = color.to_be_bytes();
+    let prefix = match style / 4 % 3 {
+        0 => "#",
+        1 => "0x",
+        _ => "0X",
+    };
+    // short forms repeat the high nibble of each channel
+    let nibble = |c: u8| c >> 4;
+    let short = |c: u8| nibble(c) * 0x11;
+
+    match style % 4 {
+        0 => (color, format!("{prefix}{color:08x}")),
+        1 => (
+            u32::from_be_bytes([r, g, b, 0xFF]),
+            format!("{prefix}{r:02X}{g:02X}{b:02X}"),
+        ),
+        2 => (
+            u32::from_be_bytes([short(r), short(g), short(b), 0xFF]),
+            format!("{prefix}{:x}{:x}{:x}", nibble(r), nibble(g), nibble(b)),
+        ),
+        _ => (
+            u32::from_be_bytes([short(r), short(g), short(b), short(a)]),
+            format!(
+                "{prefix}{:X}{:X}{:X}{:X}",
+                nibble(r),
+                nibble(g),
+                nibble(b),
+                nibble(a)
+            ),
+        ),
+    }
+}
+
+/// Strategy generating values and strings accepted by
+/// [`number_range`](crate::number_range) without range limits.
+pub fn valid_number<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + Clone,
+{
+    (any::<T>(), any::<Option<usize>>())
+        .prop_map(|(value, separator)| (value.clone(), format_number(value, separator)))
+}
+
+/// Strategy generating strings rejected by
+/// [`number_range`](crate::number_range).
+pub fn invalid_number() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("-")),
+        "[0-9]{1,5}\\.[0-9]{1,5}",
+        "[0-9]{0,5}[a-zA-Z][0-9a-zA-Z]{0,5}",
+        "[0-9]{1,5}[ .,][0-9]{1,5}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`maybe_hex`](crate::maybe_hex).
+pub fn valid_maybe_hex<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + fmt::LowerHex + fmt::UpperHex + Unsigned + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<Option<usize>>()).prop_map(|(value, style, separator)| {
+        (value.clone(), format_maybe_hex(value, style, separator))
+    })
+}
+
+/// Strategy generating strings rejected by [`maybe_hex`](crate::maybe_hex).
+pub fn invalid_maybe_hex() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("0x")),
+        "-[0-9]{1,5}",
+        "0[xX][0-9a-fA-F]{0,5}[g-zG-Z][0-9a-zA-Z]{0,5}",
+        "[1-9][0-9]{0,5}[a-zA-Z][0-9a-zA-Z]{0,5}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`maybe_bin`](crate::maybe_bin).
+pub fn valid_maybe_bin<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + fmt::Binary + Unsigned + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<Option<usize>>()).prop_map(|(value, style, separator)| {
+        (value.clone(), format_maybe_bin(value, style, separator))
+    })
+}
+
+/// Strategy generating strings rejected by [`maybe_bin`](crate::maybe_bin).
+pub fn invalid_maybe_bin() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("0b")),
+        "-[0-9]{1,5}",
+        "0[bB][01]{0,5}[2-9a-zA-Z][0-9a-zA-Z]{0,5}",
+        "[1-9][0-9]{0,5}[a-zA-Z][0-9a-zA-Z]{0,5}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`maybe_oct`](crate::maybe_oct).
+pub fn valid_maybe_oct<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + fmt::Octal + Unsigned + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<Option<usize>>()).prop_map(|(value, style, separator)| {
+        (value.clone(), format_maybe_oct(value, style, separator))
+    })
+}
+
+/// Strategy generating strings rejected by [`maybe_oct`](crate::maybe_oct).
+pub fn invalid_maybe_oct() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("0o")),
+        "-[0-9]{1,5}",
+        "0[oO][0-7]{0,5}[89a-zA-Z][0-9a-zA-Z]{0,5}",
+        "[1-9][0-9]{0,5}[a-zA-Z][0-9a-zA-Z]{0,5}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`hex_only`](crate::hex_only).
+pub fn valid_hex_only<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::LowerHex + fmt::UpperHex + Unsigned + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<Option<usize>>()).prop_map(|(value, style, separator)| {
+        (value.clone(), format_hex_only(value, style, separator))
+    })
+}
+
+/// Strategy generating strings rejected by [`hex_only`](crate::hex_only).
+pub fn invalid_hex_only() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("0x")),
+        "-[0-9a-fA-F]{1,5}",
+        "(0[xX])?[0-9a-fA-F]{0,5}[g-zG-Z][0-9a-zA-Z]{0,5}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`si_number`](crate::si_number).
+pub fn valid_si_number<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<u8>())
+        .prop_map(|(value, style, prefix)| (value.clone(), format_si_number(value, style, prefix)))
+}
+
+/// Strategy generating strings rejected by [`si_number`](crate::si_number).
+pub fn invalid_si_number() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        "[kKMGTPEZY][0-9]{0,3}",
+        "[0-9]{1,5}\\.[0-9]{1,5}",
+        "[0-9]{1,5}k[0-9]{3}[0-9]*[1-9]",
+        "[0-9]{1,5}[a-df-jl-z][0-9]{0,3}",
+        "[0-9]{1,5}[kKMGTPEZY]{2}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by an [`SiParser`] with
+/// [`allow_whitespace`](crate::SiParser::allow_whitespace).
+///
+/// [`SiParser`]: crate::SiParser
+pub fn valid_si_whitespace<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<u8>(), any::<u8>()).prop_map(
+        |(value, style, prefix, spaces)| {
+            (
+                value.clone(),
+                format_si_whitespace(value, style, prefix, spaces),
+            )
+        },
+    )
+}
+
+/// Strategy generating strings rejected by an [`SiParser`] with
+/// [`allow_whitespace`](crate::SiParser::allow_whitespace).
+///
+/// [`SiParser`]: crate::SiParser
+pub fn invalid_si_whitespace() -> impl Strategy<Value = String> {
+    prop_oneof![
+        invalid_si_number(),
+        " [0-9]{1,5}[kKMGTPEZY]?",
+        "[0-9]{1,5} [0-9]{1,5}",
+        "[0-9]{1,5}[kKMGTPEZY] [0-9]{1,3}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by an [`SiParser`] with
+/// [`allow_names`](crate::SiParser::allow_names).
+///
+/// [`SiParser`]: crate::SiParser
+pub fn valid_si_names<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<u8>(), any::<u8>()).prop_map(|(value, style, prefix, case)| {
+        (value.clone(), format_si_names(value, style, prefix, case))
+    })
+}
+
+/// Strategy generating strings rejected by an [`SiParser`] with
+/// [`allow_names`](crate::SiParser::allow_names).
+///
+/// [`SiParser`]: crate::SiParser
+pub fn invalid_si_names() -> impl Strategy<Value = String> {
+    prop_oneof![
+        invalid_si_number(),
+        "(kilo|mega|giga)",
+        "[0-9]{1,5}(kilo|mega|giga)[0-9]{1,3}",
+        "[0-9]{1,5}(kil|meg|gig)",
+        "[0-9]{1,5}(kilo|mega|giga){2}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by [`bcd`](crate::bcd).
+pub fn valid_bcd<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + fmt::Display + Unsigned + Clone,
+{
+    (any::<T>(), any::<u8>(), any::<Option<usize>>())
+        .prop_map(|(value, style, separator)| (value.clone(), format_bcd(value, style, separator)))
+}
+
+/// Strategy generating strings rejected by [`bcd`](crate::bcd).
+pub fn invalid_bcd() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("0x")),
+        "-[0-9]{1,5}",
+        "(0[xX])?[0-9]{0,5}[a-fA-F][0-9a-fA-F]{0,5}",
+        "(0[xX])?[1-9][0-9]{0,5}[g-zG-Z][0-9]{0,5}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`gray_code`](crate::gray_code).
+pub fn valid_gray_code<T>() -> impl Strategy<Value = (T, String)>
+where
+    T: Arbitrary + PrimInt + Unsigned + fmt::Display + fmt::LowerHex + fmt::UpperHex,
+{
+    (any::<T>(), any::<u8>(), any::<Option<usize>>())
+        .prop_map(|(value, style, separator)| (value, format_gray_code(value, style, separator)))
+}
+
+/// Strategy generating strings rejected by [`gray_code`](crate::gray_code).
+///
+/// This is the same as [`invalid_maybe_hex`], as the syntax is the same.
+pub fn invalid_gray_code() -> impl Strategy<Value = String> {
+    invalid_maybe_hex()
+}
+
+/// Strategy generating values and strings accepted by [`ipv4`](crate::ipv4).
+pub fn valid_ipv4() -> impl Strategy<Value = (u32, String)> {
+    (any::<u32>(), any::<u8>(), any::<Option<usize>>())
+        .prop_map(|(addr, style, separator)| format_ipv4(addr, style, separator))
+}
+
+/// Strategy generating strings rejected by [`ipv4`](crate::ipv4).
+pub fn invalid_ipv4() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        "[0-9]{1,3}\\.[0-9]{1,3}\\.[0-9]{1,3}",
+        "[0-9]{1,3}(\\.[0-9]{1,3}){4}",
+        "(25[6-9]|2[6-9][0-9]|[3-9][0-9]{2})(\\.[0-9]{1,2}){3}",
+        "[1-9][0-9]{10,15}",
+        "0[xX][0-9a-fA-F]{0,5}[g-zG-Z][0-9a-zA-Z]{0,5}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`ipv4_prefix_len`](crate::ipv4_prefix_len).
+pub fn valid_ipv4_prefix_len() -> impl Strategy<Value = (u8, String)> {
+    (any::<u8>(), any::<bool>()).prop_map(|(len, slash)| format_ipv4_prefix_len(len, slash))
+}
+
+/// Strategy generating strings rejected by
+/// [`ipv4_prefix_len`](crate::ipv4_prefix_len) and
+/// [`ipv4_netmask`](crate::ipv4_netmask).
+pub fn invalid_ipv4_prefix_len() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("/")),
+        "/?(3[3-9]|[4-9][0-9]|[1-9][0-9]{2,3})",
+        "/?-[1-9]",
+        "//[0-9]{1,2}",
+        "/?[0-9]{0,2}[a-zA-Z.][0-9]{0,2}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`ipv6_prefix_len`](crate::ipv6_prefix_len).
+pub fn valid_ipv6_prefix_len() -> impl Strategy<Value = (u8, String)> {
+    (any::<u8>(), any::<bool>()).prop_map(|(len, slash)| format_ipv6_prefix_len(len, slash))
+}
+
+/// Strategy generating strings rejected by
+/// [`ipv6_prefix_len`](crate::ipv6_prefix_len) and
+/// [`ipv6_netmask`](crate::ipv6_netmask).
+pub fn invalid_ipv6_prefix_len() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("/")),
+        "/?(129|1[3-9][0-9]|[2-9][0-9]{2}|[1-9][0-9]{3,4})",
+        "/?-[1-9]",
+        "//[0-9]{1,2}",
+        "/?[0-9]{0,2}[a-zA-Z.][0-9]{0,2}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`ipv4_netmask`](crate::ipv4_netmask).
+///
+/// See [`invalid_ipv4_prefix_len`] for strings that are rejected.
+pub fn valid_ipv4_netmask() -> impl Strategy<Value = (u32, String)> {
+    (any::<u8>(), any::<bool>()).prop_map(|(len, slash)| format_ipv4_netmask(len, slash))
+}
+
+/// Strategy generating values and strings accepted by
+/// [`ipv6_netmask`](crate::ipv6_netmask).
+///
+/// See [`invalid_ipv6_prefix_len`] for strings that are rejected.
+pub fn valid_ipv6_netmask() -> impl Strategy<Value = (u128, String)> {
+    (any::<u8>(), any::<bool>()).prop_map(|(len, slash)| format_ipv6_netmask(len, slash))
+}
+
+/// Strategy generating values and strings accepted by
+/// [`file_mode`](crate::file_mode).
+pub fn valid_file_mode() -> impl Strategy<Value = (u32, String)> {
+    (any::<u16>(), any::<u8>()).prop_map(|(mode, style)| format_file_mode(mode, style))
+}
+
+/// Strategy generating strings rejected by [`file_mode`](crate::file_mode).
+pub fn invalid_file_mode() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("0o")),
+        "-[0-7]{1,4}",
+        "[0-7]{0,3}[89a-np-zA-NP-Z_][0-7]{0,3}",
+        "(0[oO])?[1-7][0-7]{4,6}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by [`uuid`](crate::uuid).
+pub fn valid_uuid() -> impl Strategy<Value = (u128, String)> {
+    (any::<u128>(), any::<u8>()).prop_map(|(id, style)| format_uuid(id, style))
+}
+
+/// Strategy generating strings rejected by [`uuid`](crate::uuid).
+pub fn invalid_uuid() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::from("0x")),
+        "[0-9a-f]{0,31}",
+        "[0-9a-f]{33,40}",
+        "[0-9a-f]{31}[g-z]",
+        "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{16}",
+        "[0-9a-f]{7}-[0-9a-f]{5}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}",
+        "0x[1-9a-f][0-9a-f]{32,36}",
+    ]
+}
+
+/// Strategy generating values and strings accepted by
+/// [`hex_color`](crate::hex_color).
+pub fn valid_hex_color() -> impl Strategy<Value = (u32, String)> {
+    (any::<u32>(), any::<u8>()).prop_map(|(color, style)| format_hex_color(color, style))
+}
+
+/// Strategy generating strings rejected by [`hex_color`](crate::hex_color).
+pub fn invalid_hex_color() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just(String::from("#")),
+        "[0-9a-fA-F]{3,8}",
+        "#[0-9a-fA-F]{1,2}",
+        "#[0-9a-fA-F]{5}",
+        "#[0-9a-fA-F]{7}",
+        "#[0-9a-fA-F]{9,12}",
+        "(#|0x)[0-9a-fA-F]{0,5}[g-zG-Z][0-9a-fA-F]{0,2}",
+    ]
+}
+
+macro_rules! fixed_input {
+    ($(#[$meta:meta])* $NAME:ident, $TYPE:ty, $FMT:expr, $($ARG:ident),*) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $NAME {
+            /// Expected value.
+            pub value: $TYPE,
+            /// String representation of the value.
+            pub input: String,
+        }
+
+        impl<'a> arbitrary::Arbitrary<'a> for $NAME {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                $(let $ARG = u.arbitrary()?;)*
+                let (value, input) = $FMT($($ARG),*);
+                Ok(Self { value, input })
+            }
+        }
+    };
+}
+
+macro_rules! input {
+    ($(#[$meta:meta])* $NAME:ident, $FMT:expr, [$($BOUND:path),*], $($ARG:ident),*) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $NAME<T> {
+            /// Expected value.
+            pub value: T,
+            /// String representation of the value.
+            pub input: String,
+        }
+
+        impl<'a, T> arbitrary::Arbitrary<'a> for $NAME<T>
+        where
+            T: arbitrary::Arbitrary<'a> + Clone $(+ $BOUND)*,
+        {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                let value = T::arbitrary(u)?;
+                $(let $ARG = u.arbitrary()?;)*
+                Ok(Self {
+                    value: value.clone(),
+                    input: $FMT(value, $($ARG),*),
+                })
+            }
+        }
+    };
+}
+
+input!(
+    /// A value and a string accepted by [`number_range`](crate::number_range).
+    NumberInput,
+    format_number,
+    [fmt::Display],
+    separator
+);
+input!(
+    /// A value and a string accepted by [`maybe_hex`](crate::maybe_hex).
+    MaybeHexInput,
+    format_maybe_hex,
+    [fmt::Display, fmt::LowerHex, fmt::UpperHex, Unsigned],
+    style,
+    separator
+);
+input!(
+    /// A value and a string accepted by [`maybe_bin`](crate::maybe_bin).
+    MaybeBinInput,
+    format_maybe_bin,
+    [fmt::Display, fmt::Binary, Unsigned],
+    style,
+    separator
+);
+input!(
+    /// A value and a string accepted by [`maybe_oct`](crate::maybe_oct).
+    MaybeOctInput,
+    format_maybe_oct,
+    [fmt::Display, fmt::Octal, Unsigned],
+    style,
+    separator
+);
+input!(
+    /// A value and a string accepted by [`hex_only`](crate::hex_only).
+    HexOnlyInput,
+    format_hex_only,
+    [fmt::LowerHex, fmt::UpperHex, Unsigned],
+    style,
+    separator
+);
+input!(
+    /// A value and a string accepted by [`si_number`](crate::si_number).
+    SiNumberInput,
+    format_si_number,
+    [fmt::Display],
+    style,
+    prefix
+);
+input!(
+    /// A value and a string accepted by an [`SiParser`](crate::SiParser)
+    /// with [`allow_whitespace`](crate::SiParser::allow_whitespace).
+    SiWhitespaceInput,
+    format_si_whitespace,
+    [fmt::Display],
+    style,
+    prefix,
+    spaces
+);
+input!(
+    /// A value and a string accepted by an [`SiParser`](crate::SiParser)
+    /// with [`allow_names`](crate::SiParser::allow_names).
+    SiNamesInput,
+    format_si_names,
+    [fmt::Display],
+    style,
+    prefix,
+    case
+);
+input!(
+    /// A value and a string accepted by [`bcd`](crate::bcd).
+    BcdInput,
+    format_bcd,
+    [fmt::Display, Unsigned],
+    style,
+    separator
+);
+input!(
+    /// A value and a string accepted by [`gray_code`](crate::gray_code).
+    GrayCodeInput,
+    format_gray_code,
+    [PrimInt, Unsigned, fmt::Display, fmt::LowerHex, fmt::UpperHex],
+    style,
+    separator
+);
+fixed_input!(
+    /// A value and a string accepted by [`ipv4`](crate::ipv4).
+    Ipv4Input,
+    u32,
+    format_ipv4,
+    addr,
+    style,
+    separator
+);
+fixed_input!(
+    /// A value and a string accepted by
+    /// [`ipv4_prefix_len`](crate::ipv4_prefix_len).
+    Ipv4PrefixLenInput,
+    u8,
+    format_ipv4_prefix_len,
+    len,
+    slash
+);
+fixed_input!(
+    /// A value and a string accepted by
+    /// [`ipv6_prefix_len`](crate::ipv6_prefix_len).
+    Ipv6PrefixLenInput,
+    u8,
+    format_ipv6_prefix_len,
+    len,
+    slash
+);
+fixed_input!(
+    /// A value and a string accepted by [`ipv4_netmask`](crate::ipv4_netmask).
+    Ipv4NetmaskInput,
+    u32,
+    format_ipv4_netmask,
+    len,
+    slash
+);
+fixed_input!(
+    /// A value and a string accepted by [`ipv6_netmask`](crate::ipv6_netmask).
+    Ipv6NetmaskInput,
+    u128,
+    format_ipv6_netmask,
+    len,
+    slash
+);
+fixed_input!(
+    /// A value and a string accepted by [`file_mode`](crate::file_mode).
+    FileModeInput,
+    u32,
+    format_file_mode,
+    mode,
+    style
+);
+fixed_input!(
+    /// A value and a string accepted by [`uuid`](crate::uuid).
+    UuidInput,
+    u128,
+    format_uuid,
+    id,
+    style
+);
+fixed_input!(
+    /// A value and a string accepted by [`hex_color`](crate::hex_color).
+    HexColorInput,
+    u32,
+    format_hex_color,
+    color,
+    style
+);
@@ -0,0 +1,362 @@
+use crate::*;
+use core::fmt;
+use core::ops::Bound;
+
+/// Families of parsers with a similar grammar, for [`check_invariants`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Grammar {
+    /// Base-10 values, such as [`number_range`], [`exact_digits`], and
+    /// [`fraction`].
+    Decimal,
+    /// Values with a radix prefix, such as [`maybe_hex`], [`HexParser`], and
+    /// [`bcd`].
+    Radix,
+    /// Values with an SI symbol, such as [`si_number`] and [`SiParser`].
+    Si,
+    /// Values with units, such as [`mem_size`], [`duration`], and
+    /// [`QuantityParser`].
+    Units,
+    /// Values made of several values, such as [`interval`], [`pair`],
+    /// [`tolerance`], and [`ListParser`].
+    Compound,
+    /// Addresses and identifiers, such as [`ipv4`], [`uuid`], and
+    /// [`hex_color`].
+    Identifier,
+}
+
+impl Grammar {
+    /// Every grammar.
+    pub const ALL: [Self; 6] = [
+        Self::Decimal,
+        Self::Radix,
+        Self::Si,
+        Self::Units,
+        Self::Compound,
+        Self::Identifier,
+    ];
+}
+
+impl<'a> arbitrary::Arbitrary<'a> for Grammar {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&Self::ALL).copied()
+    }
+}
+
+// checks the error of a parser, returning the value if it accepted `s`
+fn accepted<T>(s: &str, result: Result<T, String>) -> Option<T> {
+    match result {
+        Ok(val) => Some(val),
+        Err(message) => {
+            assert!(!message.is_empty(), "empty error message for {s:?}");
+            check_span(s, &ParseError::new(s, message));
+            None
+        }
+    }
+}
+
+// checks that the span of an error is within the value
+fn check_span(s: &str, e: &ParseError) {
+    let span = e.span();
+    assert!(
+        span.start <= span.end
+            && span.end <= s.len()
+            && s.is_char_boundary(span.start)
+            && s.is_char_boundary(span.end),
+        "span {span:?} of error '{e}' is not within {s:?}"
+    );
+}
+
+// checks that a value accepted by a range parser is within the range
+fn in_range<T: PartialOrd + fmt::Debug>(s: &str, result: Result<T, String>, min: T, max: T) {
+    if let Some(val) = accepted(s, result) {
+        assert!(
+            min <= val && val <= max,
+            "{s:?} parsed to {val:?}, outside of {min:?}..={max:?}"
+        );
+    }
+}
+
+/// Parses a string with every parser of a grammar, panicking if a parser
+/// panics or violates one of its invariants.
+///
+/// This is intended for fuzzers and property tests of applications and of
+/// this crate, none of the parsers should panic for any input.
+/// The invariants include:
+///
+/// * Values accepted by range parsers are within the range.
+/// * Errors have a message, and the [`ParseError`] span is within the value.
+/// * Lists that are sorted or deduplicated are sorted or unique.
+///
+/// Parsers that read files or stdin are not checked.
+///
+/// # Example
+///
+/// ```
+/// use clap_num::testing::{check_invariants, Grammar};
+///
+/// for grammar in Grammar::ALL {
+///     check_invariants(grammar, "0x1_0k\u{e9}");
+/// }
+/// ```
+pub fn check_invariants(grammar: Grammar, s: &str) {
+    match grammar {
+        Grammar::Decimal => check_decimal(s),
+        Grammar::Radix => check_radix(s),
+        Grammar::Si => check_si(s),
+        Grammar::Units => check_units(s),
+        Grammar::Compound => check_compound(s),
+        Grammar::Identifier => check_identifier(s),
+    }
+}
+
+fn check_decimal(s: &str) {
+    in_range(s, number_range::<i16>(s, -1_000, 1_000), -1_000, 1_000);
+    accepted(s, number_range::<u128>(s, 0, u128::MAX));
+    in_range(s, number_at_least::<i64>(s, -5), -5, i64::MAX);
+    in_range(s, number_at_most::<u8>(s, 200), 0, 200);
+    in_range(s, positive::<i32>(s), 1, i32::MAX);
+    in_range(s, non_negative::<i8>(s), 0, i8::MAX);
+    in_range(s, negative::<i64>(s), i64::MIN, -1);
+    in_range(s, exact_digits::<u32>(s, 4), 0, 9_999);
+    in_range(s, exact_digits_range::<u16>(s, 3, 100, 500), 100, 500);
+    if let Some(val) = accepted(s, digit_string::<u64>(s)) {
+        assert_eq!(val.as_str(), s);
+    }
+    in_range(s, fraction_range(s, 0.0, 1.0), 0.0, 1.0);
+    if let Some(val) = accepted(s, fraction(s)) {
+        assert!(!val.is_nan(), "{s:?} parsed to NaN");
+    }
+    if let Some(val) = accepted(s, gain(s)) {
+        assert!(val >= 0.0, "{s:?} parsed to a gain of {val}");
+    }
+    in_range(s, multiplier_range::<u8>(s, 1, 16), 1, 16);
+    accepted(s, multiplier::<f32>(s));
+    in_range(s, presets::probability(s), 0.0, 1.0);
+    in_range(s, presets::percent(s), 0, 100);
+    in_range(s, presets::port(s), 1, u16::MAX);
+
+    #[cfg(feature = "half")]
+    {
+        use ::half::{bf16, f16};
+        in_range(
+            s,
+            half::float_range(s, f16::ZERO, f16::ONE),
+            f16::ZERO,
+            f16::ONE,
+        );
+        in_range(
+            s,
+            half::si_float_range(s, bf16::MIN, bf16::MAX),
+            bf16::MIN,
+            bf16::MAX,
+        );
+        accepted(s, half::from_bits::<f16>(s));
+    }
+
+    #[cfg(feature = "num-complex")]
+    if let Some(val) = accepted(s, complex_magnitude_range(s, 0.0, 1.0)) {
+        assert!(
+            val.norm() <= 1.0,
+            "{s:?} parsed to {val}, outside of the unit circle"
+        );
+    }
+
+    let parser = NumberParser::<f64>::decimal()
+        .range(-1e6, 1e6)
+        .max_decimal_places(2);
+    match parser.try_parse(s) {
+        Ok(val) => assert!(
+            (-1e6..=1e6).contains(&val),
+            "{s:?} parsed to {val:?}, outside of -1e6..=1e6"
+        ),
+        Err(e) => check_span(s, &e),
+    }
+}
+
+fn check_radix(s: &str) {
+    accepted(s, maybe_hex::<u64>(s));
+    in_range(s, maybe_hex_range::<u16>(s, 0x10, 0x1000), 0x10, 0x1000);
+    accepted(s, maybe_hex_with_base::<u128>(s));
+    in_range(s, maybe_bin_range::<u8>(s, 2, 100), 2, 100);
+    in_range(s, maybe_oct_range::<u32>(s, 0, 0o777), 0, 0o777);
+    in_range(s, hex_only_range::<u64>(s, 1, 0xFFFF), 1, 0xFFFF);
+    let hex = HexParser::new().prefix("$").prefix("#");
+    accepted(s, hex.parse::<u32>(s));
+    in_range(s, hex.parse_range::<u8>(s, 1, 0x7F), 1, 0x7F);
+    let radix = RadixParser::new()
+        .prefix("%", 2)
+        .prefix("&o", 8)
+        .prefix("0z", 36);
+    accepted(s, radix.parse::<u64>(s));
+    in_range(s, radix.parse_range::<u16>(s, 10, 1_000), 10, 1_000);
+    accepted(s, relative_offset::<i32>(s));
+    accepted(s, offset::<i64>(s));
+    accepted(s, bcd::<u32>(s));
+    accepted(s, gray_code::<u16>(s));
+    in_range(s, file_mode(s), 0, 0o7777);
+    in_range(s, i2c_address(s), 0, 0x7F);
+    in_range(s, i2c_address_unreserved(s), 0x08, 0x77);
+    accepted(s, repr_enum::<u8, u8>(s));
+    in_range(s, presets::dac_12bit(s), 0, 0xFFF);
+}
+
+fn check_si(s: &str) {
+    accepted(s, si_number::<i64>(s));
+    accepted(s, si_number::<u8>(s));
+    accepted(s, si_number::<u128>(s));
+    in_range(
+        s,
+        si_number_range::<i32>(s, -1_000_000, 1_000_000),
+        -1_000_000,
+        1_000_000,
+    );
+    in_range(s, si_number_at_least::<u64>(s, 1_000), 1_000, u64::MAX);
+    in_range(s, si_number_at_most::<i16>(s, 4_700), i16::MIN, 4_700);
+    accepted(s, si_number_with_unit::<u64>(s, "Hz"));
+    accepted(s, SiParser::new().binary().parse::<u64>(s));
+    let parser = SiParser::new()
+        .allow_whitespace()
+        .allow_names()
+        .optional_unit("V")
+        .rounding(Rounding::Nearest);
+    accepted(s, parser.parse::<i32>(s));
+    in_range(s, parser.parse_range::<u16>(s, 10, 10_000), 10, 10_000);
+    if let Some(range) = accepted(s, si_interval::<u32>(s, 10, 1_000_000)) {
+        assert!(
+            10 <= *range.start() && range.start() <= range.end() && *range.end() <= 1_000_000,
+            "{s:?} parsed to {range:?}"
+        );
+    }
+}
+
+fn check_units(s: &str) {
+    accepted(s, mem_size::<u64>(s));
+    in_range(s, mem_size_range::<u32>(s, 512, 1 << 30), 512, 1 << 30);
+    accepted(s, bit_rate::<u64>(s));
+    in_range(
+        s,
+        bit_rate_range::<u32>(s, 1_000, 1_000_000_000),
+        1_000,
+        1_000_000_000,
+    );
+    let units = UnitTable::new().unit("s", 512).unit("p", 4096);
+    accepted(s, prefixed_number::<i64, _>(s, &units));
+    in_range(
+        s,
+        prefixed_number_range::<u16, _>(s, &units, 512, 8192),
+        512,
+        8192,
+    );
+    if let Some(val) = accepted(s, page_aligned_to::<u64, _>(s, 4096, mem_size)) {
+        assert_eq!(val % 4096, 0, "{s:?} parsed to {val}, not page aligned");
+    }
+    let length = QuantityParser::new("m").unit("cm", 0.01).unit("in", 0.0254);
+    accepted(s, length.parse(s));
+    in_range(s, length.parse_range(s, 0.0, 10.0), 0.0, 10.0);
+
+    let min = core::time::Duration::from_millis(1);
+    let max = core::time::Duration::from_secs(3600);
+    accepted(s, duration(s));
+    in_range(s, duration_range(s, min, max), min, max);
+    accepted(s, TickParser::period(min).parse::<u32>(s));
+    accepted(
+        s,
+        TickParser::frequency(48_000)
+            .rounding(Rounding::Nearest)
+            .parse::<u64>(s),
+    );
+}
+
+fn check_compound(s: &str) {
+    if let Some((lower, upper)) = accepted(s, interval(s, si_number::<i32>)) {
+        let value = |bound| match bound {
+            Bound::Included(val) | Bound::Excluded(val) => Some(val),
+            Bound::Unbounded => None,
+        };
+        if let (Some(lower), Some(upper)) = (value(lower), value(upper)) {
+            assert!(
+                lower <= upper,
+                "{s:?} parsed to an interval of {lower}..{upper}"
+            );
+        }
+    }
+    if let Some((low, high)) = accepted(s, pair(s, maybe_hex::<u16>)) {
+        assert!(low <= high, "{s:?} parsed to {low}:{high}");
+    }
+    let parser = GeometryParser::new().width(1, 1920).x(-100, 100);
+    if let Some(geometry) = accepted(s, parser.parse(s)) {
+        assert!(
+            (1..=1920).contains(&geometry.width)
+                && geometry
+                    .offset
+                    .is_none_or(|(x, _)| (-100..=100).contains(&x)),
+            "{s:?} parsed to {geometry:?}"
+        );
+    }
+    if let Some(tol) = accepted(s, tolerance::<u8>(s)) {
+        assert!(
+            tol.range().contains(&tol.nominal),
+            "{s:?} parsed to {tol:?}"
+        );
+    }
+    if let Some(tol) = accepted(s, tolerance::<i32>(s)) {
+        assert!(
+            tol.range().contains(&tol.nominal),
+            "{s:?} parsed to {tol:?}"
+        );
+    }
+    accepted(s, key_value(s, maybe_hex::<u32>));
+    accepted(s, named_or(s, &[("cd", 0u32), ("ab", 1)], maybe_hex));
+    if let Some(val) = accepted(s, verbatim(s, si_number::<u32>)) {
+        assert_eq!(val.as_str(), s);
+    }
+    if let Some(cpus) = accepted(s, cpu_list(s, 63)) {
+        assert!(
+            cpus.windows(2).all(|w| w[0] < w[1]),
+            "{s:?} parsed to {cpus:?}"
+        );
+        assert!(
+            cpus.iter().all(|&cpu| cpu <= 63),
+            "{s:?} parsed to {cpus:?}"
+        );
+    }
+    accepted(s, cpu_mask::<u64>(s, 63));
+
+    let item = NumberParser::new(maybe_hex::<u8>).max(0x7F);
+    let list = ListParser::new(item.clone()).max_len(8).sort().dedup();
+    if let Some(values) = accepted(s, list.parse(s)) {
+        assert!(values.len() <= 8, "{s:?} parsed to {values:?}");
+        assert!(
+            values.windows(2).all(|w| w[0] < w[1]),
+            "{s:?} parsed to {values:?}"
+        );
+    }
+    let list = ListParser::new(item)
+        .delimiter(':')
+        .require_sorted()
+        .require_unique();
+    if let Some(values) = accepted(s, list.parse(s)) {
+        assert!(
+            values.windows(2).all(|w| w[0] < w[1]),
+            "{s:?} parsed to {values:?}"
+        );
+    }
+}
+
+fn check_identifier(s: &str) {
+    accepted(s, ipv4(s));
+    accepted(s, ipv4_network_order(s));
+    in_range(s, ipv4_prefix_len(s), 0, 32);
+    in_range(s, ipv6_prefix_len(s), 0, 128);
+    if let Some(mask) = accepted(s, ipv4_netmask(s)) {
+        assert_eq!(mask.leading_ones() + mask.trailing_zeros(), 32);
+    }
+    if let Some(mask) = accepted(s, ipv6_netmask(s)) {
+        assert_eq!(mask.leading_ones() + mask.trailing_zeros(), 128);
+    }
+    accepted(s, uuid(s));
+    if let Some((r, g, b, a)) = accepted(s, hex_color_rgba(s)) {
+        assert_eq!(hex_color(s), Ok(u32::from_be_bytes([r, g, b, a])));
+    }
+}
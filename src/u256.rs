@@ -0,0 +1,210 @@
+//! Parsers for 256-bit unsigned integers from [primitive-types].
+//!
+//! This module is only available with the `primitive-types` feature.
+//!
+//! Each function accepts the same values and returns the same errors as the
+//! parser of the same name in the crate root, for [`U256`] values such as
+//! the token amounts and storage words of blockchain tooling.
+//!
+//! Base-10 values are always parsed as base-10, even though the [`FromStr`]
+//! implementation of [`U256`] parses base-16.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use primitive_types::U256;
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[clap(long, value_parser=clap_num::u256::maybe_hex)]
+//!     slot: U256,
+//!     #[clap(long, value_parser=clap_num::u256::si_number)]
+//!     amount: U256,
+//! }
+//! # let args = Args::parse_from(&["", "--slot", "0xFF", "--amount", "1.5Y"]);
+//! # assert_eq!(args.slot, U256::from(0xFF));
+//! # assert_eq!(args.amount, U256::from(1_500_000_000_000_000_000_000_000u128));
+//! ```
+//!
+//! ## Error Messages
+//!
+//! Values that are too large for 256 bits will show an error message similar
+//! to this:
+//!
+//! ```text
+//! error: Invalid value for '--slot <slot>': number too large to fit in target type
+//! ```
+//!
+//! [primitive-types]: https://docs.rs/primitive-types
+
+use crate::{EMPTY_MSG, INVALID_DIGIT_MSG, OVERFLOW_MSG};
+use core::fmt;
+use core::str::FromStr;
+use num_traits::{sign, CheckedAdd, CheckedMul, CheckedSub, Num, One, Zero};
+use primitive_types::U256;
+
+// a U256 with base-10 parsing and the num-traits implementations required by
+// the generic parsers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Word(U256);
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<u128> for Word {
+    fn from(val: u128) -> Self {
+        Self(U256::from(val))
+    }
+}
+
+macro_rules! word_op {
+    ($trait:ident, $method:ident, $checked:ident, $checked_method:ident) => {
+        impl core::ops::$trait for Word {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self(core::ops::$trait::$method(self.0, rhs.0))
+            }
+        }
+
+        impl $checked for Word {
+            fn $checked_method(&self, rhs: &Self) -> Option<Self> {
+                self.0.$checked_method(rhs.0).map(Self)
+            }
+        }
+    };
+}
+
+word_op!(Add, add, CheckedAdd, checked_add);
+word_op!(Sub, sub, CheckedSub, checked_sub);
+word_op!(Mul, mul, CheckedMul, checked_mul);
+
+impl core::ops::Div for Word {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self(self.0 / rhs.0)
+    }
+}
+
+impl core::ops::Rem for Word {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl Zero for Word {
+    fn zero() -> Self {
+        Self(U256::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for Word {
+    fn one() -> Self {
+        Self(U256::one())
+    }
+}
+
+impl sign::Unsigned for Word {}
+
+impl Num for Word {
+    type FromStrRadixErr = &'static str;
+
+    // accumulates the digits with overflow checks, with the same errors as
+    // the standard library for the primitive types
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if s.is_empty() {
+            return Err(EMPTY_MSG);
+        }
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        if digits.is_empty() {
+            return Err(INVALID_DIGIT_MSG);
+        }
+        digits
+            .chars()
+            .try_fold(U256::zero(), |val, c| {
+                let digit = c.to_digit(radix).ok_or(INVALID_DIGIT_MSG)?;
+                val.checked_mul(U256::from(radix))
+                    .and_then(|val| val.checked_add(U256::from(digit)))
+                    .ok_or(OVERFLOW_MSG)
+            })
+            .map(Self)
+    }
+}
+
+impl FromStr for Word {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+/// Validates a base-10 [`U256`] value within a range, see
+/// [`number_range`](crate::number_range).
+pub fn number_range(s: &str, min: U256, max: U256) -> Result<U256, String> {
+    crate::number_range(s, Word(min), Word(max)).map(|val| val.0)
+}
+
+/// Validates a [`U256`] value that can be base-10 or base-16, see
+/// [`maybe_hex`](crate::maybe_hex).
+///
+/// ```
+/// use primitive_types::U256;
+///
+/// assert_eq!(clap_num::u256::maybe_hex("0xFF"), Ok(U256::from(255)));
+/// assert_eq!(clap_num::u256::maybe_hex("10"), Ok(U256::from(10)));
+/// ```
+pub fn maybe_hex(s: &str) -> Result<U256, String> {
+    crate::maybe_hex::<Word>(s).map(|val| val.0)
+}
+
+/// Validates a [`U256`] value that can be base-10 or base-16 within a range,
+/// see [`maybe_hex_range`](crate::maybe_hex_range).
+pub fn maybe_hex_range(s: &str, min: U256, max: U256) -> Result<U256, String> {
+    crate::maybe_hex_range(s, Word(min), Word(max)).map(|val| val.0)
+}
+
+/// Validates a [`U256`] value with a [metric prefix], such as `1.5Y`, see
+/// [`si_number`](crate::si_number).
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number(s: &str) -> Result<U256, String> {
+    crate::si_number::<Word>(s).map(|val| val.0)
+}
+
+/// Validates a [`U256`] value with a [metric prefix] within a range, see
+/// [`si_number_range`](crate::si_number_range).
+///
+/// [metric prefix]: https://en.wikipedia.org/wiki/Metric_prefix
+pub fn si_number_range(s: &str, min: U256, max: U256) -> Result<U256, String> {
+    crate::si_number_range(s, Word(min), Word(max)).map(|val| val.0)
+}
+
+/// Validates an amount of ether in wei as a [`U256`], see
+/// [`wei`](crate::wei).
+///
+/// ```
+/// use primitive_types::U256;
+///
+/// assert_eq!(clap_num::u256::wei("20gwei"), Ok(U256::from(20_000_000_000u64)));
+/// ```
+pub fn wei(s: &str) -> Result<U256, String> {
+    crate::wei::<Word>(s).map(|val| val.0)
+}
+
+/// Validates an amount of ether in wei as a [`U256`] within a range, see
+/// [`wei_range`](crate::wei_range).
+pub fn wei_range(s: &str, min: U256, max: U256) -> Result<U256, String> {
+    crate::wei_range(s, Word(min), Word(max)).map(|val| val.0)
+}
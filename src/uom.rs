@@ -0,0 +1,95 @@
+//! Parsers returning typed quantities from [uom].
+//!
+//! This module is only available with the `uom` feature.
+//!
+//! Each function parses a value followed by a unit with an optional SI
+//! prefix, such as `10kHz`, with a [`QuantityParser`], and returns a quantity
+//! of the matching dimension.
+//! This allows the type system to enforce dimensional correctness after the
+//! value leaves the command line.
+//!
+//! Values without a unit are in the base unit of the quantity.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::Parser;
+//! use uom::si::f64::{ElectricPotential, Frequency};
+//! use uom::si::{electric_potential::volt, frequency::hertz};
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[clap(long, value_parser=clap_num::uom::frequency)]
+//!     clock: Frequency,
+//!     #[clap(long, value_parser=clap_num::uom::electric_potential)]
+//!     vdd: ElectricPotential,
+//! }
+//! # let args = Args::parse_from(&["", "--clock", "16MHz", "--vdd", "3.3V"]);
+//! # assert_eq!(args.clock.get::<hertz>(), 16e6);
+//! # assert_eq!(args.vdd.get::<volt>(), 3.3);
+//! ```
+//!
+//! ## Error Messages
+//!
+//! Values with a unit of a different dimension will show an error message
+//! similar to this:
+//!
+//! ```text
+//! error: Invalid value for '--clock <clock>': unknown unit 'V', expected one of Hz, kHz, MHz, GHz
+//! ```
+//!
+//! [uom]: https://docs.rs/uom
+
+use crate::QuantityParser;
+use ::uom::si::electric_potential::volt;
+use ::uom::si::f64::{ElectricPotential, Frequency, Length};
+use ::uom::si::frequency::hertz;
+use ::uom::si::length::meter;
+
+// units of length, in meters
+const LENGTH_UNITS: [(&str, f64); 6] = [
+    ("km", 1e3),
+    ("cm", 1e-2),
+    ("mm", 1e-3),
+    ("µm", 1e-6),
+    ("um", 1e-6),
+    ("nm", 1e-9),
+];
+
+// units of frequency, in hertz
+const FREQUENCY_UNITS: [(&str, f64); 3] = [("kHz", 1e3), ("MHz", 1e6), ("GHz", 1e9)];
+
+// units of electric potential, in volts
+const ELECTRIC_POTENTIAL_UNITS: [(&str, f64); 4] =
+    [("kV", 1e3), ("mV", 1e-3), ("µV", 1e-6), ("uV", 1e-6)];
+
+// parses a value in the base unit or one of the units of a table
+fn parse_base(s: &str, base: &'static str, units: &[(&'static str, f64)]) -> Result<f64, String> {
+    units
+        .iter()
+        .fold(QuantityParser::new(base), |parser, &(unit, factor)| {
+            parser.unit(unit, factor)
+        })
+        .parse(s)
+}
+
+/// Validates a length, such as `5mm` or `1.5km`.
+///
+/// The accepted units are `m`, `km`, `cm`, `mm`, `µm` or `um`, and `nm`.
+pub fn length(s: &str) -> Result<Length, String> {
+    parse_base(s, "m", &LENGTH_UNITS).map(Length::new::<meter>)
+}
+
+/// Validates a frequency, such as `10kHz` or `2.4GHz`.
+///
+/// The accepted units are `Hz`, `kHz`, `MHz`, and `GHz`.
+pub fn frequency(s: &str) -> Result<Frequency, String> {
+    parse_base(s, "Hz", &FREQUENCY_UNITS).map(Frequency::new::<hertz>)
+}
+
+/// Validates an electric potential, such as `3.3V` or `500mV`.
+///
+/// The accepted units are `V`, `kV`, `mV`, and `µV` or `uV`.
+pub fn electric_potential(s: &str) -> Result<ElectricPotential, String> {
+    parse_base(s, "V", &ELECTRIC_POTENTIAL_UNITS).map(ElectricPotential::new::<volt>)
+}
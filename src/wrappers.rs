@@ -0,0 +1,397 @@
+use core::fmt;
+use core::str::FromStr;
+use num_traits::identities::Zero;
+use num_traits::{sign, CheckedAdd, CheckedMul, CheckedSub, Num};
+
+macro_rules! wrapper {
+    (
+        $(#[$meta:meta])*
+        $NAME:ident, $PARSER:path, [$($BOUNDS:tt)*]
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $NAME<T>(pub T);
+
+        impl<T> $NAME<T> {
+            /// Returns the wrapped value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> FromStr for $NAME<T>
+        where
+            $($BOUNDS)*
+        {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $PARSER(s).map(Self)
+            }
+        }
+
+        impl<T> TryFrom<&str> for $NAME<T>
+        where
+            $($BOUNDS)*
+        {
+            type Error = String;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        #[cfg(feature = "clap")]
+        impl<T> clap::builder::ValueParserFactory for $NAME<T>
+        where
+            $($BOUNDS)*
+            T: Clone + Send + Sync + 'static,
+        {
+            type Parser = fn(&str) -> Result<Self, String>;
+
+            fn value_parser() -> Self::Parser {
+                |s| $PARSER(s).map(Self)
+            }
+        }
+    };
+}
+
+wrapper!(
+    /// An unsigned integer value that can be base-10 or base-16.
+    ///
+    /// This parses with [`maybe_hex`](crate::maybe_hex).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap::Parser;
+    /// use clap_num::MaybeHex;
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     #[clap(short, long)]
+    ///     address: MaybeHex<u32>,
+    /// }
+    /// # let args = Args::parse_from(&["", "-a", "0x10"]);
+    /// # assert_eq!(args.address, MaybeHex(16));
+    ///
+    /// let address: MaybeHex<u32> = "0x10".parse().unwrap();
+    /// assert_eq!(address.0, 16);
+    /// ```
+    MaybeHex,
+    crate::maybe_hex,
+    [<T as Num>::FromStrRadixErr: std::fmt::Display, T: Num + sign::Unsigned,]
+);
+
+wrapper!(
+    /// An unsigned integer value that can be base-10 or base-2.
+    ///
+    /// This parses with [`maybe_bin`](crate::maybe_bin).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::MaybeBin;
+    ///
+    /// let mask = MaybeBin::<u8>::try_from("0b101").unwrap();
+    /// assert_eq!(mask.into_inner(), 5);
+    /// ```
+    MaybeBin,
+    crate::maybe_bin,
+    [<T as Num>::FromStrRadixErr: std::fmt::Display, T: Num + sign::Unsigned,]
+);
+
+wrapper!(
+    /// An unsigned integer value that can be base-10 or base-8.
+    ///
+    /// This parses with [`maybe_oct`](crate::maybe_oct).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::MaybeOct;
+    ///
+    /// let mode: MaybeOct<u16> = "0o755".parse().unwrap();
+    /// assert_eq!(mode.0, 0o755);
+    /// ```
+    MaybeOct,
+    crate::maybe_oct,
+    [<T as Num>::FromStrRadixErr: std::fmt::Display, T: Num + sign::Unsigned,]
+);
+
+wrapper!(
+    /// An unsigned integer value that is always base-16.
+    ///
+    /// This parses with [`hex_only`](crate::hex_only).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap_num::HexOnly;
+    ///
+    /// let id: HexOnly<u32> = "DEAD_BEEF".parse().unwrap();
+    /// assert_eq!(id.0, 0xDEAD_BEEF);
+    /// ```
+    HexOnly,
+    crate::hex_only,
+    [<T as Num>::FromStrRadixErr: std::fmt::Display, T: Num + sign::Unsigned,]
+);
+
+wrapper!(
+    /// A signed or unsigned integer value with a metric prefix.
+    ///
+    /// This parses with [`si_number`](crate::si_number).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clap::Parser;
+    /// use clap_num::SiNumber;
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     #[clap(short, long)]
+    ///     resistance: SiNumber<u128>,
+    /// }
+    /// # let args = Args::parse_from(&["", "-r", "1k2"]);
+    /// # assert_eq!(args.resistance, SiNumber(1200));
+    ///
+    /// let resistance: SiNumber<u128> = "1.2k".parse().unwrap();
+    /// assert_eq!(resistance.0, 1200);
+    /// ```
+    SiNumber,
+    crate::si_number,
+    [
+        <T as TryFrom<u128>>::Error: std::fmt::Display,
+        <T as FromStr>::Err: std::fmt::Display,
+        T: CheckedAdd,
+        T: CheckedMul,
+        T: CheckedSub,
+        T: FromStr,
+        T: PartialOrd,
+        T: TryFrom<u128>,
+        T: Zero,
+    ]
+);
+
+/// A parsed value along with the string it was parsed from.
+///
+/// This allows applications to show a value exactly as the user wrote it,
+/// such as `3k3`, instead of the normalized number.
+///
+/// This parses with the [`FromStr`] implementation of `T`, such as one of the
+/// other wrapper types, or with any parser using [`verbatim`](crate::verbatim).
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::{SiNumber, Verbatim};
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(short, long)]
+///     resistance: Verbatim<SiNumber<u32>>,
+/// }
+/// # let args = Args::parse_from(&["", "-r", "3k3"]);
+/// # assert_eq!(args.resistance.value, SiNumber(3_300));
+///
+/// let resistance: Verbatim<SiNumber<u32>> = "3k3".parse().unwrap();
+/// assert_eq!(resistance.value, SiNumber(3_300));
+/// assert_eq!(resistance.to_string(), "3k3");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Verbatim<T> {
+    /// Parsed value.
+    pub value: T,
+    /// String the value was parsed from.
+    pub input: String,
+}
+
+impl<T> Verbatim<T> {
+    /// Returns the parsed value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the string the value was parsed from.
+    pub fn as_str(&self) -> &str {
+        &self.input
+    }
+}
+
+impl<T> fmt::Display for Verbatim<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.input)
+    }
+}
+
+impl<T: FromStr> FromStr for Verbatim<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            value: s.parse()?,
+            input: String::from(s),
+        })
+    }
+}
+
+impl<T: FromStr> TryFrom<&str> for Verbatim<T> {
+    type Error = T::Err;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "clap")]
+impl<T> clap::builder::ValueParserFactory for Verbatim<T>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Parser = fn(&str) -> Result<Self, T::Err>;
+
+    fn value_parser() -> Self::Parser {
+        |s| s.parse()
+    }
+}
+
+/// A signed or unsigned base-10 integer value within a range that is part of
+/// the type, such as `Ranged<u8, 1, 99>`.
+///
+/// This parses with [`number_range`](crate::number_range), with `MIN` and
+/// `MAX` as the inclusive bounds, so no parser function is required and the
+/// bounds are visible in the type of the field.
+///
+/// The value is always within the bounds, use [`new`](Self::new) to create
+/// one from a value.
+///
+/// # Example
+///
+/// ```
+/// use clap::Parser;
+/// use clap_num::Ranged;
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[clap(long, value_parser)]
+///     cents: Ranged<u8, 0, 99>,
+/// }
+/// # let args = Args::parse_from(&["", "--cents", "42"]);
+/// # assert_eq!(args.cents.get(), 42);
+///
+/// let cents: Ranged<u8, 0, 99> = "42".parse().unwrap();
+/// assert_eq!(cents.get(), 42);
+/// assert_eq!(
+///     "100".parse::<Ranged<u8, 0, 99>>(),
+///     Err(String::from("exceeds maximum of 99"))
+/// );
+/// ```
+///
+/// Bounds where `MIN` exceeds `MAX` fail to compile when the type is parsed:
+///
+/// ```compile_fail
+/// let cents: clap_num::Ranged<u8, 99, 0> = "42".parse().unwrap();
+/// ```
+///
+/// ## Error Messages
+///
+/// Values exceeding the limits will show an error message similar to this:
+///
+/// ```text
+/// error: Invalid value for '--cents <cents>': exceeds maximum of 99
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ranged<T, const MIN: i128, const MAX: i128>(T);
+
+impl<T, const MIN: i128, const MAX: i128> Ranged<T, MIN, MAX>
+where
+    T: Copy + TryInto<i128>,
+{
+    // evaluated when the type is parsed, failing to compile for invalid bounds
+    const BOUNDS: () = assert!(MIN <= MAX, "minimum exceeds maximum");
+
+    /// Returns the value if it is within the bounds, or `None` otherwise.
+    ///
+    /// ```
+    /// use clap_num::Ranged;
+    ///
+    /// assert!(Ranged::<u8, 0, 99>::new(99).is_some());
+    /// assert!(Ranged::<u8, 0, 99>::new(100).is_none());
+    /// ```
+    pub fn new(val: T) -> Option<Self> {
+        match val.try_into() {
+            Ok(wide) if (MIN..=MAX).contains(&wide) => Some(Self(val)),
+            _ => None,
+        }
+    }
+
+    /// Returns the value.
+    pub fn get(self) -> T {
+        self.0
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> fmt::Display for Ranged<T, MIN, MAX>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> FromStr for Ranged<T, MIN, MAX>
+where
+    <T as FromStr>::Err: std::fmt::Display,
+    T: FromStr,
+    T: Copy + TryInto<i128>,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let () = Self::BOUNDS;
+
+        let val: T = crate::parse_decimal(s)?;
+        // only unsigned values above the maximum of i128 do not convert
+        let wide: i128 = val
+            .try_into()
+            .map_err(|_| String::from(crate::RangeError::AboveMaximum(MAX)))?;
+        crate::check_range(wide, MIN, MAX)?;
+        Ok(Self(val))
+    }
+}
+
+impl<T, const MIN: i128, const MAX: i128> TryFrom<&str> for Ranged<T, MIN, MAX>
+where
+    <T as FromStr>::Err: std::fmt::Display,
+    T: FromStr,
+    T: Copy + TryInto<i128>,
+{
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "clap")]
+impl<T, const MIN: i128, const MAX: i128> clap::builder::ValueParserFactory for Ranged<T, MIN, MAX>
+where
+    <T as FromStr>::Err: std::fmt::Display,
+    T: FromStr,
+    T: Copy + TryInto<i128> + Send + Sync + 'static,
+{
+    type Parser = fn(&str) -> Result<Self, String>;
+
+    fn value_parser() -> Self::Parser {
+        |s| s.parse()
+    }
+}
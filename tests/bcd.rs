@@ -0,0 +1,52 @@
+use clap_num::bcd;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(bcd($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ident, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<$TYPE, String> = bcd($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(zero, "0", 0u8);
+    pos!(seconds, "0x59", 59u8);
+    pos!(upper_prefix, "0X42", 42u8);
+    pos!(no_prefix, "1234", 1234u16);
+    pos!(leading_zero, "0x0001", 1u16);
+    pos!(max_u8, "0x99", 99u8);
+    pos!(wide, "0x12345678", 12_345_678u32);
+    pos!(underscores, "0x1234_5678", 12_345_678u32);
+
+    neg!(hex_nibble, "0x5A", u8, "invalid BCD nibble 'A'");
+    neg!(lower_hex_nibble, "0x5f", u8, "invalid BCD nibble 'f'");
+    neg!(
+        missing_digits,
+        "0x",
+        u8,
+        "cannot parse integer from empty string"
+    );
+    neg!(non_hex, "0x5G", u8, "invalid digit found in string");
+    neg!(
+        overflow,
+        "0x256",
+        u8,
+        "number too large to fit in target type"
+    );
+}
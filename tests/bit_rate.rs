@@ -0,0 +1,132 @@
+use clap::Parser;
+use clap_num::{bit_rate, bit_rate_range};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(bit_rate($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u64, String> = bit_rate($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plain, "9600", 9_600u64);
+    pos!(bps, "9600bps", 9_600u64);
+    pos!(kbps, "56kbps", 56_000u64);
+    pos!(mbps, "100Mbps", 100_000_000u64);
+    pos!(gbps, "1.5Gbps", 1_500_000_000u64);
+    pos!(tbps, "1Tbps", 1_000_000_000_000u64);
+    pos!(bit_per_second, "9600bit/s", 9_600u64);
+    pos!(kbit_per_second, "1.5kbit/s", 1_500u64);
+    pos!(gbit_per_second, "10Gbit/s", 10_000_000_000u64);
+
+    neg!(
+        bytes,
+        "100MBps",
+        "unknown suffix 'MBps', expected one of bps, kbps, Mbps, Gbps, Tbps, bit/s, kbit/s, Mbit/s, Gbit/s, Tbit/s"
+    );
+    neg!(fraction_bit, "1.5bps", "not an integer");
+    neg!(
+        overflow,
+        "20000000Tbps",
+        "number too large to fit in target type"
+    );
+    neg!(negative, "-1kbps", "value cannot be negative");
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u64, String> = bit_rate_range($VAL, $MIN, $MAX);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    #[test]
+    fn in_range() {
+        assert_eq!(
+            bit_rate_range("10Gbps", 1_000, 10_000_000_000),
+            Ok(10_000_000_000u64)
+        );
+        assert_eq!(
+            bit_rate_range("1kbit/s", 1_000, 10_000_000_000),
+            Ok(1_000u64)
+        );
+    }
+
+    neg!(
+        max,
+        "40Gbps",
+        1_000,
+        10_000_000_000,
+        "exceeds maximum of 10Gbps"
+    );
+    neg!(
+        min,
+        "999bps",
+        1_000,
+        10_000_000_000,
+        "less than minimum of 1kbps"
+    );
+    neg!(
+        alias_bound,
+        "2Mbit/s",
+        0,
+        1_500_000,
+        "exceeds maximum of 1500kbps"
+    );
+    neg!(plain_bound, "1kbps", 0, 999, "exceeds maximum of 999");
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn bandwidth(s: &str) -> Result<u64, String> {
+        bit_rate_range(s, 1_000, 10_000_000_000)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=bandwidth)]
+        bandwidth: u64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--bandwidth", "100Mbps"]);
+        assert_eq!(args.bandwidth, 100_000_000);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--bandwidth", "40Gbit/s"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 10Gbps")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "bounded-integer")]
+
+use bounded_integer::{BoundedI8, BoundedU128, BoundedU16, BoundedU32, BoundedU8};
+use clap::Parser;
+use clap_num::bounded_integer::{maybe_hex, number, si_number};
+
+type Percent = BoundedU8<1, 99>;
+type Offset = BoundedI8<-10, 10>;
+type Address = BoundedU16<0x10, 0x3FF>;
+type Frequency = BoundedU32<1_000, 10_000_000>;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL).map(|val| val.get()), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(number_value, number::<Percent>, "42", 42);
+    pos!(number_min, number::<Percent>, "1", 1);
+    pos!(number_max, number::<Percent>, "99", 99);
+    pos!(number_signed, number::<Offset>, "-10", -10);
+    pos!(
+        number_full,
+        number::<BoundedU128<0, { u128::MAX }>>,
+        "340282366920938463463374607431768211455",
+        u128::MAX
+    );
+    pos!(hex, maybe_hex::<Address>, "0x3FF", 0x3FF);
+    pos!(hex_decimal, maybe_hex::<Address>, "16", 0x10);
+    pos!(si, si_number::<Frequency>, "1.5M", 1_500_000);
+    pos!(si_plain, si_number::<Frequency>, "1000", 1_000);
+
+    neg!(
+        number_above,
+        number::<Percent>,
+        "100",
+        "exceeds maximum of 99"
+    );
+    neg!(
+        number_below,
+        number::<Percent>,
+        "0",
+        "less than minimum of 1"
+    );
+    neg!(
+        number_signed_below,
+        number::<Offset>,
+        "-11",
+        "less than minimum of -10"
+    );
+    neg!(
+        number_overflow,
+        number::<Percent>,
+        "256",
+        "number too large to fit in target type"
+    );
+    neg!(
+        number_invalid,
+        number::<Percent>,
+        "4a",
+        "invalid digit found in string"
+    );
+    neg!(
+        hex_above,
+        maybe_hex::<Address>,
+        "0x400",
+        "exceeds maximum of 0x3FF"
+    );
+    neg!(
+        hex_below,
+        maybe_hex::<Address>,
+        "0xF",
+        "less than minimum of 0x10"
+    );
+    neg!(
+        si_above,
+        si_number::<Frequency>,
+        "11M",
+        "exceeds maximum of 10M"
+    );
+    neg!(
+        si_below,
+        si_number::<Frequency>,
+        "999",
+        "less than minimum of 1000"
+    );
+}
+
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=number::<Percent>)]
+        percent: Percent,
+        #[clap(long, value_parser=maybe_hex::<Address>)]
+        address: Address,
+    }
+
+    #[test]
+    fn parse() {
+        let args = Args::parse_from(["", "--percent", "42", "--address", "0x20"]);
+        assert_eq!(args.percent.get(), 42);
+        assert_eq!(args.address.get(), 0x20);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let err = Args::try_parse_from(["", "--percent", "100", "--address", "0x20"])
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("exceeds maximum of 99"));
+    }
+}
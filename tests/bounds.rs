@@ -0,0 +1,102 @@
+use clap::Parser;
+use clap_num::{maybe_hex, si_number, Bounds, NumberParser};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let bounds = Bounds::new(1u8, 10).unwrap();
+        assert_eq!(*bounds.min(), 1);
+        assert_eq!(*bounds.max(), 10);
+        assert_eq!(bounds.into_inner(), (1, 10));
+    }
+
+    #[test]
+    fn equal() {
+        assert!(Bounds::new(5i8, 5).is_ok());
+    }
+
+    #[test]
+    fn inverted() {
+        assert_eq!(
+            Bounds::new(10u8, 1),
+            Err(String::from("minimum of 10 exceeds maximum of 1"))
+        );
+    }
+
+    #[test]
+    fn nan() {
+        assert_eq!(
+            Bounds::new(f64::NAN, 1.0),
+            Err(String::from("minimum of NaN exceeds maximum of 1"))
+        );
+    }
+
+    #[test]
+    fn check() {
+        let bounds = Bounds::new(-5i32, 5).unwrap();
+        assert_eq!(bounds.check(5), Ok(5));
+        assert_eq!(bounds.check(6), Err(String::from("exceeds maximum of 5")));
+        assert_eq!(
+            bounds.check(-6),
+            Err(String::from("less than minimum of -5"))
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let bounds = Bounds::new(1_000u32, 10_000).unwrap();
+        assert_eq!(bounds.parse("4k7", si_number), Ok(4_700));
+        assert_eq!(
+            bounds.parse("20k", si_number),
+            Err(String::from("exceeds maximum of 10000"))
+        );
+        assert_eq!(
+            bounds.parse("1x", si_number),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn number_parser() {
+        let parser = NumberParser::new(maybe_hex::<u16>).bounds(Bounds::new(0x10, 0xFF).unwrap());
+        assert_eq!(parser.parse("0x10"), Ok(0x10));
+        assert_eq!(
+            parser.parse("0x100"),
+            Err(String::from("exceeds maximum of 255"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn channel(s: &str) -> Result<u8, String> {
+        Bounds::new(1, 14)?.parse(s, maybe_hex)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=channel)]
+        channel: u8,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--channel", "0xE"]);
+        assert_eq!(args.channel, 14);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--channel", "15"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 14")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use clap_num::{ipv4_netmask, ipv4_prefix_len, ipv6_netmask, ipv6_prefix_len};
+
+#[cfg(test)]
+mod prefix_len {
+    use super::*;
+
+    macro_rules! pos {
+        ($NAME:ident, $FN:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($FN($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $FN:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($FN($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(v4_slash, ipv4_prefix_len, "/24", 24);
+    pos!(v4_bare, ipv4_prefix_len, "24", 24);
+    pos!(v4_zero, ipv4_prefix_len, "/0", 0);
+    pos!(v4_max, ipv4_prefix_len, "/32", 32);
+    pos!(v6_slash, ipv6_prefix_len, "/64", 64);
+    pos!(v6_max, ipv6_prefix_len, "128", 128);
+
+    neg!(
+        v4_max_exceeded,
+        ipv4_prefix_len,
+        "/33",
+        "exceeds maximum of 32"
+    );
+    neg!(
+        v6_max_exceeded,
+        ipv6_prefix_len,
+        "/129",
+        "exceeds maximum of 128"
+    );
+    neg!(
+        double_slash,
+        ipv4_prefix_len,
+        "//24",
+        "invalid digit found in string"
+    );
+    neg!(
+        empty,
+        ipv4_prefix_len,
+        "/",
+        "cannot parse integer from empty string"
+    );
+}
+
+#[cfg(test)]
+mod netmask {
+    use super::*;
+
+    macro_rules! pos {
+        ($NAME:ident, $FN:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($FN($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    pos!(v4_zero, ipv4_netmask, "/0", 0);
+    pos!(v4_eight, ipv4_netmask, "/8", 0xFF00_0000);
+    pos!(v4_class_c, ipv4_netmask, "/24", 0xFFFF_FF00);
+    pos!(v4_host, ipv4_netmask, "/32", u32::MAX);
+    pos!(v6_zero, ipv6_netmask, "/0", 0);
+    pos!(v6_subnet, ipv6_netmask, "/64", u128::MAX << 64);
+    pos!(v6_host, ipv6_netmask, "/128", u128::MAX);
+
+    #[test]
+    fn v4_out_of_range() {
+        assert_eq!(
+            ipv4_netmask("/33"),
+            Err(String::from("exceeds maximum of 32"))
+        );
+    }
+}
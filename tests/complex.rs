@@ -0,0 +1,172 @@
+#![cfg(feature = "num-complex")]
+
+use clap::Parser;
+use clap_num::{complex, complex_magnitude_range};
+use num_complex::Complex;
+
+fn pole(s: &str) -> Result<Complex<f64>, String> {
+    complex_magnitude_range(s, 0.0, 1.0)
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RE:expr, $IM:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(complex($VAL), Ok(Complex::new($RE, $IM)));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(complex($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(rectangular, "3+4i", 3.0, 4.0);
+    pos!(negative_imaginary, "3-4i", 3.0, -4.0);
+    pos!(negative_both, "-3-4i", -3.0, -4.0);
+    pos!(real, "1.0", 1.0, 0.0);
+    pos!(negative_real, "-2", -2.0, 0.0);
+    pos!(imaginary, "-2.5i", 0.0, -2.5);
+    pos!(positive_imaginary, "+2.5i", 0.0, 2.5);
+    pos!(unit, "i", 0.0, 1.0);
+    pos!(negative_unit, "-i", 0.0, -1.0);
+    pos!(implied_unit, "3+i", 3.0, 1.0);
+    pos!(implied_negative_unit, "3-i", 3.0, -1.0);
+    pos!(engineering, "3+4j", 3.0, 4.0);
+    pos!(exponent, "1e-3+2e-3i", 1e-3, 2e-3);
+    pos!(exponent_imaginary, "1e-3i", 0.0, 1e-3);
+    pos!(exponent_upper, "1E+2-1E-2i", 100.0, -0.01);
+    pos!(underscores, "1_000+2_000i", 1_000.0, 2_000.0);
+
+    neg!(empty, "", "cannot parse float from empty string");
+    neg!(invalid_real, "x+4i", "invalid float literal");
+    neg!(invalid_imaginary, "3+xi", "invalid float literal");
+    neg!(double_sign, "3+-4i", "invalid float literal");
+    neg!(whitespace, "3 + 4i", "invalid float literal");
+    neg!(suffix_only_real, "4k", "invalid float literal");
+    neg!(nan, "NaN", "value is not a number");
+}
+
+#[cfg(test)]
+mod polar {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RE:expr, $IM:expr) => {
+            #[test]
+            fn $NAME() {
+                let val = complex($VAL).unwrap();
+                let expected = Complex::new($RE, $IM);
+                assert!((val - expected).norm() < 1e-12, "{val} != {expected}");
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(complex($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(angle, "1∠45", 0.5f64.sqrt(), 0.5f64.sqrt());
+    pos!(ascii, "1<45", 0.5f64.sqrt(), 0.5f64.sqrt());
+    pos!(degrees, "2<90deg", 0.0, 2.0);
+    pos!(degree_sign, "2∠90°", 0.0, 2.0);
+    pos!(radians, "2<3.141592653589793rad", -2.0, 0.0);
+    pos!(negative_angle, "230∠-120", -115.0, -199.18584287042088);
+    pos!(zero_angle, "5<0", 5.0, 0.0);
+    pos!(zero_magnitude, "0∠30", 0.0, 0.0);
+    pos!(exponent, "1e3<180", -1e3, 0.0);
+
+    neg!(
+        empty_magnitude,
+        "<45",
+        "cannot parse float from empty string"
+    );
+    neg!(empty_angle, "1<", "cannot parse float from empty string");
+    neg!(unit_only, "1<deg", "cannot parse float from empty string");
+    neg!(negative_magnitude, "-1<45", "magnitude cannot be negative");
+    neg!(invalid_unit, "1<45grad", "invalid float literal");
+    neg!(double_unit, "1<45degdeg", "invalid float literal");
+    neg!(imaginary_angle, "1<45i", "invalid float literal");
+    neg!(double_separator, "1<45<45", "invalid float literal");
+    neg!(infinite_angle, "1<inf", "value is not a number");
+}
+
+#[cfg(test)]
+mod magnitude {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(pole("0.6-0.8i"), Ok(Complex::new(0.6, -0.8)));
+        assert_eq!(pole("0"), Ok(Complex::new(0.0, 0.0)));
+        assert_eq!(pole("-i"), Ok(Complex::new(0.0, -1.0)));
+        assert!(pole("1∠-60").is_ok());
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(
+            pole("1+i"),
+            Err(String::from("magnitude exceeds maximum of 1"))
+        );
+        assert_eq!(
+            pole("1.5<30deg"),
+            Err(String::from("magnitude exceeds maximum of 1"))
+        );
+        assert_eq!(
+            complex_magnitude_range("0.1i", 0.5, 1.0),
+            Err(String::from("magnitude less than minimum of 0.5"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=pole, allow_hyphen_values=true)]
+        pole: Complex<f64>,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--pole", "-0.5+0.5i"]);
+        assert_eq!(args.pole, Complex::new(-0.5, 0.5));
+    }
+
+    #[test]
+    fn pos_polar() {
+        let args = Args::parse_from(["", "--pole", "0.5<90"]);
+        assert!((args.pole - Complex::new(0.0, 0.5)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--pole", "3+4i"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("magnitude exceeds maximum of 1")),
+            _ => unreachable!(),
+        }
+    }
+}
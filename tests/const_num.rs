@@ -0,0 +1,161 @@
+use clap::Parser;
+use clap_num::{const_num, const_si, maybe_hex, si_number};
+
+#[cfg(test)]
+mod num {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $TYPE:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                const VALUE: $TYPE = const_num!($TYPE, $VAL);
+                assert_eq!(VALUE, $RESULT);
+            }
+        };
+    }
+
+    pos!(decimal, u8, "255", 255);
+    pos!(hex, u32, "0xDEAD_BEEF", 0xDEAD_BEEF);
+    pos!(hex_upper, u32, "0XdeadBEEF", 0xDEAD_BEEF);
+    pos!(binary, u8, "0b1010_1010", 0b1010_1010);
+    pos!(octal, u16, "0o755", 0o755);
+    pos!(negative, i8, "-128", i8::MIN);
+    pos!(negative_hex, i16, "-0x10", -16);
+    pos!(negative_zero, u8, "-0", 0);
+    pos!(plus, i32, "+42", 42);
+    pos!(
+        u128_max,
+        u128,
+        "340282366920938463463374607431768211455",
+        u128::MAX
+    );
+    pos!(
+        i128_min,
+        i128,
+        "-0x8000_0000_0000_0000_0000_0000_0000_0000",
+        i128::MIN
+    );
+
+    #[test]
+    fn matches_runtime() {
+        assert_eq!(const_num!(u64, "0x1F"), maybe_hex::<u64>("0x1F").unwrap());
+    }
+}
+
+#[cfg(test)]
+mod si {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $TYPE:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                const VALUE: $TYPE = const_si!($TYPE, $VAL);
+                assert_eq!(VALUE, $RESULT);
+                assert_eq!(si_number::<$TYPE>($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    pos!(plain, u32, "1000", 1_000);
+    pos!(kilo, u32, "4k", 4_000);
+    pos!(kilo_upper, u32, "4K", 4_000);
+    pos!(symbol_point, u32, "4k7", 4_700);
+    pos!(decimal_suffix, u64, "1.5M", 1_500_000);
+    pos!(trailing_zeros, u32, "1.2340k", 1_234);
+    pos!(underscores, u64, "1_000k", 1_000_000);
+    pos!(negative, i32, "-1k5", -1_500);
+    pos!(giga, u64, "2G", 2_000_000_000);
+    pos!(yotta, u128, "1Y", 1_000_000_000_000_000_000_000_000);
+    pos!(zero, u8, "0k", 0);
+    pos!(exponent, u64, "2e3", 2_000);
+    pos!(exponent_fraction, u32, "1.5e3", 1_500);
+    pos!(exponent_suffix, u64, "1.5e3k", 1_500_000);
+    pos!(exponent_negative, u32, "25e-1k", 2_500);
+    pos!(exponent_plus, u32, "1e+3", 1_000);
+    pos!(exponent_zero, u8, "0e50", 0);
+    pos!(negative_exponent, i32, "-2e3", -2_000);
+    pos!(
+        i128_min,
+        i128,
+        "-170141183460469231731687303715884105728",
+        i128::MIN
+    );
+
+    // the constant parser at runtime, with the panic message as the error
+    fn const_si(s: &'static str) -> Result<i128, String> {
+        std::panic::catch_unwind(|| clap_num::__const_si(s))
+            .map(|(negative, magnitude)| match negative {
+                true => (magnitude as i128).wrapping_neg(),
+                false => magnitude as i128,
+            })
+            .map_err(|e| String::from(*e.downcast::<&str>().unwrap()))
+    }
+
+    #[test]
+    fn matches_runtime() {
+        let values = [
+            "0",
+            "1_000",
+            "4k7",
+            "1.5M",
+            "2e3",
+            "1.5e3k",
+            "1e-3k",
+            "-2e3",
+            "1.5",
+            "1.2345k",
+            "1e-1",
+            "1k5e3",
+            "1ex",
+            "1e",
+            "e3",
+            "k",
+            "-k",
+            "",
+            "-",
+            "1.",
+            ".5k",
+            "1x",
+            "1e40",
+            "-1e40",
+            "1e99999999999",
+        ];
+        std::panic::set_hook(Box::new(|_| {}));
+        for s in values {
+            assert_eq!(const_si(s), si_number::<i128>(s), "{s}");
+        }
+        let _ = std::panic::take_hook();
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=si_number::<u32>, default_value_t=const_si!(u32, "44k1"))]
+        rate: u32,
+        #[clap(long, value_parser=maybe_hex::<u16>, default_value_t=const_num!(u16, "0x1F"))]
+        addr: u16,
+    }
+
+    #[test]
+    fn defaults() {
+        let args = Args::parse_from([""]);
+        assert_eq!(args.rate, 44_100);
+        assert_eq!(args.addr, 0x1F);
+    }
+
+    #[test]
+    fn values() {
+        let args = Args::parse_from(["", "--rate", "48k", "--addr", "0x20"]);
+        assert_eq!(args.rate, 48_000);
+        assert_eq!(args.addr, 0x20);
+    }
+}
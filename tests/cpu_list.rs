@@ -0,0 +1,150 @@
+use clap::Parser;
+use clap_num::{cpu_list, cpu_mask};
+
+#[cfg(test)]
+mod list {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(cpu_list($VAL, $MAX), Ok(Vec::from($RESULT)));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(cpu_list($VAL, $MAX), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(single, "3", 7, [3]);
+    pos!(range, "0-3", 7, [0, 1, 2, 3]);
+    pos!(mixed, "0-3,8,10-11", 15, [0, 1, 2, 3, 8, 10, 11]);
+    pos!(unordered, "8,0-1", 15, [0, 1, 8]);
+    pos!(overlapping, "0-3,2-4,3", 7, [0, 1, 2, 3, 4]);
+    pos!(single_range, "5-5", 7, [5]);
+    pos!(whitespace, " 0 - 1 , 4 ", 7, [0, 1, 4]);
+    pos!(max_limit, "7", 7, [7]);
+    pos!(adjacent, "4-5,0-3", 7, [0, 1, 2, 3, 4, 5]);
+    pos!(contained, "0-7,2-3", 7, [0, 1, 2, 3, 4, 5, 6, 7]);
+    pos!(usize_max, "18446744073709551615", usize::MAX, [usize::MAX]);
+
+    neg!(empty, "", 7, "empty CPU list");
+    neg!(max, "8", 7, "invalid CPU '8': exceeds maximum of 7");
+    neg!(range_max, "4-8", 7, "invalid CPU '8': exceeds maximum of 7");
+    neg!(
+        reversed,
+        "3-0",
+        7,
+        "invalid CPU range '3-0', 3 is greater than 0"
+    );
+    neg!(
+        empty_element,
+        "0,,1",
+        7,
+        "invalid CPU '': cannot parse integer from empty string"
+    );
+    neg!(
+        open_range,
+        "0-",
+        7,
+        "invalid CPU '': cannot parse integer from empty string"
+    );
+    neg!(
+        too_many,
+        "0-18446744073709551615",
+        usize::MAX,
+        "CPU list has more than 65536 CPUs"
+    );
+    neg!(
+        too_many_ranges,
+        "0-65535,100000",
+        usize::MAX,
+        "CPU list has more than 65536 CPUs"
+    );
+    neg!(
+        hex,
+        "0x1",
+        7,
+        "invalid CPU '0x1': hexadecimal prefix '0x' is not accepted, expected decimal"
+    );
+}
+
+#[cfg(test)]
+mod mask {
+    use super::*;
+
+    #[test]
+    fn mixed() {
+        assert_eq!(cpu_mask::<u64>("0-3,8,10-11", 63), Ok(0b1101_0000_1111));
+    }
+
+    #[test]
+    fn highest_bit() {
+        assert_eq!(cpu_mask::<u8>("7", 255), Ok(0x80));
+        assert_eq!(cpu_mask::<u128>("127", 255), Ok(1 << 127));
+    }
+
+    #[test]
+    fn too_wide() {
+        assert_eq!(
+            cpu_mask::<u32>("0,32", 255),
+            Err(String::from("CPU 32 does not fit in a 32-bit mask"))
+        );
+    }
+
+    #[test]
+    fn too_wide_range() {
+        assert_eq!(
+            cpu_mask::<u8>("0-3,6-18446744073709551615", usize::MAX),
+            Err(String::from("CPU 8 does not fit in a 8-bit mask"))
+        );
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(
+            cpu_mask::<u64>("0-15", 7),
+            Err(String::from("invalid CPU '15': exceeds maximum of 7"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn cpus(s: &str) -> Result<Vec<usize>, String> {
+        cpu_list(s, 15)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=cpus)]
+        cpus: ::std::vec::Vec<usize>,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--cpus", "0-1,4"]);
+        assert_eq!(args.cpus, [0, 1, 4]);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--cpus", "0-16"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 15")),
+            _ => unreachable!(),
+        }
+    }
+}
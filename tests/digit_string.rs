@@ -0,0 +1,101 @@
+use clap::Parser;
+use clap_num::{digit_string, digit_string_range, Verbatim};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Verbatim<u32> = digit_string($VAL).unwrap();
+                assert_eq!(val.value, $RESULT);
+                assert_eq!(val.input, $VAL);
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<Verbatim<u32>, String> = digit_string($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(simple, "123", 123);
+    pos!(leading_zeros, "000123", 123);
+    pos!(zero, "0", 0);
+    pos!(zeros, "0000", 0);
+
+    neg!(empty, "", "cannot parse integer from empty string");
+    neg!(letter, "12a", "invalid digit found in string");
+    neg!(underscores, "1_000", "invalid digit found in string");
+    neg!(space, "02 134", "invalid digit found in string");
+    neg!(negative, "-1", "value cannot be negative");
+    neg!(
+        overflow,
+        "4294967296",
+        "number too large to fit in target type"
+    );
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<Verbatim<u32>, String> = digit_string_range($VAL, 501, 99_950);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    #[test]
+    fn in_range() {
+        let val = digit_string_range("02134", 501u32, 99_950).unwrap();
+        assert_eq!(val.input, "02134");
+        assert_eq!(val.value, 2_134);
+        assert_eq!(val.to_string(), "02134");
+    }
+
+    neg!(min, "00400", "less than minimum of 501");
+    neg!(max, "99999", "exceeds maximum of 99950");
+    neg!(invalid, "0213x", "invalid digit found in string");
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=digit_string::<u64>)]
+        account: Verbatim<u64>,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--account", "007"]);
+        assert_eq!(args.account.input, "007");
+        assert_eq!(args.account.value, 7);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--account", "00-7"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("invalid digit found in string")),
+            _ => unreachable!(),
+        }
+    }
+}
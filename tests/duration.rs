@@ -0,0 +1,164 @@
+use clap::Parser;
+use clap_num::{duration, duration_range};
+use std::time::Duration;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(duration($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(duration($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(zero, "0", Duration::ZERO);
+    pos!(zero_unit, "0s", Duration::ZERO);
+    pos!(nanos, "15ns", Duration::from_nanos(15));
+    pos!(micros, "15us", Duration::from_micros(15));
+    pos!(micros_symbol, "15µs", Duration::from_micros(15));
+    pos!(millis, "100ms", Duration::from_millis(100));
+    pos!(secs, "2s", Duration::from_secs(2));
+    pos!(mins, "10m", Duration::from_secs(600));
+    pos!(hours, "2h", Duration::from_secs(7_200));
+    pos!(days, "1d", Duration::from_secs(86_400));
+    pos!(decimal, "1.5s", Duration::from_millis(1_500));
+    pos!(decimal_mins, "0.5m", Duration::from_secs(30));
+    pos!(compound, "1h30m", Duration::from_secs(5_400));
+    pos!(compound_millis, "1s500ms", Duration::from_millis(1_500));
+    pos!(underscores, "1_000ms", Duration::from_secs(1));
+    pos!(
+        max,
+        "18446744073709551615s999999999ns",
+        Duration::new(u64::MAX, 999_999_999)
+    );
+
+    neg!(empty, "", "cannot parse integer from empty string");
+    neg!(
+        no_unit,
+        "10",
+        "missing unit, expected one of ns, us, ms, s, m, h, d"
+    );
+    neg!(
+        trailing_value,
+        "1m30",
+        "missing unit, expected one of ns, us, ms, s, m, h, d"
+    );
+    neg!(
+        unknown_unit,
+        "2w",
+        "unknown unit 'w', expected one of ns, us, ms, s, m, h, d"
+    );
+    neg!(no_value, "s", "no value found before unit 's'");
+    neg!(negative, "-1s", "value cannot be negative");
+    neg!(
+        sub_nanosecond,
+        "1.5ns",
+        "duration must be a whole number of nanoseconds"
+    );
+    neg!(invalid_digit, "1.5.0s", "invalid digit found in string");
+    neg!(
+        overflow,
+        "18446744073709551616s",
+        "number too large to fit in target type"
+    );
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    const MIN: Duration = Duration::from_millis(100);
+    const MAX: Duration = Duration::from_secs(600);
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(duration_range($VAL, $MIN, $MAX), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    #[test]
+    fn in_range() {
+        assert_eq!(duration_range("10m", MIN, MAX), Ok(MAX));
+        assert_eq!(duration_range("0.1s", MIN, MAX), Ok(MIN));
+    }
+
+    neg!(above, "11m", MIN, MAX, "exceeds maximum of 10m");
+    neg!(below, "99ms", MIN, MAX, "less than minimum of 100ms");
+    neg!(
+        compound,
+        "2h",
+        MIN,
+        Duration::from_secs(5_400),
+        "exceeds maximum of 1h30m"
+    );
+    neg!(
+        sub_second,
+        "1s",
+        Duration::from_micros(1_500_001),
+        MAX,
+        "less than minimum of 1s500ms1us"
+    );
+    neg!(
+        zero,
+        "1s",
+        Duration::ZERO,
+        Duration::ZERO,
+        "exceeds maximum of 0s"
+    );
+    neg!(
+        days,
+        "2d",
+        MIN,
+        Duration::from_secs(90_000),
+        "exceeds maximum of 1d1h"
+    );
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn timeout(s: &str) -> Result<Duration, String> {
+        duration_range(s, Duration::from_millis(100), Duration::from_secs(600))
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=timeout)]
+        timeout: Duration,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--timeout", "1m30s"]);
+        assert_eq!(args.timeout, Duration::from_secs(90));
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--timeout", "50ms"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("less than minimum of 100ms")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use clap::Parser;
+use clap_num::{exact_digits, exact_digits_range};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $COUNT:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(exact_digits($VAL, $COUNT), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $COUNT:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u32, String> = exact_digits($VAL, $COUNT);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(pin, "1234", 4, 1234u16);
+    pos!(leading_zeros, "0042", 4, 42u16);
+    pos!(all_zeros, "000000", 6, 0u32);
+    pos!(single, "7", 1, 7u8);
+    pos!(max, "255", 3, 255u8);
+
+    neg!(too_short, "123", 4, "expected 4 digits, got 3");
+    neg!(too_long, "12345", 4, "expected 4 digits, got 5");
+    neg!(empty, "", 4, "expected 4 digits, got 0");
+    neg!(single_digit, "12", 1, "expected 1 digit, got 2");
+    neg!(letter, "12a4", 4, "invalid digit found in string");
+    neg!(underscores, "1_234", 4, "invalid digit found in string");
+    neg!(plus, "+123", 3, "invalid digit found in string");
+    neg!(negative, "-123", 3, "value cannot be negative");
+    neg!(
+        hex_prefix,
+        "0x12",
+        4,
+        "hexadecimal prefix '0x' is not accepted, expected decimal"
+    );
+    neg!(
+        overflow,
+        "99999999999",
+        11,
+        "number too large to fit in target type"
+    );
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u16, String> = exact_digits_range($VAL, 3, 200, 899);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    #[test]
+    fn in_range() {
+        assert_eq!(exact_digits_range("212", 3, 200, 899), Ok(212u16));
+    }
+
+    neg!(min, "012", "less than minimum of 200");
+    neg!(max, "900", "exceeds maximum of 899");
+    neg!(length_before_range, "2120", "expected 3 digits, got 4");
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn otp(s: &str) -> Result<u32, String> {
+        exact_digits(s, 6)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=otp)]
+        otp: u32,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--otp", "012345"]);
+        assert_eq!(args.otp, 12345);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--otp", "12345"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("expected 6 digits, got 5")),
+            _ => unreachable!(),
+        }
+    }
+}
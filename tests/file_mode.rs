@@ -0,0 +1,45 @@
+use clap_num::file_mode;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(file_mode($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(file_mode($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(simple, "644", 0o644);
+    pos!(leading_zero, "0755", 0o755);
+    pos!(prefix, "0o4755", 0o4755);
+    pos!(prefix_upper, "0O600", 0o600);
+    pos!(zero, "0", 0);
+    pos!(max, "7777", 0o7777);
+
+    neg!(invalid_digit, "0758", "invalid octal digit '8'");
+    neg!(hex_prefix, "0x755", "invalid octal digit 'x'");
+    neg!(symbolic, "u+x", "invalid octal digit 'u'");
+    neg!(negative, "-644", "invalid octal digit '-'");
+    neg!(too_large, "17777", "exceeds maximum of 0o7777");
+    neg!(
+        integer_overflow,
+        "0o777777777777",
+        "exceeds maximum of 0o7777"
+    );
+    neg!(empty, "0o", "cannot parse integer from empty string");
+}
@@ -0,0 +1,161 @@
+use clap::Parser;
+use clap_num::fmt::{Bin, Engineering, Grouped, Hex, Oct, Si};
+use clap_num::{maybe_bin, maybe_hex, maybe_oct, number_range, si_number};
+
+// values with every number of digits, and at the limits of each type
+fn samples() -> Vec<u64> {
+    let mut samples = vec![0, 1, 7, 9, 10, 999, 1_000, 1_001, 4_700, 65_535, u64::MAX];
+    for digits in 1..20 {
+        let power = 10u64.pow(digits);
+        samples.extend([power - 1, power, power + 1, power / 2 * 3]);
+    }
+    samples
+}
+
+#[cfg(test)]
+mod grouped {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(Grouped(0).to_string(), "0");
+        assert_eq!(Grouped(100).to_string(), "100");
+        assert_eq!(Grouped(1_000).to_string(), "1_000");
+        assert_eq!(Grouped(123_456_789).to_string(), "123_456_789");
+        assert_eq!(Grouped(-1_000_000i32).to_string(), "-1_000_000");
+        assert_eq!(Grouped(i8::MIN).to_string(), "-128");
+    }
+
+    #[test]
+    fn round_trip() {
+        for val in samples() {
+            let s = Grouped(val).to_string();
+            assert_eq!(number_range(&s, 0, u64::MAX), Ok(val), "{s}");
+        }
+        for val in [i64::MIN, -1_000_000, -1, 0, 1_000_000, i64::MAX] {
+            let s = Grouped(val).to_string();
+            assert_eq!(number_range(&s, i64::MIN, i64::MAX), Ok(val), "{s}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod radix {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(Hex(0xFFu8).to_string(), "0xFF");
+        assert_eq!(Hex(0u8).to_string(), "0x0");
+        assert_eq!(format!("{:#}", Hex(0xDEAD_BEEFu32)), "0xDEAD_BEEF");
+        assert_eq!(format!("{:#}", Hex(0x1_0000u32)), "0x1_0000");
+        assert_eq!(Bin(10u8).to_string(), "0b1010");
+        assert_eq!(format!("{:#}", Bin(0x1Fu8)), "0b1_1111");
+        assert_eq!(Oct(0o755u16).to_string(), "0o755");
+        assert_eq!(format!("{:#}", Oct(0o7755u16)), "0o7_755");
+    }
+
+    #[test]
+    fn round_trip() {
+        for val in samples() {
+            for s in [Hex(val).to_string(), format!("{:#}", Hex(val))] {
+                assert_eq!(maybe_hex(&s), Ok(val), "{s}");
+            }
+            for s in [Bin(val).to_string(), format!("{:#}", Bin(val))] {
+                assert_eq!(maybe_bin(&s), Ok(val), "{s}");
+            }
+            for s in [Oct(val).to_string(), format!("{:#}", Oct(val))] {
+                assert_eq!(maybe_oct(&s), Ok(val), "{s}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod si {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(Si(0).to_string(), "0");
+        assert_eq!(Si(1_000).to_string(), "1k");
+        assert_eq!(Si(1_234_567).to_string(), "1.234567M");
+        assert_eq!(Si(-4_700i32).to_string(), "-4.7k");
+        assert_eq!(Si(10u128.pow(24)).to_string(), "1Y");
+        assert_eq!(Si(10u128.pow(27)).to_string(), "1000Y");
+    }
+
+    #[test]
+    fn round_trip() {
+        for val in samples() {
+            let s = Si(val).to_string();
+            assert_eq!(si_number(&s), Ok(val), "{s}");
+        }
+        for val in [i64::MIN, -4_700, -1, 0, i64::MAX] {
+            let s = Si(val).to_string();
+            assert_eq!(si_number(&s), Ok(val), "{s}");
+        }
+        for val in [u128::MAX, 10u128.pow(27)] {
+            let s = Si(val).to_string();
+            assert_eq!(si_number(&s), Ok(val), "{s}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod engineering {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(Engineering(0).to_string(), "0");
+        assert_eq!(Engineering(-999).to_string(), "-999");
+        assert_eq!(Engineering(1_000).to_string(), "1e3");
+        assert_eq!(Engineering(12_340).to_string(), "12.34e3");
+        assert_eq!(Engineering(123_456_789).to_string(), "123.456789e6");
+        assert_eq!(Engineering(10u128.pow(27)).to_string(), "1e27");
+    }
+
+    #[test]
+    fn round_trip() {
+        for val in samples() {
+            let s = Engineering(val).to_string();
+            assert_eq!(si_number(&s), Ok(val), "{s}");
+        }
+        for val in [i64::MIN, -4_700, -1, 0, i64::MAX] {
+            let s = Engineering(val).to_string();
+            assert_eq!(si_number(&s), Ok(val), "{s}");
+        }
+        for val in [u128::MAX, 10u128.pow(27)] {
+            let s = Engineering(val).to_string();
+            assert_eq!(si_number(&s), Ok(val), "{s}");
+        }
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    const DEFAULT_ADDRESS: u32 = 0x4000_0000;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=maybe_hex::<u32>, default_value_t=DEFAULT_ADDRESS)]
+        address: u32,
+    }
+
+    #[test]
+    fn default() {
+        let args = Args::parse_from([""]);
+        assert_eq!(args.address, DEFAULT_ADDRESS);
+    }
+
+    #[test]
+    fn formatted() {
+        let hex = format!("{:#}", Hex(DEFAULT_ADDRESS));
+        let args = Args::parse_from(["", "--address", &hex]);
+        assert_eq!(args.address, DEFAULT_ADDRESS);
+    }
+}
@@ -0,0 +1,124 @@
+use clap::Parser;
+use clap_num::{fraction, fraction_range};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(fraction($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(fraction($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(third, "1/3", 1.0 / 3.0);
+    pos!(pi, "22/7", 22.0 / 7.0);
+    pos!(quarter, "1/4", 0.25);
+    pos!(decimal, "0.5", 0.5);
+    pos!(integer, "2", 2.0);
+    pos!(decimal_parts, "1.5/3", 0.5);
+    pos!(negative, "-1/2", -0.5);
+    pos!(negative_denominator, "1/-2", -0.5);
+    pos!(zero_numerator, "0/5", 0.0);
+
+    neg!(division_by_zero, "1/0", "division by zero in '1/0'");
+    neg!(
+        division_by_negative_zero,
+        "1/-0.0",
+        "division by zero in '1/-0.0'"
+    );
+    neg!(
+        missing_denominator,
+        "1/",
+        "cannot parse float from empty string"
+    );
+    neg!(
+        missing_numerator,
+        "/2",
+        "cannot parse float from empty string"
+    );
+    neg!(double_slash, "1/2/3", "invalid float literal");
+    neg!(spaces, "1 / 3", "invalid float literal");
+    neg!(nan, "NaN", "fraction is not a number");
+    neg!(nan_fraction, "inf/inf", "fraction is not a number");
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(fraction_range("1/3", 0.0, 1.0), Ok(1.0 / 3.0));
+        assert_eq!(fraction_range("1", 0.0, 1.0), Ok(1.0));
+    }
+
+    #[test]
+    fn above_max() {
+        assert_eq!(
+            fraction_range("4/3", 0.0, 1.0),
+            Err(String::from("exceeds maximum of 1"))
+        );
+    }
+
+    #[test]
+    fn below_min() {
+        assert_eq!(
+            fraction_range("-1/3", 0.0, 1.0),
+            Err(String::from("less than minimum of 0"))
+        );
+    }
+
+    #[test]
+    fn nan() {
+        assert_eq!(
+            fraction_range("nan", 0.0, 1.0),
+            Err(String::from("fraction is not a number"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn ratio(s: &str) -> Result<f64, String> {
+        fraction_range(s, 0.0, 1.0)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=ratio)]
+        ratio: f64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--ratio", "3/4"]);
+        assert_eq!(args.ratio, 0.75);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--ratio", "1/0"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("division by zero in '1/0'")),
+            _ => unreachable!(),
+        }
+    }
+}
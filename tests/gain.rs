@@ -0,0 +1,78 @@
+use clap::Parser;
+use clap_num::gain;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path, with a tolerance for the decibel conversion
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val = gain($VAL).unwrap();
+                assert!((val - $RESULT).abs() < 1e-9, "{val} != {}", $RESULT);
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(gain($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(linear, "2x", 2.0);
+    pos!(linear_no_suffix, "0.5", 0.5);
+    pos!(linear_symbol, "1.5×", 1.5);
+    pos!(linear_zero, "0", 0.0);
+    pos!(unity_db, "0dB", 1.0);
+    pos!(positive_db, "+6dB", 1.995_262_314_968_879_5);
+    pos!(negative_db, "-20dB", 0.1);
+    pos!(lowercase_db, "20db", 10.0);
+    pos!(fractional_db, "-10.0dB", 0.316_227_766_016_837_94);
+
+    neg!(
+        negative_linear,
+        "-0.5x",
+        "linear gain cannot be negative, use dB for attenuation"
+    );
+    neg!(empty_db, "dB", "cannot parse float from empty string");
+    neg!(both_suffixes, "2xdB", "invalid float literal");
+    neg!(unit_only, "DB", "invalid float literal");
+    neg!(nan, "NaN", "gain is not a number");
+    neg!(nan_db, "NaNdB", "gain is not a number");
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=gain, allow_hyphen_values=true)]
+        volume: f64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--volume", "2x"]);
+        assert_eq!(args.volume, 2.0);
+        let args = Args::parse_from(["", "--volume", "-40dB"]);
+        assert!((args.volume - 0.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--volume", "-2"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("linear gain cannot be negative")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use clap::Parser;
+use clap_num::{geometry, Geometry, GeometryParser};
+
+fn crop(s: &str) -> Result<Geometry, String> {
+    GeometryParser::new()
+        .width(1, 1920)
+        .height(1, 1080)
+        .x(-100, 1919)
+        .y(0, 1079)
+        .parse(s)
+}
+
+const fn size(width: u32, height: u32) -> Geometry {
+    Geometry {
+        width,
+        height,
+        offset: None,
+    }
+}
+
+const fn placed(width: u32, height: u32, x: i32, y: i32) -> Geometry {
+    Geometry {
+        width,
+        height,
+        offset: Some((x, y)),
+    }
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(crop($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(crop($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(size_only, "640x480", size(640, 480));
+    pos!(upper_x, "640X480", size(640, 480));
+    pos!(offset, "640x480+10+20", placed(640, 480, 10, 20));
+    pos!(negative_x, "640x480-10+20", placed(640, 480, -10, 20));
+    pos!(zero_offset, "1920x1080+0+0", placed(1920, 1080, 0, 0));
+    pos!(underscores, "1_920x1_080", size(1920, 1080));
+
+    neg!(
+        no_height,
+        "640",
+        "expected geometry in the format of 640x480+10+20"
+    );
+    neg!(
+        one_offset,
+        "640x480+10",
+        "expected geometry in the format of 640x480+10+20"
+    );
+    neg!(
+        offset_only,
+        "+10+20",
+        "expected geometry in the format of 640x480+10+20"
+    );
+    neg!(
+        empty_width,
+        "x480",
+        "invalid width '': cannot parse integer from empty string"
+    );
+    neg!(
+        invalid_height,
+        "640x48a",
+        "invalid height '48a': invalid digit found in string"
+    );
+    neg!(
+        three_offsets,
+        "640x480+1+2+3",
+        "invalid vertical offset '+2+3': invalid digit found in string"
+    );
+    neg!(
+        width_above,
+        "4000x480",
+        "invalid width '4000': exceeds maximum of 1920"
+    );
+    neg!(
+        height_below,
+        "640x0",
+        "invalid height '0': less than minimum of 1"
+    );
+    neg!(
+        x_below,
+        "640x480-101+0",
+        "invalid horizontal offset '-101': less than minimum of -100"
+    );
+    neg!(
+        y_below,
+        "640x480+0-1",
+        "invalid vertical offset '-1': less than minimum of 0"
+    );
+}
+
+#[cfg(test)]
+mod unbounded {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(geometry("0x0-5-5"), Ok(placed(0, 0, -5, -5)));
+        assert_eq!(geometry("4294967295x1"), Ok(size(u32::MAX, 1)));
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(
+            geometry("4294967296x1"),
+            Err(String::from(
+                "invalid width '4294967296': number too large to fit in target type"
+            ))
+        );
+        assert_eq!(
+            geometry("640x-480"),
+            Err(String::from(
+                "invalid height '': cannot parse integer from empty string"
+            ))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=crop)]
+        crop: Geometry,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--crop", "640x480+10+20"]);
+        assert_eq!(args.crop, placed(640, 480, 10, 20));
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--crop", "4000x480"]);
+        match args {
+            Err(e) => assert!(e
+                .to_string()
+                .contains("invalid width '4000': exceeds maximum of 1920")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use clap_num::{gray_code, gray_decode, gray_encode};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(gray_code($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u8, String> = gray_code($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(zero, "0", 0u8);
+    pos!(one, "1", 1u8);
+    pos!(two, "3", 2u8);
+    pos!(three, "2", 3u8);
+    pos!(hex, "0x6", 4u8);
+    pos!(msb, "0x80", 0xFFu8);
+    pos!(wide, "0x8000000000000000", u64::MAX);
+
+    neg!(overflow, "0x100", "number too large to fit in target type");
+    neg!(nan, "gray", "invalid digit found in string");
+
+    #[test]
+    fn round_trip() {
+        for value in 0..=u16::MAX {
+            assert_eq!(gray_decode(gray_encode(value)), value);
+        }
+    }
+
+    #[test]
+    fn adjacent_values_differ_by_one_bit() {
+        for value in 0..u16::MAX {
+            let diff = gray_encode(value) ^ gray_encode(value + 1);
+            assert_eq!(diff.count_ones(), 1);
+        }
+    }
+}
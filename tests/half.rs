@@ -0,0 +1,207 @@
+#![cfg(feature = "half")]
+
+use clap::Parser;
+use clap_num::half::{float, float_range, from_bits, si_float, si_float_range};
+use half::{bf16, f16};
+
+fn h(val: f32) -> f16 {
+    f16::from_f32(val)
+}
+
+fn b(val: f32) -> bf16 {
+    bf16::from_f32(val)
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(f16_simple, float::<f16>, "0.25", h(0.25));
+    pos!(f16_negative, float::<f16>, "-1.5", h(-1.5));
+    pos!(f16_exponent, float::<f16>, "1.5e-3", h(1.5e-3));
+    pos!(f16_rounded, float::<f16>, "0.1", h(0.1));
+    pos!(f16_max, float::<f16>, "65504", f16::MAX);
+    pos!(f16_underscores, float::<f16>, "1_000", h(1000.0));
+    pos!(bf16_simple, float::<bf16>, "3.140625", b(3.140625));
+    pos!(bf16_large, float::<bf16>, "1e38", b(1e38));
+
+    neg!(
+        f16_overflow,
+        float::<f16>,
+        "70000",
+        "number too large to fit in target type"
+    );
+    neg!(
+        f16_underflow,
+        float::<f16>,
+        "-70000",
+        "number too small to fit in target type"
+    );
+    neg!(
+        bf16_overflow,
+        float::<bf16>,
+        "1e39",
+        "number too large to fit in target type"
+    );
+    neg!(f16_nan, float::<f16>, "NaN", "value is not a number");
+    neg!(
+        f16_empty,
+        float::<f16>,
+        "",
+        "cannot parse float from empty string"
+    );
+    neg!(f16_invalid, float::<f16>, "1.2.3", "invalid float literal");
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    fn threshold(s: &str) -> Result<f16, String> {
+        float_range(s, f16::ZERO, f16::ONE)
+    }
+
+    #[test]
+    fn pos() {
+        assert_eq!(threshold("0"), Ok(f16::ZERO));
+        assert_eq!(threshold("0.5"), Ok(h(0.5)));
+        assert_eq!(threshold("1"), Ok(f16::ONE));
+        // rounded to 1.0 before the range check
+        assert_eq!(threshold("1.0001"), Ok(f16::ONE));
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(threshold("1.5"), Err(String::from("exceeds maximum of 1")));
+        assert_eq!(
+            threshold("-0.5"),
+            Err(String::from("less than minimum of 0"))
+        );
+        assert_eq!(
+            si_float_range("2k", b(0.0), b(1000.0)),
+            Err(String::from("exceeds maximum of 1000"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod si {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(si_float::<f16>("1.5k"), Ok(h(1500.0)));
+        assert_eq!(si_float::<f16>("1k5"), Ok(h(1500.0)));
+        assert_eq!(si_float::<f16>("-2k"), Ok(h(-2000.0)));
+        assert_eq!(si_float::<f16>("0.5"), Ok(h(0.5)));
+        assert_eq!(si_float::<bf16>("3G"), Ok(b(3e9)));
+        assert_eq!(si_float_range("1k", b(0.0), b(1000.0)), Ok(b(1000.0)));
+    }
+
+    #[test]
+    fn decimal_prefixes() {
+        assert_eq!(si_float::<f16>("5da"), Ok(h(50.0)));
+        assert_eq!(si_float::<f16>("10.13h"), Ok(h(1013.0)));
+        assert_eq!(si_float::<f16>("1h5"), Ok(h(150.0)));
+        assert_eq!(si_float::<f16>("5d"), Ok(h(0.5)));
+        assert_eq!(si_float::<f16>("25c"), Ok(h(0.25)));
+        assert_eq!(si_float::<bf16>("-2c"), Ok(b(-0.02)));
+        assert_eq!(
+            si_float::<f16>("1000h"),
+            Err(String::from("number too large to fit in target type"))
+        );
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(
+            si_float::<f16>("100k"),
+            Err(String::from("number too large to fit in target type"))
+        );
+        assert_eq!(
+            si_float::<f16>("1.5k5"),
+            Err(String::from("invalid digit found in string"))
+        );
+        assert_eq!(
+            si_float::<f16>("1kk"),
+            Err(String::from("invalid float literal"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod bits {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(from_bits::<f16>("0x3C00"), Ok(f16::ONE));
+        assert_eq!(from_bits::<bf16>("0x3F80"), Ok(bf16::ONE));
+        assert_eq!(from_bits::<f16>("15360"), Ok(f16::ONE));
+        assert!(from_bits::<f16>("0x7E00").unwrap().is_nan());
+        assert_eq!(from_bits::<f16>("0x7C00"), Ok(f16::INFINITY));
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(
+            from_bits::<f16>("0x10000"),
+            Err(String::from("number too large to fit in target type"))
+        );
+        assert_eq!(
+            from_bits::<f16>("1.0"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn threshold(s: &str) -> Result<f16, String> {
+        float_range(s, f16::ZERO, f16::ONE)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=threshold)]
+        threshold: f16,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--threshold", "0.25"]);
+        assert_eq!(args.threshold, h(0.25));
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--threshold", "2"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 1")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use clap_num::{hex_color, hex_color_rgba};
+
+#[cfg(test)]
+mod rgba {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(hex_color_rgba($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(hex_color_rgba($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(short, "#F80", (0xFF, 0x88, 0x00, 0xFF));
+    pos!(short_alpha, "#F808", (0xFF, 0x88, 0x00, 0x88));
+    pos!(long, "#FF8800", (0xFF, 0x88, 0x00, 0xFF));
+    pos!(long_alpha, "#FF880080", (0xFF, 0x88, 0x00, 0x80));
+    pos!(lowercase, "#ff8800", (0xFF, 0x88, 0x00, 0xFF));
+    pos!(hex_prefix, "0xFF8800", (0xFF, 0x88, 0x00, 0xFF));
+    pos!(hex_prefix_upper, "0X12345678", (0x12, 0x34, 0x56, 0x78));
+
+    neg!(
+        no_prefix,
+        "FF8800",
+        "expected color to start with '#' or '0x'"
+    );
+    neg!(
+        five_digits,
+        "#FF880",
+        "expected 3, 4, 6, or 8 hex digits in color, found 5"
+    );
+    neg!(
+        empty,
+        "#",
+        "expected 3, 4, 6, or 8 hex digits in color, found 0"
+    );
+    neg!(non_hex, "#GG8800", "invalid digit found in string");
+    neg!(sign, "#+F80", "invalid digit found in string");
+    neg!(multibyte, "#é8800", "invalid digit found in string");
+}
+
+#[cfg(test)]
+mod packed {
+    use super::*;
+
+    #[test]
+    fn opaque() {
+        assert_eq!(hex_color("#FF8800"), Ok(0xFF88_00FF));
+    }
+
+    #[test]
+    fn alpha() {
+        assert_eq!(hex_color("#12345678"), Ok(0x1234_5678));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            hex_color("#12345"),
+            Err(String::from(
+                "expected 3, 4, 6, or 8 hex digits in color, found 5"
+            ))
+        );
+    }
+}
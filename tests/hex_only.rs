@@ -0,0 +1,70 @@
+use clap_num::{hex_only, hex_only_range};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(hex_only($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u32, String> = hex_only($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(no_prefix, "10", 16u8);
+    pos!(prefix, "0x10", 16u8);
+    pos!(prefix_upper, "0X10", 16u8);
+    pos!(letters, "ff", 255u8);
+    pos!(underscores, "DEAD_BEEF", 0xDEAD_BEEFu32);
+    pos!(zero, "0", 0u8);
+
+    neg!(empty, "", "cannot parse integer from empty string");
+    neg!(prefix_only, "0x", "cannot parse integer from empty string");
+    neg!(non_hex, "0x1G", "invalid digit found in string");
+    neg!(negative, "-ff", "value cannot be negative");
+    neg!(
+        overflow,
+        "1_0000_0000",
+        "number too large to fit in target type"
+    );
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(hex_only_range("7F", 0u8, 0x7Fu8), Ok(0x7F));
+    }
+
+    #[test]
+    fn above_max() {
+        assert_eq!(
+            hex_only_range("80", 0u8, 0x7Fu8),
+            Err(String::from("exceeds maximum of 0x7F"))
+        );
+    }
+
+    #[test]
+    fn below_min() {
+        assert_eq!(
+            hex_only_range("0x7", 0x8u8, 0x7Fu8),
+            Err(String::from("less than minimum of 0x8"))
+        );
+    }
+}
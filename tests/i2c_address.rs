@@ -0,0 +1,99 @@
+use clap_num::{i2c_address, i2c_address_unreserved};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(i2c_address($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(i2c_address($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(zero, "0", 0x00);
+    pos!(hex, "0x50", 0x50);
+    pos!(decimal, "80", 0x50);
+    pos!(reserved, "0x78", 0x78);
+    pos!(max, "0x7F", 0x7F);
+
+    neg!(above, "0x80", "exceeds maximum of 0x7F");
+    neg!(above_u8, "0x100", "exceeds maximum of 0x7F");
+    neg!(
+        overflow,
+        "0x1_0000_0000",
+        "number too large to fit in target type"
+    );
+    neg!(empty, "", "cannot parse integer from empty string");
+    neg!(invalid, "0xG", "invalid digit found in string");
+}
+
+#[cfg(test)]
+mod unreserved {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(i2c_address_unreserved($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(i2c_address_unreserved($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(min, "0x08", 0x08);
+    pos!(max, "0x77", 0x77);
+
+    neg!(
+        general_call,
+        "0",
+        "address 0x00 is reserved for general call or START byte"
+    );
+    neg!(cbus, "0x01", "address 0x01 is reserved for CBUS");
+    neg!(
+        bus_format,
+        "0x02",
+        "address 0x02 is reserved for a different bus format"
+    );
+    neg!(
+        future,
+        "0x03",
+        "address 0x03 is reserved for future purposes"
+    );
+    neg!(
+        hs_mode,
+        "0x07",
+        "address 0x07 is reserved for Hs-mode controller codes"
+    );
+    neg!(
+        ten_bit,
+        "0x78",
+        "address 0x78 is reserved for 10-bit addressing"
+    );
+    neg!(device_id, "0x7F", "address 0x7F is reserved for device ID");
+    neg!(above, "0x80", "exceeds maximum of 0x7F");
+}
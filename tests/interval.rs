@@ -0,0 +1,127 @@
+use clap::Parser;
+use clap_num::{interval, number_range, si_number};
+use std::ops::{Bound, RangeBounds};
+
+type Interval = (Bound<i32>, Bound<i32>);
+
+fn window(s: &str) -> Result<Interval, String> {
+    interval(s, |b| number_range(b, -100, 100))
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+    use Bound::{Excluded, Included, Unbounded};
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $LOWER:expr, $UPPER:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(window($VAL), Ok(($LOWER, $UPPER)));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(window($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(closed, "[0,10]", Included(0), Included(10));
+    pos!(half_open, "[0,10)", Included(0), Excluded(10));
+    pos!(half_open_left, "(0,10]", Excluded(0), Included(10));
+    pos!(open, "(0,10)", Excluded(0), Excluded(10));
+    pos!(negative, "[-10,-5]", Included(-10), Included(-5));
+    pos!(whitespace, "[ 0 , 10 )", Included(0), Excluded(10));
+    pos!(degenerate, "[5,5]", Included(5), Included(5));
+    pos!(lower_unbounded, "(-inf,10]", Unbounded, Included(10));
+    pos!(upper_unbounded, "[0,inf)", Included(0), Unbounded);
+    pos!(upper_unbounded_sign, "[0,+inf)", Included(0), Unbounded);
+    pos!(unbounded, "(-∞,∞)", Unbounded, Unbounded);
+
+    neg!(
+        no_brackets,
+        "0,10",
+        "expected interval in the format of [0,10)"
+    );
+    neg!(
+        missing_close,
+        "[0,10",
+        "expected interval in the format of [0,10)"
+    );
+    neg!(
+        missing_comma,
+        "[0 10]",
+        "expected interval in the format of [0,10)"
+    );
+    neg!(empty, "[]", "expected interval in the format of [0,10)");
+    neg!(
+        dot_dot,
+        "0..10",
+        "expected interval in the format of [0,10)"
+    );
+    neg!(reversed, "[10,0]", "interval [10,0] is empty");
+    neg!(empty_open, "[5,5)", "interval [5,5) is empty");
+    neg!(empty_both_open, "(5,5)", "interval (5,5) is empty");
+    neg!(
+        inclusive_infinity,
+        "[-inf,0]",
+        "infinite bound '-inf' must be exclusive"
+    );
+    neg!(wrong_infinity, "(inf,0]", "invalid digit found in string");
+    neg!(bound_range, "[0,101]", "exceeds maximum of 100");
+    neg!(invalid_bound, "[a,1]", "invalid digit found in string");
+}
+
+#[cfg(test)]
+mod range_bounds {
+    use super::*;
+
+    #[test]
+    fn contains() {
+        let window = window("(0,10]").unwrap();
+        assert!(!window.contains(&0));
+        assert!(window.contains(&1));
+        assert!(window.contains(&10));
+        assert!(!window.contains(&11));
+    }
+
+    #[test]
+    fn other_parser() {
+        let si = interval::<u32, _>("[1k,2k)", si_number).unwrap();
+        assert_eq!(si, (Bound::Included(1_000), Bound::Excluded(2_000)));
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=window, allow_hyphen_values=true)]
+        window: Interval,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--window", "(-inf,10)"]);
+        assert_eq!(args.window, (Bound::Unbounded, Bound::Excluded(10)));
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--window", "[10,0)"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("interval [10,0) is empty")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use clap::Parser;
+use clap_num::{ipv4, ipv4_network_order};
+
+// standalone basic tests
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(ipv4($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(ipv4($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(dotted, "192.168.1.1", 0xC0A8_0101);
+    pos!(unspecified, "0.0.0.0", 0);
+    pos!(broadcast, "255.255.255.255", u32::MAX);
+    pos!(loopback, "127.0.0.1", 0x7F00_0001);
+    pos!(decimal, "3232235777", 0xC0A8_0101);
+    pos!(hex, "0xC0A80101", 0xC0A8_0101);
+
+    neg!(octet_overflow, "256.0.0.1", "invalid IPv4 address syntax");
+    neg!(too_few_octets, "192.168.1", "invalid IPv4 address syntax");
+    neg!(too_many_octets, "1.2.3.4.5", "invalid IPv4 address syntax");
+    neg!(trailing_dot, "1.2.3.4.", "invalid IPv4 address syntax");
+    neg!(
+        integer_overflow,
+        "0x100000000",
+        "number too large to fit in target type"
+    );
+    neg!(hostname, "localhost", "invalid digit found in string");
+
+    #[test]
+    fn network_order() {
+        let addr = ipv4_network_order("192.168.1.1").unwrap();
+        assert_eq!(addr.to_ne_bytes(), [192, 168, 1, 1]);
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=ipv4)]
+        addr: u32,
+    }
+
+    #[test]
+    fn dotted() {
+        let opt = Args::parse_from(["", "--addr", "10.0.0.1"]);
+        assert_eq!(opt.addr, 0x0A00_0001);
+    }
+
+    #[test]
+    fn invalid() {
+        let opt = Args::try_parse_from(["", "--addr", "10.0.0"]);
+        match opt {
+            Err(e) => {
+                assert!(format!("{:?}", e).contains("invalid IPv4 address syntax"));
+            }
+            _ => unreachable!(),
+        };
+    }
+}
@@ -0,0 +1,49 @@
+use clap_num::{key_value, maybe_hex, number_range, si_number};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $KEY:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(key_value($VAL, $PARSER), Ok((String::from($KEY), $RESULT)));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<(String, u32), String> = key_value($VAL, maybe_hex);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(hex, maybe_hex, "FOO=0x10", "FOO", 16u32);
+    pos!(decimal, maybe_hex, "FOO=10", "FOO", 10u32);
+    pos!(si, si_number, "rate=1.5k", "rate", 1_500i32);
+    pos!(
+        range,
+        |v| number_range(v, -5, 5),
+        "offset=-5",
+        "offset",
+        -5i8
+    );
+
+    neg!(no_equals, "FOO", "expected NAME=VALUE");
+    neg!(empty, "", "expected NAME=VALUE");
+    neg!(empty_name, "=1", "empty name before '='");
+    neg!(
+        empty_value,
+        "FOO=",
+        "cannot parse integer from empty string"
+    );
+    neg!(invalid_value, "FOO=0xG", "invalid digit found in string");
+    neg!(first_equals, "A=B=1", "invalid digit found in string");
+}
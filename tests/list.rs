@@ -0,0 +1,441 @@
+use clap_num::{maybe_hex, si_number, ListParser, NumberParser};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    fn channels() -> ListParser<u8> {
+        ListParser::new(NumberParser::new(maybe_hex::<u8>).max(0x0F)).max_len(4)
+    }
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Ok($RESULT.to_vec()));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(empty, channels(), "", [0u8; 0]);
+    pos!(blank, channels(), "  ", [0u8; 0]);
+    pos!(single, channels(), "3", [3]);
+    pos!(mixed, channels(), "1,0x2,0xF", [1, 2, 15]);
+    pos!(whitespace, channels(), " 1 , 2 ", [1, 2]);
+    pos!(max_len, channels(), "1,2,3,4", [1, 2, 3, 4]);
+    pos!(
+        delimiter,
+        ListParser::new(NumberParser::new(si_number::<u32>)).delimiter(':'),
+        "1k:2M",
+        [1_000, 2_000_000]
+    );
+    pos!(
+        unbounded,
+        ListParser::new(NumberParser::<i8>::decimal()),
+        "-1,-2,-3,-4,-5",
+        [-1, -2, -3, -4, -5]
+    );
+
+    neg!(
+        above,
+        channels(),
+        "1,0x10",
+        "invalid element '0x10': exceeds maximum of 15"
+    );
+    neg!(
+        invalid,
+        channels(),
+        "1,x",
+        "invalid element 'x': invalid digit found in string"
+    );
+    neg!(
+        trailing,
+        channels(),
+        "1,",
+        "invalid element '': cannot parse integer from empty string"
+    );
+    neg!(
+        too_many,
+        channels(),
+        "1,2,3,4,5",
+        "expected at most 4 values, got 5"
+    );
+}
+
+// sorting, deduplication, and order checks
+#[cfg(test)]
+mod order {
+    use super::*;
+
+    fn ids() -> ListParser<u32> {
+        ListParser::new(NumberParser::new(maybe_hex::<u32>))
+    }
+
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Ok($RESULT.to_vec()));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(sort, ids().sort(), "3,1,2", [1, 2, 3]);
+    pos!(sort_duplicates, ids().sort(), "3,1,3", [1, 3, 3]);
+    pos!(dedup, ids().dedup(), "3,1,3,0x1", [3, 1]);
+    pos!(sort_dedup, ids().sort().dedup(), "3,1,3,2,1", [1, 2, 3]);
+    pos!(sorted, ids().require_sorted(), "1,2,2,3", [1, 2, 2, 3]);
+    pos!(unique, ids().require_unique(), "3,1,2", [3, 1, 2]);
+    pos!(
+        strictly_ascending,
+        ids().require_sorted().require_unique(),
+        "1,2,3",
+        [1, 2, 3]
+    );
+    pos!(
+        empty,
+        ids().require_sorted().require_unique(),
+        "",
+        [0u32; 0]
+    );
+    pos!(
+        float,
+        ListParser::new(NumberParser::<f32>::decimal()).sort(),
+        "2.5,-1,0.5",
+        [-1.0, 0.5, 2.5]
+    );
+
+    neg!(
+        unsorted,
+        ids().require_sorted(),
+        "1,3,0x2",
+        "element '0x2' is less than the previous element '3'"
+    );
+    neg!(
+        duplicate,
+        ids().require_unique(),
+        "1,2,0x1",
+        "duplicate element '0x1', equal to '1'"
+    );
+    neg!(
+        duplicate_same,
+        ids().require_unique(),
+        "4,5,6,5",
+        "duplicate element '5'"
+    );
+    neg!(
+        duplicate_float,
+        ListParser::new(NumberParser::<f64>::decimal()).require_unique(),
+        "0.5,1,0.50",
+        "duplicate element '0.50', equal to '0.5'"
+    );
+    neg!(
+        strictly_ascending_duplicate,
+        ids().require_sorted().require_unique(),
+        "1,2,2",
+        "duplicate element '2'"
+    );
+    pos!(increasing, ids().require_increasing(), "1,2,0x3", [1, 2, 3]);
+    neg!(
+        increasing_equal,
+        ids().require_increasing(),
+        "1,2,0x2,3",
+        "element '0x2' is not greater than the previous element '2'"
+    );
+    neg!(
+        increasing_less,
+        ids().require_increasing(),
+        "1,3,2,1",
+        "element '2' is not greater than the previous element '3'"
+    );
+    neg!(
+        increasing_float,
+        ListParser::new(NumberParser::<f64>::decimal()).require_increasing(),
+        "0,1.5,1.25",
+        "element '1.25' is not greater than the previous element '1.5'"
+    );
+    neg!(
+        checked_before_sort,
+        ids().require_sorted().sort(),
+        "2,1",
+        "element '1' is less than the previous element '2'"
+    );
+    neg!(
+        checked_before_dedup,
+        ids().require_unique().dedup(),
+        "1,1",
+        "duplicate element '1'"
+    );
+}
+
+// element count constraints
+#[cfg(test)]
+mod len {
+    use super::*;
+
+    fn values() -> ListParser<u8> {
+        ListParser::new(NumberParser::<u8>::decimal())
+    }
+
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Ok($RESULT.to_vec()));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(exact, values().len(3), "255,128,0", [255, 128, 0]);
+    pos!(min_len, values().min_len(2), "1,2", [1, 2]);
+    pos!(above_min_len, values().min_len(2), "1,2,3", [1, 2, 3]);
+    pos!(bounded, values().min_len(1).max_len(3), "1,2,3", [1, 2, 3]);
+    pos!(empty_allowed, values().max_len(2), "", [0u8; 0]);
+
+    neg!(
+        exact_few,
+        values().len(3),
+        "255,128",
+        "expected exactly 3 values, got 2"
+    );
+    neg!(
+        exact_many,
+        values().len(3),
+        "1,2,3,4",
+        "expected exactly 3 values, got 4"
+    );
+    neg!(
+        exact_one,
+        values().len(1),
+        "1,2",
+        "expected exactly 1 value, got 2"
+    );
+    neg!(
+        too_few,
+        values().min_len(2),
+        "1",
+        "expected at least 2 values, got 1"
+    );
+    neg!(
+        empty,
+        values().min_len(1),
+        "",
+        "expected at least 1 value, got 0"
+    );
+    neg!(
+        too_many,
+        values().min_len(1).max_len(3),
+        "1,2,3,4",
+        "expected at most 3 values, got 4"
+    );
+    neg!(
+        element_first,
+        values().len(3),
+        "1,x",
+        "invalid element 'x': invalid digit found in string"
+    );
+}
+
+// sum constraints
+#[cfg(test)]
+mod sum {
+    use super::*;
+
+    fn percentages() -> ListParser<u8> {
+        ListParser::new(NumberParser::<u8>::decimal()).require_sum(100)
+    }
+
+    fn weights() -> ListParser<f64> {
+        ListParser::new(NumberParser::<f64>::decimal()).require_sum_within(1.0, 0.01)
+    }
+
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Ok($RESULT.to_vec()));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(exact, percentages(), "50,30,20", [50, 30, 20]);
+    pos!(single, percentages(), "100", [100]);
+    pos!(within, weights(), "0.5,0.495", [0.5, 0.495]);
+    pos!(float_error, weights(), "0.1,0.2,0.7", [0.1, 0.2, 0.7]);
+    pos!(sorted, percentages().sort(), "50,30,20", [20, 30, 50]);
+    pos!(after_dedup, percentages().dedup(), "60,40,40", [60, 40]);
+    pos!(
+        signed,
+        ListParser::new(NumberParser::<i8>::decimal()).require_sum(0),
+        "-5,10,-5",
+        [-5, 10, -5]
+    );
+
+    neg!(
+        below,
+        percentages(),
+        "50,30,10",
+        "values sum to 90, expected 100"
+    );
+    neg!(
+        above,
+        percentages(),
+        "50,30,30",
+        "values sum to 110, expected 100"
+    );
+    neg!(empty, percentages(), "", "values sum to 0, expected 100");
+    neg!(
+        overflow,
+        percentages(),
+        "200,100",
+        "sum of values overflows, expected 100"
+    );
+    neg!(
+        outside,
+        weights(),
+        "0.5,0.25",
+        "values sum to 0.75, expected 1±0.01"
+    );
+    neg!(
+        dedup_removed,
+        percentages().dedup(),
+        "50,25,25",
+        "values sum to 75, expected 100"
+    );
+    neg!(
+        element_first,
+        percentages(),
+        "50,x",
+        "invalid element 'x': invalid digit found in string"
+    );
+}
+
+// constraints on values that were already parsed
+#[cfg(test)]
+mod check {
+    use super::*;
+
+    fn ids() -> ListParser<u32> {
+        ListParser::new(NumberParser::new(maybe_hex::<u32>))
+            .max_len(3)
+            .require_unique()
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(ids().check(vec![3, 1, 2]), Ok(vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn too_many() {
+        assert_eq!(
+            ids().check(vec![1, 2, 3, 4]),
+            Err(String::from("expected at most 3 values, got 4"))
+        );
+    }
+
+    #[test]
+    fn duplicate() {
+        assert_eq!(
+            ids().check(vec![1, 2, 1]),
+            Err(String::from("duplicate element '1'"))
+        );
+    }
+
+    #[test]
+    fn sort_dedup() {
+        let parser = ListParser::new(NumberParser::<u8>::decimal())
+            .sort()
+            .dedup();
+        assert_eq!(parser.check(vec![3, 1, 3, 2]), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn element() {
+        assert_eq!(ids().parse_element(" 0x10 "), Ok(16));
+        assert_eq!(
+            ids().parse_element("x"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+    use clap::Parser;
+
+    fn channels() -> ListParser<u8> {
+        ListParser::new(NumberParser::new(maybe_hex::<u8>).max(0x0F))
+            .min_len(2)
+            .require_sorted()
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, num_args=1.., value_delimiter=',', value_parser=|s: &str| channels().parse_element(s))]
+        channels: Vec<u8>,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--channels", "1,0x2", "15"]);
+        assert_eq!(channels().check(args.channels), Ok(vec![1, 2, 15]));
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::parse_from(["", "--channels", "2", "1"]);
+        assert_eq!(
+            channels().check(args.channels),
+            Err(String::from(
+                "element '1' is less than the previous element '2'"
+            ))
+        );
+        let args = Args::parse_from(["", "--channels", "2"]);
+        assert_eq!(
+            channels().check(args.channels),
+            Err(String::from("expected at least 2 values, got 1"))
+        );
+        assert!(Args::try_parse_from(["", "--channels", "0x10"]).is_err());
+    }
+}
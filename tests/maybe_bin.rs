@@ -0,0 +1,125 @@
+use clap_num::{maybe_bin, maybe_bin_range, maybe_bin_with_base, Base};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(maybe_bin($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u8, String> = maybe_bin($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(simple, "123", 123u8);
+    pos!(zero_bin, "0b0", 0u8);
+    pos!(one_bin, "0b1", 1u8);
+    pos!(case, "0B101", 5u8);
+    pos!(underscores, "0b1010_1010", 0xAAu8);
+    pos!(wide, "0b1_0000_0000", 256u16);
+
+    neg!(
+        missing_suffix,
+        "0b",
+        "cannot parse integer from empty string"
+    );
+    neg!(non_bin_digit, "0b102", "invalid digit found in string");
+    neg!(negative, "-0b1", "value cannot be negative");
+    neg!(
+        hex_prefix,
+        "0x1",
+        "hexadecimal prefix '0x' is not accepted, expected decimal or binary with '0b'"
+    );
+    neg!(
+        octal_prefix,
+        "0O7",
+        "octal prefix '0O' is not accepted, expected decimal or binary with '0b'"
+    );
+    neg!(
+        overflow,
+        "0b1_0000_0000",
+        "number too large to fit in target type"
+    );
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(maybe_bin_range("0b1111", 0u8, 0b1111u8), Ok(0b1111));
+    }
+
+    #[test]
+    fn decimal_in_range() {
+        assert_eq!(maybe_bin_range("3", 0u8, 0b1111u8), Ok(3));
+    }
+
+    #[test]
+    fn above_max() {
+        assert_eq!(
+            maybe_bin_range("0b10000", 0u8, 0b1111u8),
+            Err(String::from("exceeds maximum of 0b1111"))
+        );
+    }
+
+    #[test]
+    fn below_min() {
+        assert_eq!(
+            maybe_bin_range("0b1", 2u8, 0b1111u8),
+            Err(String::from("less than minimum of 0b10"))
+        );
+    }
+}
+
+// the base used to parse the value is returned
+#[cfg(test)]
+mod with_base {
+    use super::*;
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr, $BASE:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(maybe_bin_with_base($VAL), Ok(($RESULT, $BASE)));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<(u8, Base), String> = maybe_bin_with_base($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(prefix, "0b101", 5u8, Base::Binary);
+    pos!(prefix_upper, "0B11", 3u8, Base::Binary);
+    pos!(underscores, "0b1111_0000", 0xF0u8, Base::Binary);
+    pos!(decimal, "10", 10u8, Base::Decimal);
+    pos!(zero, "0", 0u8, Base::Decimal);
+    neg!(
+        hex_prefix,
+        "0x1",
+        "hexadecimal prefix '0x' is not accepted, expected decimal or binary with '0b'"
+    );
+    neg!(negative, "-1", "value cannot be negative");
+}
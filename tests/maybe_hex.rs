@@ -1,4 +1,4 @@
-use clap_num::maybe_hex;
+use clap_num::{maybe_hex, maybe_hex_range, maybe_hex_with_base, Base};
 
 #[cfg(test)]
 mod basic {
@@ -32,6 +32,9 @@ mod basic {
     pos!(one_hex, "0x1", 1u64);
     pos!(leading_zero, "001", 1u64);
     pos!(case, "0XABcDE", 703710u32);
+    pos!(underscore_hex, "0xDEAD_BEEF", 0xDEAD_BEEFu32);
+    pos!(underscore_after_prefix, "0x_FF", 0xFFu8);
+    pos!(underscore_dec, "1_000_000", 1_000_000u32);
 
     neg!(
         missing_suffix,
@@ -40,4 +43,231 @@ mod basic {
     );
     neg!(dec_with_hex, "1A", "invalid digit found in string");
     neg!(non_hex_digit, "0x12G", "invalid digit found in string");
+    neg!(negative, "-5", "value cannot be negative");
+    neg!(negative_hex, "-0x5", "value cannot be negative");
+    neg!(negative_invalid, "-5x", "invalid digit found in string");
+    neg!(
+        negative_large,
+        "-0x1_0000_0000_0000_0000",
+        "value cannot be negative"
+    );
+
+    #[test]
+    fn negative_large_u8() {
+        assert_eq!(
+            maybe_hex::<u8>("-0x1FF"),
+            Err(String::from("value cannot be negative"))
+        );
+    }
+    neg!(sign_only, "-", "invalid digit found in string");
+    neg!(
+        binary_prefix,
+        "0b1010",
+        "binary prefix '0b' is not accepted, expected decimal or hexadecimal with '0x'"
+    );
+    neg!(
+        octal_prefix,
+        "0o1_7",
+        "octal prefix '0o' is not accepted, expected decimal or hexadecimal with '0x'"
+    );
+    neg!(
+        binary_prefix_invalid,
+        "0b12",
+        "invalid digit found in string"
+    );
+    neg!(binary_prefix_only, "0b", "invalid digit found in string");
+    neg!(
+        underscore_only,
+        "0x_",
+        "cannot parse integer from empty string"
+    );
+    neg!(
+        underscore_in_prefix,
+        "0_x1",
+        "invalid digit found in string"
+    );
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(maybe_hex_range("0x200", 0x100u32, 0x200u32), Ok(0x200));
+    }
+
+    #[test]
+    fn above_max_hex() {
+        assert_eq!(
+            maybe_hex_range("0x201", 0x100u32, 0x200u32),
+            Err(String::from("exceeds maximum of 0x200"))
+        );
+    }
+
+    #[test]
+    fn below_min_hex() {
+        assert_eq!(
+            maybe_hex_range("0Xff", 0x100u32, 0x200u32),
+            Err(String::from("less than minimum of 0x100"))
+        );
+    }
+
+    #[test]
+    fn above_max_decimal() {
+        assert_eq!(
+            maybe_hex_range("513", 0x100u32, 0x200u32),
+            Err(String::from("exceeds maximum of 512"))
+        );
+    }
+
+    #[test]
+    fn zero_bound() {
+        assert_eq!(
+            maybe_hex_range("0x1", 0u8, 0u8),
+            Err(String::from("exceeds maximum of 0x0"))
+        );
+    }
+
+    #[test]
+    fn max_bound() {
+        assert_eq!(
+            maybe_hex_range("0x0", 1u128, u128::MAX),
+            Err(String::from("less than minimum of 0x1"))
+        );
+        assert_eq!(
+            maybe_hex_range("0x1_0000", 0u64, u64::MAX - 1),
+            Ok(0x1_0000)
+        );
+    }
+}
+
+// the base used to parse the value is returned
+#[cfg(test)]
+mod with_base {
+    use super::*;
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr, $BASE:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(maybe_hex_with_base($VAL), Ok(($RESULT, $BASE)));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<(u8, Base), String> = maybe_hex_with_base($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(prefix, "0x10", 16u8, Base::Hexadecimal);
+    pos!(prefix_upper, "0XFF", 255u8, Base::Hexadecimal);
+    pos!(
+        underscores,
+        "0xDEAD_BEEF",
+        0xDEAD_BEEFu32,
+        Base::Hexadecimal
+    );
+    pos!(decimal, "10", 10u8, Base::Decimal);
+    pos!(zero, "0", 0u8, Base::Decimal);
+    neg!(
+        binary_prefix,
+        "0b1",
+        "binary prefix '0b' is not accepted, expected decimal or hexadecimal with '0x'"
+    );
+    neg!(negative, "-1", "value cannot be negative");
+
+    #[test]
+    fn echo() {
+        let (val, base) = maybe_hex_with_base::<u32>("0x1F").unwrap();
+        assert_eq!(base.radix(), 16);
+        assert_eq!(format!("{}{:X}", base.prefix(), val), "0x1F");
+        assert_eq!(Base::Decimal.prefix(), "");
+    }
+}
+
+// additional prefixes for base-16 values
+#[cfg(test)]
+mod hex_parser {
+    use clap_num::HexParser;
+
+    fn parser() -> HexParser {
+        HexParser::new().prefix("$").prefix("#")
+    }
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(parser().parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u8, String> = parser().parse($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(dollar, "$FF", 255u8);
+    pos!(hash, "#ff", 255u8);
+    pos!(zero_x, "0xFF", 255u8);
+    pos!(zero_x_upper, "0XFF", 255u8);
+    pos!(decimal, "255", 255u8);
+    pos!(underscores, "$DEAD_BEEF", 0xDEAD_BEEFu32);
+
+    neg!(empty_digits, "$", "cannot parse integer from empty string");
+    neg!(overflow, "$100", "number too large to fit in target type");
+    neg!(negative, "-$1", "value cannot be negative");
+    neg!(
+        binary_prefix,
+        "0b1",
+        "binary prefix '0b' is not accepted, expected decimal or hexadecimal with '0x'"
+    );
+    neg!(unknown_prefix, "%1", "invalid digit found in string");
+
+    #[test]
+    fn default_rejects() {
+        let val: Result<u8, String> = HexParser::new().parse("$FF");
+        assert_eq!(val, Err(String::from("invalid digit found in string")));
+    }
+
+    #[test]
+    fn longest_prefix() {
+        let parser = HexParser::new().prefix("h").prefix("h'");
+        assert_eq!(parser.parse("h'FF"), Ok(255u8));
+        assert_eq!(parser.parse("hFF"), Ok(255u8));
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(
+            parser().parse_range("$201", 0x100u16, 0x200),
+            Err(String::from("exceeds maximum of $200"))
+        );
+        assert_eq!(
+            parser().parse_range("#FF", 0x100u16, 0x200),
+            Err(String::from("less than minimum of #100"))
+        );
+        assert_eq!(
+            parser().parse_range("0x201", 0x100u16, 0x200),
+            Err(String::from("exceeds maximum of 0x200"))
+        );
+        assert_eq!(
+            parser().parse_range("513", 0x100u16, 0x200),
+            Err(String::from("exceeds maximum of 512"))
+        );
+        assert_eq!(parser().parse_range("$180", 0x100u16, 0x200), Ok(0x180));
+    }
 }
@@ -0,0 +1,123 @@
+use clap_num::{maybe_oct, maybe_oct_range, maybe_oct_with_base, Base};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(maybe_oct($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u16, String> = maybe_oct($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(simple, "123", 123u16);
+    pos!(zero_oct, "0o0", 0u16);
+    pos!(leading_zero_is_decimal, "0755", 755u16);
+    pos!(oct, "0o755", 0o755u16);
+    pos!(case, "0O17", 15u8);
+    pos!(underscores, "0o7_777", 0o7777u16);
+
+    neg!(
+        missing_suffix,
+        "0o",
+        "cannot parse integer from empty string"
+    );
+    neg!(non_oct_digit, "0o78", "invalid digit found in string");
+    neg!(negative, "-0o7", "value cannot be negative");
+    neg!(
+        hex_prefix,
+        "0xFF",
+        "hexadecimal prefix '0x' is not accepted, expected decimal or octal with '0o'"
+    );
+    neg!(
+        overflow,
+        "0o200000",
+        "number too large to fit in target type"
+    );
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(maybe_oct_range("0o777", 0u16, 0o777u16), Ok(0o777));
+    }
+
+    #[test]
+    fn above_max() {
+        assert_eq!(
+            maybe_oct_range("0o1000", 0u16, 0o777u16),
+            Err(String::from("exceeds maximum of 0o777"))
+        );
+    }
+
+    #[test]
+    fn below_min() {
+        assert_eq!(
+            maybe_oct_range("0o7", 8u16, 0o777u16),
+            Err(String::from("less than minimum of 0o10"))
+        );
+    }
+
+    #[test]
+    fn above_max_decimal() {
+        assert_eq!(
+            maybe_oct_range("512", 0u16, 0o777u16),
+            Err(String::from("exceeds maximum of 511"))
+        );
+    }
+}
+
+// the base used to parse the value is returned
+#[cfg(test)]
+mod with_base {
+    use super::*;
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr, $BASE:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(maybe_oct_with_base($VAL), Ok(($RESULT, $BASE)));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<(u8, Base), String> = maybe_oct_with_base($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(prefix, "0o17", 15u8, Base::Octal);
+    pos!(prefix_upper, "0O7", 7u8, Base::Octal);
+    pos!(underscores, "0o7_777", 0o7_777u16, Base::Octal);
+    pos!(decimal, "10", 10u8, Base::Decimal);
+    pos!(zero, "0", 0u8, Base::Decimal);
+    neg!(
+        hex_prefix,
+        "0x1",
+        "hexadecimal prefix '0x' is not accepted, expected decimal or octal with '0o'"
+    );
+    neg!(negative, "-1", "value cannot be negative");
+}
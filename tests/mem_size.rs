@@ -0,0 +1,142 @@
+use clap::Parser;
+use clap_num::{mem_size, mem_size_range};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(mem_size($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u64, String> = mem_size($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plain, "100", 100u64);
+    pos!(bytes, "100B", 100u64);
+    pos!(kilo, "4kB", 4_000u64);
+    pos!(kibi, "4KiB", 4_096u64);
+    pos!(mebi, "1.5MiB", 1_572_864u64);
+    pos!(giga, "2GB", 2_000_000_000u64);
+    pos!(gibi, "64GiB", 64u64 << 30);
+    pos!(tebi, "1TiB", 1u64 << 40);
+    pos!(peta, "1PB", 1_000_000_000_000_000u64);
+    pos!(exbi, "15EiB", 15u64 << 60);
+    pos!(u128_max, "256EiB", 1u128 << 68);
+
+    neg!(
+        uppercase_k,
+        "4KB",
+        "ambiguous unit: use kB (1000) or KiB (1024)"
+    );
+    neg!(bare_k, "4k", "ambiguous unit: use kB (1000) or KiB (1024)");
+    neg!(
+        bare_uppercase_k,
+        "4K",
+        "ambiguous unit: use kB (1000) or KiB (1024)"
+    );
+    neg!(
+        bare_m,
+        "1.5M",
+        "ambiguous unit: use MB (1000000) or MiB (1048576)"
+    );
+    neg!(
+        bare_g,
+        "2G",
+        "ambiguous unit: use GB (1000000000) or GiB (1073741824)"
+    );
+    neg!(
+        lowercase_binary,
+        "4kiB",
+        "unknown suffix 'kiB', expected one of B, kB, MB, GB, TB, PB, EB, KiB, MiB, GiB, TiB, PiB, EiB"
+    );
+    neg!(
+        bits,
+        "4Mb",
+        "unknown suffix 'Mb', expected one of B, kB, MB, GB, TB, PB, EB, KiB, MiB, GiB, TiB, PiB, EiB"
+    );
+    neg!(fraction_byte, "1.5B", "not an integer");
+    neg!(overflow, "16EiB", "number too large to fit in target type");
+    neg!(negative, "-1KiB", "value cannot be negative");
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u64, String> = mem_size_range($VAL, $MIN, $MAX);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    #[test]
+    fn in_range() {
+        assert_eq!(mem_size_range("64GiB", 1 << 20, 64 << 30), Ok(64u64 << 30));
+        assert_eq!(mem_size_range("1MiB", 1 << 20, 64 << 30), Ok(1u64 << 20));
+    }
+
+    neg!(max, "65GiB", 1 << 20, 64 << 30, "exceeds maximum of 64GiB");
+    neg!(min, "1KiB", 1 << 20, 64 << 30, "less than minimum of 1MiB");
+    neg!(si_bound, "2GB", 0, 1_000_000_000, "exceeds maximum of 1GB");
+    neg!(bytes_bound, "1KiB", 0, 1_000, "exceeds maximum of 1kB");
+    neg!(plain_bound, "1KiB", 0, 999, "exceeds maximum of 999");
+    neg!(binary_bound, "1MiB", 0, 3_072, "exceeds maximum of 3KiB");
+    neg!(inexact_bound, "1MiB", 0, 1_536, "exceeds maximum of 1536");
+    neg!(
+        ambiguous,
+        "1M",
+        0,
+        64 << 30,
+        "ambiguous unit: use MB (1000000) or MiB (1048576)"
+    );
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn limit(s: &str) -> Result<u64, String> {
+        mem_size_range(s, 1 << 20, 64 << 30)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=limit)]
+        limit: u64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--limit", "512MiB"]);
+        assert_eq!(args.limit, 512 << 20);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--limit", "100GB"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 64GiB")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "miette")]
+
+use clap_num::{ErrorKind, NumberParser, ParseError};
+use miette::{Diagnostic, LabeledSpan};
+
+fn labels(err: &ParseError) -> Vec<LabeledSpan> {
+    err.labels().unwrap().collect()
+}
+
+#[test]
+fn invalid_digit() {
+    let err = NumberParser::<u16>::decimal()
+        .try_parse("12x4")
+        .unwrap_err();
+    assert_eq!(labels(&err), [LabeledSpan::at(2..3, "invalid digit")]);
+    assert_eq!(
+        err.help().unwrap().to_string(),
+        "remove the characters that are not digits"
+    );
+    assert_eq!(err.code().unwrap().to_string(), "clap_num::InvalidDigit");
+}
+
+#[test]
+fn above_maximum() {
+    let err = NumberParser::<u16>::decimal()
+        .max(99)
+        .try_parse("100")
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AboveMaximum);
+    assert_eq!(labels(&err), [LabeledSpan::at(0..3, "too large")]);
+    assert!(err.help().is_none());
+}
+
+#[test]
+fn source_code() {
+    let err = ParseError::new("-5", "value cannot be negative");
+    let source = err.source_code().unwrap();
+    let contents = source.read_span(&(0..2).into(), 0, 0).unwrap();
+    assert_eq!(contents.data(), b"-5");
+    assert_eq!(labels(&err), [LabeledSpan::at(0..1, "negative")]);
+}
+
+#[test]
+fn report() {
+    let report = miette::Report::new(NumberParser::<u8>::decimal().try_parse("").unwrap_err());
+    assert_eq!(report.to_string(), "cannot parse integer from empty string");
+}
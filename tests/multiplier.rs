@@ -0,0 +1,121 @@
+use clap::Parser;
+use clap_num::{multiplier, multiplier_range};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(multiplier($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ty, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<$TYPE, String> = multiplier($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(integer, "4x", 4u8);
+    pos!(upper, "4X", 4u8);
+    pos!(symbol, "2×", 2u32);
+    pos!(no_suffix, "8", 8u16);
+    pos!(underscores, "1_000x", 1_000u16);
+    pos!(fraction, "0.5x", 0.5f32);
+    pos!(float_integer, "2x", 2.0f64);
+    pos!(negative, "-1.5x", -1.5f64);
+
+    neg!(
+        fraction_integer,
+        "0.5x",
+        u8,
+        "invalid digit found in string"
+    );
+    neg!(empty, "x", u8, "cannot parse integer from empty string");
+    neg!(double_suffix, "2xx", u8, "invalid digit found in string");
+    neg!(negative_unsigned, "-2x", u8, "value cannot be negative");
+    neg!(
+        overflow,
+        "256x",
+        u8,
+        "number too large to fit in target type"
+    );
+    neg!(nan, "NaNx", f32, "multiplier is not a number");
+    neg!(invalid_float, "fastx", f32, "invalid float literal");
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(multiplier_range("16x", 1u8, 16u8), Ok(16));
+        assert_eq!(multiplier_range("0.25x", 0.25f32, 4.0f32), Ok(0.25));
+    }
+
+    #[test]
+    fn above_max() {
+        assert_eq!(
+            multiplier_range("32x", 1u8, 16u8),
+            Err(String::from("exceeds maximum of 16x"))
+        );
+    }
+
+    #[test]
+    fn below_min() {
+        assert_eq!(
+            multiplier_range("0.1x", 0.25f32, 4.0f32),
+            Err(String::from("less than minimum of 0.25x"))
+        );
+    }
+
+    #[test]
+    fn nan() {
+        assert_eq!(
+            multiplier_range("nan", 0.25f32, 4.0f32),
+            Err(String::from("multiplier is not a number"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn speed(s: &str) -> Result<f64, String> {
+        multiplier_range(s, 0.25, 4.0)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=speed)]
+        speed: f64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--speed", "1.5×"]);
+        assert_eq!(args.speed, 1.5);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--speed", "8x"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 4x")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use clap::Parser;
+use clap_num::{maybe_hex, named_or, si_number_range};
+
+const RATES: [(&str, u32); 2] = [("cd", 44_100), ("dvd", 48_000)];
+
+fn sample_rate(s: &str) -> Result<u32, String> {
+    named_or(s, &RATES, |v| si_number_range(v, 8_000, 192_000))
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(sample_rate($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(sample_rate($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(name, "cd", 44_100);
+    pos!(name_upper, "DVD", 48_000);
+    pos!(number, "96000", 96_000);
+    pos!(si_number, "22.05k", 22_050);
+
+    neg!(
+        unknown,
+        "bluray",
+        "unknown name 'bluray', expected a number or one of cd, dvd"
+    );
+    neg!(
+        empty,
+        "",
+        "unknown name '', expected a number or one of cd, dvd"
+    );
+    neg!(above, "1M", "exceeds maximum of 192k");
+    neg!(below, "1k", "less than minimum of 8k");
+    neg!(negative, "-1", "value cannot be negative");
+    neg!(fraction, "44.1001k", "not an integer");
+    neg!(invalid, "44k1k", "invalid digit found in string");
+}
+
+#[cfg(test)]
+mod names {
+    use super::*;
+
+    #[test]
+    fn precedence() {
+        // names are checked before the value parser
+        let names = [("10", 0x20u8)];
+        assert_eq!(named_or("10", &names, maybe_hex), Ok(0x20));
+        assert_eq!(named_or("0x10", &names, maybe_hex), Ok(0x10));
+    }
+
+    #[test]
+    fn no_names() {
+        assert_eq!(
+            named_or::<u8, _>("x", &[], maybe_hex),
+            Err(String::from(
+                "unknown name 'x', expected a number or one of "
+            ))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=sample_rate)]
+        sample_rate: u32,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--sample-rate", "dvd"]);
+        assert_eq!(args.sample_rate, 48_000);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--sample-rate", "vinyl"]);
+        match args {
+            Err(e) => assert!(e
+                .to_string()
+                .contains("expected a number or one of cd, dvd")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,1261 @@
+use clap_num::{maybe_hex, si_number, ErrorKind, NumberParser};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    fn cents() -> NumberParser<u8> {
+        NumberParser::decimal().range(0, 99)
+    }
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(min, cents(), "0", 0);
+    pos!(max, cents(), "99", 99);
+    pos!(underscores, cents(), "9_9", 99);
+    pos!(unbounded, NumberParser::<i64>::decimal(), "-1_000", -1_000);
+    pos!(
+        hex,
+        NumberParser::new(maybe_hex::<u32>).max(0xFF),
+        "0xFF",
+        0xFF
+    );
+    pos!(
+        si,
+        NumberParser::new(si_number::<i32>).min(-1000),
+        "-1k",
+        -1000
+    );
+
+    neg!(above, cents(), "100", "exceeds maximum of 99");
+    neg!(
+        below,
+        NumberParser::<i8>::decimal().min(-5),
+        "-6",
+        "less than minimum of -5"
+    );
+    neg!(empty, cents(), "", "cannot parse integer from empty string");
+    neg!(
+        overflow,
+        cents(),
+        "256",
+        "number too large to fit in target type"
+    );
+    neg!(
+        above_override,
+        cents().message(ErrorKind::AboveMaximum, "cents must be 0-99"),
+        "100",
+        "cents must be 0-99"
+    );
+    neg!(
+        overflow_override,
+        cents().message(ErrorKind::PosOverflow, "way too many cents"),
+        "256",
+        "way too many cents"
+    );
+    neg!(
+        other_kind_kept,
+        cents().message(ErrorKind::AboveMaximum, "cents must be 0-99"),
+        "1x",
+        "invalid digit found in string"
+    );
+    neg!(
+        last_override_wins,
+        cents()
+            .message(ErrorKind::InvalidDigit, "first")
+            .message(ErrorKind::InvalidDigit, String::from("second")),
+        "1x",
+        "second"
+    );
+    neg!(
+        not_an_integer_override,
+        NumberParser::new(si_number::<u32>).message(ErrorKind::NotAnInteger, "whole numbers only"),
+        "1.2345k",
+        "whole numbers only"
+    );
+}
+
+#[cfg(test)]
+mod validate {
+    use super::*;
+
+    fn even() -> NumberParser<u8> {
+        NumberParser::decimal()
+            .max(100)
+            .validate(|v| v % 2 == 0, "must be even")
+            .validate(|&v| v != 42, "must not be 42")
+    }
+
+    #[test]
+    fn pass() {
+        assert_eq!(even().parse("64"), Ok(64));
+    }
+
+    #[test]
+    fn fail() {
+        assert_eq!(even().parse("7"), Err(String::from("must be even")));
+    }
+
+    #[test]
+    fn order() {
+        assert_eq!(even().parse("42"), Err(String::from("must not be 42")));
+    }
+
+    #[test]
+    fn after_range() {
+        assert_eq!(
+            even().parse("101"),
+            Err(String::from("exceeds maximum of 100"))
+        );
+    }
+
+    #[test]
+    fn after_parse() {
+        assert_eq!(
+            even().parse("x"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn clone() {
+        let parser = even();
+        assert_eq!(parser.clone().parse("3"), parser.parse("3"));
+    }
+}
+
+#[cfg(test)]
+mod error_kind {
+    use super::*;
+    use clap_num::SiParser;
+
+    macro_rules! kind {
+        ($NAME:ident, $RESULT:expr, $KIND:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(ErrorKind::of(&$RESULT.unwrap_err()), $KIND);
+            }
+        };
+    }
+
+    kind!(empty, maybe_hex::<u8>(""), ErrorKind::Empty);
+    kind!(
+        invalid_digit,
+        maybe_hex::<u8>("0xG"),
+        ErrorKind::InvalidDigit
+    );
+    kind!(pos_overflow, si_number::<u8>("1k"), ErrorKind::PosOverflow);
+    kind!(
+        pos_overflow_si,
+        si_number::<u8>("1000000"),
+        ErrorKind::PosOverflow
+    );
+    kind!(neg_overflow, si_number::<i8>("-1k"), ErrorKind::NegOverflow);
+    kind!(
+        not_an_integer,
+        si_number::<u32>("1.5"),
+        ErrorKind::InvalidDigit
+    );
+    kind!(negative, maybe_hex::<u8>("-1"), ErrorKind::Negative);
+    kind!(other, clap_num::hex_color("red"), ErrorKind::Other);
+    kind!(range, clap_num::number_range("5", 0, 4), ErrorKind::Other);
+    kind!(
+        file_mode_range,
+        clap_num::file_mode("10000"),
+        ErrorKind::Other
+    );
+    kind!(
+        other_with_range_wording,
+        Err::<u8, _>("exceeds maximum of my patience"),
+        ErrorKind::Other
+    );
+
+    // the kind does not depend on the errors of other values
+    #[test]
+    fn after_other_parse() {
+        let err = clap_num::maybe_hex_range::<u8>("0x80", 0, 0x7F).unwrap_err();
+        let kind = ErrorKind::of(&err);
+        assert!(maybe_hex::<u8>("0x100").is_err());
+        assert_eq!(ErrorKind::of(&err), kind);
+    }
+
+    #[test]
+    fn above_maximum() {
+        let parser = NumberParser::<u8>::decimal().range(0, 4);
+        assert_eq!(
+            parser.try_parse("5").unwrap_err().kind(),
+            ErrorKind::AboveMaximum
+        );
+    }
+
+    #[test]
+    fn below_minimum() {
+        let parser = NumberParser::<i8>::decimal().range(0, 4);
+        assert_eq!(
+            parser.try_parse("-1").unwrap_err().kind(),
+            ErrorKind::BelowMinimum
+        );
+    }
+
+    #[test]
+    fn si() {
+        let parser =
+            NumberParser::<u8>::si(SiParser::new()).message(ErrorKind::PosOverflow, "too large");
+        assert_eq!(parser.parse("1k"), Err(String::from("too large")));
+    }
+
+    // messages of other parsers are not matched by their wording
+    #[test]
+    fn custom_parser() {
+        let parser = NumberParser::new(|s| match s {
+            "0" => Ok(0u8),
+            _ => Err(String::from("exceeds maximum of my patience")),
+        })
+        .message(ErrorKind::AboveMaximum, "too large");
+        assert_eq!(
+            parser.parse("1"),
+            Err(String::from("exceeds maximum of my patience"))
+        );
+        assert_eq!(parser.try_parse("1").unwrap_err().kind(), ErrorKind::Other);
+    }
+}
+
+#[cfg(test)]
+mod format_bounds {
+    use super::*;
+
+    fn address() -> NumberParser<u16> {
+        NumberParser::new(maybe_hex::<u16>)
+            .range(0x100, 0x3FF)
+            .format_bounds(|bound| format!("{bound:#06X}"))
+    }
+
+    #[test]
+    fn above() {
+        assert_eq!(
+            address().parse("0x400"),
+            Err(String::from("exceeds maximum of 0x03FF"))
+        );
+    }
+
+    #[test]
+    fn below() {
+        assert_eq!(
+            address().parse("1"),
+            Err(String::from("less than minimum of 0x0100"))
+        );
+    }
+
+    #[test]
+    fn override_wins() {
+        assert_eq!(
+            address()
+                .message(ErrorKind::AboveMaximum, "too high")
+                .parse("0x400"),
+            Err(String::from("too high"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod describe_overflow {
+    use super::*;
+
+    #[test]
+    fn unsigned() {
+        assert_eq!(
+            NumberParser::<u8>::decimal()
+                .describe_overflow()
+                .parse("256"),
+            Err(String::from("number too large for u8 (max 255)"))
+        );
+    }
+
+    #[test]
+    fn signed_max() {
+        assert_eq!(
+            NumberParser::new(si_number::<i16>)
+                .describe_overflow()
+                .parse("1M"),
+            Err(String::from("number too large for i16 (max 32767)"))
+        );
+    }
+
+    #[test]
+    fn signed_min() {
+        assert_eq!(
+            NumberParser::<i8>::decimal()
+                .describe_overflow()
+                .parse("-129"),
+            Err(String::from("number too small for i8 (min -128)"))
+        );
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(
+            NumberParser::new(maybe_hex::<u16>)
+                .describe_overflow()
+                .parse("0x1_0000"),
+            Err(String::from("number too large for u16 (max 65535)"))
+        );
+    }
+
+    #[test]
+    fn other_errors() {
+        assert_eq!(
+            NumberParser::<u8>::decimal().describe_overflow().parse("x"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod max_decimal_places {
+    use super::*;
+    use clap_num::multiplier;
+
+    fn price() -> NumberParser<f64> {
+        NumberParser::decimal().min(0.0).max_decimal_places(2)
+    }
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(price().parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(price().parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(integer, "20", 20.0);
+    pos!(one, "19.9", 19.9);
+    pos!(two, "19.99", 19.99);
+    pos!(trailing_zeros, "19.9900", 19.99);
+    pos!(exponent, "1999e-2", 19.99);
+    pos!(exponent_zeros, "19990e-3", 19.99);
+    pos!(positive_exponent, "1.999e1", 19.99);
+
+    neg!(
+        three,
+        "19.999",
+        "too many decimal places, expected at most 2"
+    );
+    neg!(nan, "NaN", "value is not a number");
+    neg!(
+        exponent_three,
+        "1999e-3",
+        "too many decimal places, expected at most 2"
+    );
+    neg!(
+        underscores,
+        "0.000_1",
+        "too many decimal places, expected at most 2"
+    );
+    neg!(invalid, "19.99.9", "invalid float literal");
+    neg!(below_min, "-0.01", "less than minimum of 0");
+
+    #[test]
+    fn zero_places() {
+        let parser = NumberParser::<f32>::decimal().max_decimal_places(0);
+        assert_eq!(parser.parse("5"), Ok(5.0));
+        assert_eq!(
+            parser.parse("5.5"),
+            Err(String::from("too many decimal places, expected at most 0"))
+        );
+    }
+
+    #[test]
+    fn suffix() {
+        let parser = NumberParser::new(multiplier::<f32>).max_decimal_places(1);
+        assert_eq!(parser.parse("1.5x"), Ok(1.5));
+        assert_eq!(
+            parser.parse("1.25x"),
+            Err(String::from("too many decimal places, expected at most 1"))
+        );
+    }
+
+    #[test]
+    fn message() {
+        let parser = price().message(ErrorKind::TooPrecise, "prices are in cents");
+        assert_eq!(
+            parser.parse("0.001"),
+            Err(String::from("prices are in cents"))
+        );
+    }
+
+    #[test]
+    fn kind() {
+        let err = price().try_parse("0.001").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TooPrecise);
+    }
+}
+
+#[cfg(test)]
+mod warn {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn baud() -> NumberParser<u32> {
+        NumberParser::decimal()
+            .range(110, 115_200)
+            .warn(|&baud| baud < 1_200, "baud rates below 1200 are deprecated")
+            .warn(|&baud| baud % 300 != 0, "non-standard baud rate")
+    }
+
+    #[test]
+    fn no_warnings() {
+        assert_eq!(baud().parse_with_warnings("9600"), Ok((9_600, vec![])));
+    }
+
+    #[test]
+    fn one_warning() {
+        assert_eq!(
+            baud().parse_with_warnings("300"),
+            Ok((300, vec!["baud rates below 1200 are deprecated"]))
+        );
+    }
+
+    #[test]
+    fn multiple_warnings() {
+        assert_eq!(
+            baud().parse_with_warnings("110"),
+            Ok((
+                110,
+                vec![
+                    "baud rates below 1200 are deprecated",
+                    "non-standard baud rate"
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn errors_take_precedence() {
+        assert_eq!(
+            baud().parse_with_warnings("100"),
+            Err(String::from("less than minimum of 110"))
+        );
+    }
+
+    #[test]
+    fn handler() {
+        let warnings: Arc<Mutex<Vec<String>>> = Arc::default();
+        let sink = Arc::clone(&warnings);
+        let parser = baud().on_warning(move |w| sink.lock().unwrap().push(String::from(w)));
+
+        assert_eq!(parser.parse("9600"), Ok(9_600));
+        assert!(warnings.lock().unwrap().is_empty());
+
+        assert_eq!(parser.parse("1000"), Ok(1_000));
+        assert_eq!(
+            *warnings.lock().unwrap(),
+            [
+                "baud rates below 1200 are deprecated",
+                "non-standard baud rate"
+            ]
+        );
+    }
+
+    #[test]
+    fn no_handler() {
+        assert_eq!(baud().parse("300"), Ok(300));
+    }
+}
+
+// values read from files with `@path`
+#[cfg(test)]
+mod allow_file {
+    use super::*;
+    use std::path::PathBuf;
+
+    // writes a file unique to the test, returning the argument for it
+    fn file(name: &str, contents: &str) -> (PathBuf, String) {
+        let path = std::env::temp_dir().join(format!("clap-num-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        let arg = format!("@{}", path.display());
+        (path, arg)
+    }
+
+    fn key() -> NumberParser<u128> {
+        NumberParser::new(maybe_hex)
+            .max(u64::MAX.into())
+            .allow_file()
+    }
+
+    #[test]
+    fn read() {
+        let (path, arg) = file("read", "0xDEAD_BEEF\n");
+        assert_eq!(key().parse(&arg), Ok(0xDEAD_BEEF));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn whitespace() {
+        let (path, arg) = file("whitespace", "  \t1234\r\n\n");
+        assert_eq!(key().parse(&arg), Ok(1234));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn validated() {
+        let (path, arg) = file("validated", "0x1_0000_0000_0000_0000");
+        assert_eq!(
+            key().parse(&arg),
+            Err(String::from("exceeds maximum of 18446744073709551615"))
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn multiple_values() {
+        let (path, arg) = file("multiple_values", "1\n2\n");
+        assert_eq!(
+            key().parse(&arg),
+            Err(String::from("invalid digit found in string"))
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn missing() {
+        let path = std::env::temp_dir().join("clap-num-missing-file");
+        let err = key().parse(&format!("@{}", path.display())).unwrap_err();
+        assert!(err.starts_with(&format!("cannot read '{}': ", path.display())));
+    }
+
+    #[test]
+    fn direct_value() {
+        assert_eq!(key().parse("0x10"), Ok(0x10));
+    }
+
+    #[test]
+    fn default_rejects() {
+        let parser = NumberParser::new(maybe_hex::<u32>);
+        assert_eq!(
+            parser.parse("@key.txt"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+}
+
+// values read from stdin with `-`
+#[cfg(test)]
+mod allow_stdin {
+    use super::*;
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    // environment variable set for the child process reading stdin
+    const CHILD: &str = "CLAP_NUM_STDIN_CHILD";
+
+    fn key() -> NumberParser<u32> {
+        NumberParser::new(maybe_hex).max(0xFFFF).allow_stdin()
+    }
+
+    // runs this test in a child process with `input` as stdin
+    fn run_child(name: &str, input: &str) -> bool {
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", &format!("allow_stdin::{name}")])
+            .env(CHILD, "1")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        child.wait().unwrap().success()
+    }
+
+    #[test]
+    fn read() {
+        if std::env::var_os(CHILD).is_some() {
+            assert_eq!(key().parse("-"), Ok(0xBEEF));
+        } else {
+            assert!(run_child("read", "  0xBEEF\n"));
+        }
+    }
+
+    #[test]
+    fn validated() {
+        if std::env::var_os(CHILD).is_some() {
+            assert_eq!(
+                key().parse("-"),
+                Err(String::from("exceeds maximum of 65535"))
+            );
+        } else {
+            assert!(run_child("validated", "0x10000\n"));
+        }
+    }
+
+    #[test]
+    fn direct_value() {
+        assert_eq!(key().parse("0x10"), Ok(0x10));
+    }
+
+    #[test]
+    fn default_rejects() {
+        let parser = NumberParser::new(maybe_hex::<u32>);
+        assert_eq!(
+            parser.parse("-"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+}
+
+// default values for empty strings
+#[cfg(test)]
+mod default_on_empty {
+    use super::*;
+    use clap::Parser;
+
+    fn level() -> NumberParser<u8> {
+        NumberParser::decimal().range(1, 9).default_on_empty(6)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(level().parse(""), Ok(6));
+    }
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(level().parse(" \t"), Ok(6));
+    }
+
+    #[test]
+    fn value() {
+        assert_eq!(level().parse("9"), Ok(9));
+        assert_eq!(
+            level().parse("0"),
+            Err(String::from("less than minimum of 1"))
+        );
+    }
+
+    #[test]
+    fn not_validated() {
+        let parser = NumberParser::<u8>::decimal().max(9).default_on_empty(10);
+        assert_eq!(parser.parse(""), Ok(10));
+    }
+
+    #[test]
+    fn default_rejects() {
+        assert_eq!(
+            NumberParser::<u8>::decimal().parse(""),
+            Err(String::from("cannot parse integer from empty string"))
+        );
+    }
+
+    fn parse_level(s: &str) -> Result<u8, String> {
+        level().parse(s)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=parse_level)]
+        level: u8,
+    }
+
+    #[test]
+    fn integration() {
+        let args = Args::parse_from(["", "--level", ""]);
+        assert_eq!(args.level, 6);
+        let args = Args::parse_from(["", "--level=3"]);
+        assert_eq!(args.level, 3);
+    }
+}
+
+#[cfg(test)]
+mod reject_separators {
+    use super::*;
+
+    fn strict() -> NumberParser<u32> {
+        NumberParser::new(maybe_hex::<u32>).reject_separators()
+    }
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(strict().parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(strict().parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(decimal, "1000", 1_000);
+    pos!(hex, "0xDEADBEEF", 0xDEAD_BEEF);
+
+    neg!(underscore, "1_000", "digit separator '_' is not allowed");
+    neg!(
+        hex_underscore,
+        "0xDEAD_BEEF",
+        "digit separator '_' is not allowed"
+    );
+    neg!(comma, "1,000", "digit separator ',' is not allowed");
+    neg!(apostrophe, "1'000", "digit separator ''' is not allowed");
+    neg!(space, "1 000", "digit separator ' ' is not allowed");
+    neg!(trailing, "1000_", "digit separator '_' is not allowed");
+    neg!(invalid, "1x", "invalid digit found in string");
+
+    #[test]
+    fn default_accepts() {
+        let parser = NumberParser::new(maybe_hex::<u32>);
+        assert_eq!(parser.parse("1_000"), Ok(1_000));
+    }
+
+    #[test]
+    fn floats() {
+        let parser = NumberParser::<f64>::decimal().reject_separators();
+        assert_eq!(parser.parse("1.5e3"), Ok(1_500.0));
+        assert_eq!(
+            parser.parse("1_000.5"),
+            Err(String::from("digit separator '_' is not allowed"))
+        );
+    }
+
+    #[test]
+    fn message() {
+        let parser = strict().message(ErrorKind::Separator, "use canonical numbers");
+        assert_eq!(
+            parser.parse("1_000"),
+            Err(String::from("use canonical numbers"))
+        );
+    }
+
+    #[test]
+    fn kind() {
+        let err = strict().try_parse("1_000").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Separator);
+    }
+}
+
+#[cfg(test)]
+mod separators {
+    use super::*;
+    use clap_num::Grouping;
+
+    fn thousands() -> NumberParser<i64> {
+        NumberParser::decimal().separators(&[','], Grouping::Every(3))
+    }
+
+    fn anywhere() -> NumberParser<u32> {
+        NumberParser::new(maybe_hex::<u32>).separators(&['\'', '_'], Grouping::Anywhere)
+    }
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(grouped, thousands(), "1,000,000", 1_000_000);
+    pos!(ungrouped, thousands(), "1000000", 1_000_000);
+    pos!(short_first, thousands(), "12,345", 12_345);
+    pos!(negative, thousands(), "-1,000", -1_000);
+    pos!(small, thousands(), "999", 999);
+    pos!(any_apostrophe, anywhere(), "1'0'0", 100);
+    pos!(any_mixed, anywhere(), "1'000_000", 1_000_000);
+    pos!(any_hex, anywhere(), "0xDE'AD'BE'EF", 0xDEAD_BEEF);
+
+    neg!(
+        indian,
+        thousands(),
+        "10,00,000",
+        "digit separators must split the integer part into groups of 3 digits"
+    );
+    neg!(
+        long_first,
+        thousands(),
+        "1000,000",
+        "digit separators must split the integer part into groups of 3 digits"
+    );
+    neg!(
+        trailing,
+        thousands(),
+        "1,000,",
+        "digit separators must split the integer part into groups of 3 digits"
+    );
+    neg!(
+        leading,
+        thousands(),
+        ",100",
+        "digit separators must split the integer part into groups of 3 digits"
+    );
+    neg!(
+        other,
+        thousands(),
+        "1_000",
+        "digit separator '_' is not allowed"
+    );
+    neg!(
+        space,
+        thousands(),
+        "1 000",
+        "digit separator ' ' is not allowed"
+    );
+    neg!(
+        any_leading,
+        anywhere(),
+        "_1",
+        "digit separator '_' must be between two digits"
+    );
+    neg!(
+        any_after_prefix,
+        anywhere(),
+        "0x_FF",
+        "digit separator '_' must be between two digits"
+    );
+    neg!(
+        any_double,
+        anywhere(),
+        "1__0",
+        "digit separator '_' must be between two digits"
+    );
+    neg!(
+        any_trailing,
+        anywhere(),
+        "10'",
+        "digit separator ''' must be between two digits"
+    );
+    neg!(
+        any_comma,
+        anywhere(),
+        "1,000",
+        "digit separator ',' is not allowed"
+    );
+    neg!(
+        any_invalid,
+        anywhere(),
+        "1'0x",
+        "invalid digit found in string"
+    );
+
+    #[test]
+    fn hex_groups() {
+        let parser = NumberParser::new(maybe_hex::<u32>).separators(&['_'], Grouping::Every(4));
+        assert_eq!(parser.parse("0xDEAD_BEEF"), Ok(0xDEAD_BEEF));
+        assert_eq!(parser.parse("0x1_0000"), Ok(0x1_0000));
+        assert_eq!(
+            parser.parse("0xDE_AD_BE_EF"),
+            Err(String::from(
+                "digit separators must split the integer part into groups of 4 digits"
+            ))
+        );
+    }
+
+    #[test]
+    fn decimal_places() {
+        let parser = NumberParser::<f64>::decimal()
+            .separators(&[' '], Grouping::Every(3))
+            .max_decimal_places(2);
+        assert_eq!(parser.parse("1 000.25"), Ok(1_000.25));
+        assert_eq!(
+            parser.parse("1 000.255"),
+            Err(String::from("too many decimal places, expected at most 2"))
+        );
+        assert_eq!(
+            parser.parse("1.000 1"),
+            Err(String::from(
+                "digit separators must split the integer part into groups of 3 digits"
+            ))
+        );
+    }
+
+    #[test]
+    fn base_parser_without_separators() {
+        fn plain(s: &str) -> Result<u32, String> {
+            s.parse().map_err(|e| format!("{e}"))
+        }
+        let parser = NumberParser::new(plain).separators(&['_'], Grouping::Anywhere);
+        assert_eq!(parser.parse("1_000"), Ok(1_000));
+    }
+
+    #[test]
+    fn kind() {
+        let err = thousands().try_parse("10,00").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Separator);
+    }
+}
+
+#[cfg(test)]
+mod allow_non_finite {
+    use super::*;
+    use clap_num::{fraction, maybe_hex, named_or};
+
+    fn sentinel() -> NumberParser<f64> {
+        NumberParser::decimal().allow_non_finite()
+    }
+
+    fn bounded() -> NumberParser<f64> {
+        NumberParser::decimal().range(0.0, 1.0).allow_non_finite()
+    }
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(sentinel().parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER.parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(finite, "0.5", 0.5);
+    pos!(inf, "inf", f64::INFINITY);
+    pos!(inf_upper, "INF", f64::INFINITY);
+    pos!(positive_inf, "+inf", f64::INFINITY);
+    pos!(negative_inf, "-inf", f64::NEG_INFINITY);
+    pos!(infinity, "Infinity", f64::INFINITY);
+    pos!(negative_infinity, "-infinity", f64::NEG_INFINITY);
+
+    neg!(
+        default_nan,
+        NumberParser::<f64>::decimal(),
+        "nan",
+        "value is not a number"
+    );
+    neg!(
+        default_inf,
+        NumberParser::<f64>::decimal(),
+        "inf",
+        "value is infinite"
+    );
+    neg!(
+        default_negative_inf,
+        NumberParser::<f32>::decimal(),
+        "-Infinity",
+        "value is infinite"
+    );
+    neg!(bounds, bounded(), "1.5", "exceeds maximum of 1");
+    neg!(bounds_inf, bounded(), "inf", "exceeds maximum of 1");
+    neg!(
+        bounds_negative_inf,
+        bounded(),
+        "-inf",
+        "less than minimum of 0"
+    );
+    neg!(bounds_nan, bounded(), "NaN", "value is not a number");
+    neg!(
+        partial_keyword,
+        sentinel(),
+        "infinit",
+        "invalid float literal"
+    );
+    neg!(
+        keyword_with_space,
+        sentinel(),
+        " inf",
+        "invalid float literal"
+    );
+
+    #[test]
+    fn nan() {
+        assert!(sentinel().parse("nan").unwrap().is_nan());
+        assert!(sentinel().parse("NaN").unwrap().is_nan());
+        assert!(sentinel().parse("-NAN").unwrap().is_nan());
+    }
+
+    #[test]
+    fn base_parser() {
+        // the keywords are accepted even if the base parser rejects them
+        let parser = NumberParser::new(fraction).allow_non_finite();
+        assert!(parser.parse("nan").unwrap().is_nan());
+        assert_eq!(parser.parse("1/2"), Ok(0.5));
+    }
+
+    #[test]
+    fn validators() {
+        let parser = sentinel().validate(|v| v.is_finite(), "must be finite");
+        assert_eq!(parser.parse("-inf"), Err(String::from("must be finite")));
+        assert_eq!(parser.parse("0.5"), Ok(0.5));
+    }
+
+    #[test]
+    fn integer_keyword() {
+        // keywords are given to the base parser unless they are accepted
+        let parser = NumberParser::new(|s| named_or(s, &[("inf", u32::MAX)], maybe_hex));
+        assert_eq!(parser.parse("inf"), Ok(u32::MAX));
+        assert_eq!(parser.parse("0x10"), Ok(16));
+    }
+}
+
+// spans of the offending part of a value
+#[cfg(test)]
+mod spans {
+    use super::*;
+    use clap_num::{number_range, prefixed_number, ParseError, SiParser};
+
+    // span of an error from a parser
+    macro_rules! span {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $KIND:expr, $SPAN:expr) => {
+            #[test]
+            fn $NAME() {
+                let err = $PARSER.try_parse($VAL).unwrap_err();
+                assert_eq!(err.kind(), $KIND);
+                assert_eq!(err.span(), $SPAN);
+            }
+        };
+    }
+
+    span!(
+        decimal_digit,
+        NumberParser::<u8>::decimal(),
+        "12a4",
+        ErrorKind::InvalidDigit,
+        2..3
+    );
+    span!(
+        hex_digit,
+        NumberParser::new(maybe_hex::<u32>),
+        "0xDEAG",
+        ErrorKind::InvalidDigit,
+        5..6
+    );
+    span!(
+        signed_digit,
+        NumberParser::<i8>::decimal(),
+        "-1_2x",
+        ErrorKind::InvalidDigit,
+        4..5
+    );
+    span!(
+        decimal_point,
+        NumberParser::<u8>::decimal(),
+        "1.5",
+        ErrorKind::InvalidDigit,
+        1..2
+    );
+    span!(
+        multi_byte,
+        NumberParser::<u8>::decimal(),
+        "1µ",
+        ErrorKind::InvalidDigit,
+        1..3
+    );
+    span!(
+        too_precise,
+        NumberParser::<f64>::decimal().max_decimal_places(2),
+        "-1.2_345",
+        ErrorKind::TooPrecise,
+        6..8
+    );
+    span!(
+        negative,
+        NumberParser::<u8>::decimal(),
+        "-5",
+        ErrorKind::Negative,
+        0..1
+    );
+    span!(
+        above_maximum,
+        NumberParser::<u8>::decimal().max(9),
+        "10",
+        ErrorKind::AboveMaximum,
+        0..2
+    );
+    span!(
+        si_digit,
+        NumberParser::<u32>::si(SiParser::new()),
+        "1.5q",
+        ErrorKind::InvalidDigit,
+        3..4
+    );
+    span!(
+        si_exponent_digit,
+        NumberParser::<i64>::si(SiParser::new()),
+        "-1.5e+3x",
+        ErrorKind::InvalidDigit,
+        7..8
+    );
+    span!(
+        si_multi_byte,
+        NumberParser::<u32>::si(SiParser::new()),
+        "2.2µ",
+        ErrorKind::InvalidDigit,
+        3..5
+    );
+    span!(
+        overflow,
+        NumberParser::new(si_number::<u8>),
+        "1k",
+        ErrorKind::PosOverflow,
+        0..2
+    );
+    span!(
+        empty,
+        NumberParser::<u8>::decimal(),
+        "",
+        ErrorKind::Empty,
+        0..0
+    );
+    span!(
+        separator,
+        NumberParser::<u32>::decimal().reject_separators(),
+        "1_000",
+        ErrorKind::Separator,
+        1..2
+    );
+    span!(
+        misplaced_separator,
+        NumberParser::<u32>::decimal().separators(&['_'], clap_num::Grouping::Anywhere),
+        "1__0",
+        ErrorKind::Separator,
+        1..2
+    );
+    span!(
+        stripped_separator,
+        NumberParser::<u8>::decimal().separators(&['_'], clap_num::Grouping::Anywhere),
+        "2_5_6",
+        ErrorKind::PosOverflow,
+        0..5
+    );
+    span!(
+        validator,
+        NumberParser::<u8>::decimal().validate(|&v| v % 2 == 0, "must be even"),
+        "3",
+        ErrorKind::Other,
+        0..1
+    );
+
+    #[test]
+    fn unknown_suffix() {
+        const UNITS: [(&str, u128); 1] = [("Ki", 1 << 10)];
+        let input = "4Mi";
+        let err = ParseError::new(input, prefixed_number::<u32, _>(input, &UNITS).unwrap_err());
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(&input[err.span()], "Mi");
+    }
+
+    span!(
+        si_unit_digit,
+        NumberParser::<u32>::si(SiParser::new().unit("Hz")),
+        "10kqHz",
+        ErrorKind::InvalidDigit,
+        3..4
+    );
+    span!(
+        si_unit,
+        NumberParser::<u32>::si(SiParser::new().unit("Hz")),
+        "10kB",
+        ErrorKind::Other,
+        3..4
+    );
+
+    // the span only depends on the value and the message
+    #[test]
+    fn new_after_other_parse() {
+        let input = "1.5x";
+        let message = si_number::<u32>(input).unwrap_err();
+        let err = ParseError::new(input, message.as_str());
+
+        let copy = String::from(input);
+        assert!(si_number::<u32>(&copy).is_err());
+        assert_eq!(ParseError::new(input, message), err);
+    }
+
+    #[test]
+    fn radix_prefix() {
+        let input = "0x10";
+        let err = ParseError::new(input, number_range(input, 0u8, 100).unwrap_err());
+        assert_eq!(&input[err.span()], "0x");
+    }
+
+    #[test]
+    fn custom_message() {
+        let parser = NumberParser::<u8>::decimal().message(ErrorKind::InvalidDigit, "not a number");
+        let err = parser.try_parse("1x").unwrap_err();
+        assert_eq!(err.to_string(), "not a number");
+        assert_eq!(err.span(), 1..2);
+        assert_eq!(parser.parse("1x"), Err(String::from("not a number")));
+    }
+
+    #[test]
+    fn std_error() {
+        fn parse(s: &str) -> Result<u8, Box<dyn std::error::Error>> {
+            Ok(NumberParser::decimal().try_parse(s)?)
+        }
+        let err = parse("256").unwrap_err();
+        assert_eq!(err.to_string(), "number too large to fit in target type");
+    }
+
+    #[test]
+    fn read_from_file() {
+        let path = std::env::temp_dir().join(format!("clap-num-{}-span", std::process::id()));
+        std::fs::write(&path, "12a4").unwrap();
+        let arg = format!("@{}", path.display());
+        let err = NumberParser::<u32>::decimal()
+            .allow_file()
+            .try_parse(&arg)
+            .unwrap_err();
+        assert_eq!(err.span(), 0..arg.len());
+        std::fs::remove_file(path).unwrap();
+    }
+}
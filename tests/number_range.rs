@@ -29,26 +29,122 @@ mod basic {
     pos!(neg, "-1", -10i8, 10i8, -1);
     pos!(min_limit, "-5", -5i8, -5i8, -5i8);
     pos!(max_limit, "65535", 0, u16::MAX, u16::MAX);
+    pos!(underscores, "1_000", 0u16, 1_000u16, 1_000u16);
+    pos!(neg_underscores, "-1_000", -1_000i16, 0i16, -1_000i16);
 
     neg!(decimal, "1.1", -10i8, 10i8, "invalid digit found in string");
     neg!(min, "-1", 0i8, 0i8, "less than minimum of 0");
+    neg!(
+        negative_unsigned,
+        "-1",
+        0u8,
+        10u8,
+        "value cannot be negative"
+    );
+    neg!(
+        negative_unsigned_large,
+        "-300",
+        0u8,
+        255u8,
+        "value cannot be negative"
+    );
+    neg!(
+        double_negative_unsigned,
+        "--1",
+        0u8,
+        10u8,
+        "invalid digit found in string"
+    );
+    neg!(
+        double_negative_signed,
+        "--1",
+        -10i8,
+        10i8,
+        "invalid digit found in string"
+    );
+    neg!(
+        negative_overflow,
+        "-129",
+        -10i8,
+        10i8,
+        "number too small to fit in target type"
+    );
     neg!(max, "1", 0i8, 0i8, "exceeds maximum of 0");
+    neg!(
+        hex_prefix,
+        "0x10",
+        0u8,
+        10u8,
+        "hexadecimal prefix '0x' is not accepted, expected decimal"
+    );
+    neg!(
+        negative_hex_prefix,
+        "-0x1",
+        -10i8,
+        10i8,
+        "hexadecimal prefix '0x' is not accepted, expected decimal"
+    );
     neg!(
         overflow,
         "256",
         0,
-        std::u8::MAX,
+        u8::MAX,
         "number too large to fit in target type"
     );
     neg!(nan, "nan", 0, 0, "invalid digit found in string");
 
     #[test]
     #[should_panic]
+    #[cfg(debug_assertions)]
     fn min_max_debug_assert() {
         let _ = number_range("", 2, 1);
     }
 }
 
+// bounds are only formatted when a value is out of range
+#[cfg(test)]
+mod lazy_bounds {
+    use super::*;
+    use std::fmt;
+    use std::num::ParseIntError;
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static FORMATTED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Counted(u8);
+
+    impl fmt::Display for Counted {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            FORMATTED.fetch_add(1, Ordering::SeqCst);
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl FromStr for Counted {
+        type Err = ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(Counted)
+        }
+    }
+
+    #[test]
+    fn formatted_on_error_only() {
+        for _ in 0..100 {
+            assert_eq!(number_range("5", Counted(0), Counted(10)), Ok(Counted(5)));
+        }
+        assert_eq!(FORMATTED.load(Ordering::SeqCst), 0);
+
+        assert_eq!(
+            number_range("11", Counted(0), Counted(10)),
+            Err(String::from("exceeds maximum of 10"))
+        );
+        assert_eq!(FORMATTED.load(Ordering::SeqCst), 1);
+    }
+}
+
 // integration tests with clap
 #[cfg(test)]
 mod integration {
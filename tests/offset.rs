@@ -0,0 +1,89 @@
+use clap_num::{offset, relative_offset, Offset};
+
+#[cfg(test)]
+mod relative {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(relative_offset($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<i8, String> = relative_offset($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plus_hex, "+0x10", 16i32);
+    pos!(minus_hex, "-0X10", -16i32);
+    pos!(plus_dec, "+4", 4i32);
+    pos!(minus_dec, "-4", -4i32);
+    pos!(zero, "+0", 0i32);
+    pos!(underscores, "-0x1_00", -256i32);
+    pos!(min, "-0x80", i8::MIN);
+    pos!(max, "+127", i8::MAX);
+
+    neg!(no_sign, "4", "expected offset to start with '+' or '-'");
+    neg!(empty, "", "expected offset to start with '+' or '-'");
+    neg!(sign_only, "+", "cannot parse integer from empty string");
+    neg!(double_sign, "--4", "invalid digit found in string");
+    neg!(mixed_sign, "+-5", "invalid digit found in string");
+    neg!(mixed_sign_hex, "-+0x5", "invalid digit found in string");
+    neg!(overflow, "+128", "number too large to fit in target type");
+    neg!(underflow, "-0x81", "number too small to fit in target type");
+    neg!(invalid, "+0xG", "invalid digit found in string");
+}
+
+#[cfg(test)]
+mod absolute_or_relative {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(offset::<i64>($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    pos!(absolute_hex, "0x10", Offset::Absolute(16));
+    pos!(absolute_dec, "10", Offset::Absolute(10));
+    pos!(relative_plus, "+0x10", Offset::Relative(16));
+    pos!(relative_minus, "-10", Offset::Relative(-10));
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            offset::<i64>("0x"),
+            Err(String::from("cannot parse integer from empty string"))
+        );
+    }
+
+    #[test]
+    fn mixed_sign() {
+        assert_eq!(
+            offset::<i64>("+-5"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        assert_eq!(Offset::Absolute(5i8).resolve(100), Some(5));
+        assert_eq!(Offset::Relative(-5i8).resolve(100), Some(95));
+        assert_eq!(Offset::Relative(100i8).resolve(100), None);
+    }
+}
@@ -0,0 +1,102 @@
+use clap_num::{
+    maybe_hex_at_least, maybe_hex_at_most, number_at_least, number_at_most, si_number_at_least,
+    si_number_at_most,
+};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $BOUND:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL, $BOUND), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $BOUND:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL, $BOUND), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(at_least_min, number_at_least, "1", 1u32, 1);
+    pos!(at_least_max, number_at_least, "4294967295", 1u32, u32::MAX);
+    pos!(at_most_max, number_at_most, "10", 10i8, 10);
+    pos!(at_most_min, number_at_most, "-128", 10i8, i8::MIN);
+    pos!(hex_at_least, maybe_hex_at_least, "0x100", 0x100u32, 0x100);
+    pos!(hex_at_most, maybe_hex_at_most, "0", 0xFFu16, 0);
+    pos!(si_at_least, si_number_at_least, "1k", 1_000u64, 1_000);
+    pos!(si_at_most, si_number_at_most, "-1M", 0i32, -1_000_000);
+
+    neg!(
+        at_least_below,
+        number_at_least,
+        "0",
+        1u32,
+        "less than minimum of 1"
+    );
+    neg!(
+        at_least_overflow,
+        number_at_least,
+        "4294967296",
+        1u32,
+        "number too large to fit in target type"
+    );
+    neg!(
+        at_most_above,
+        number_at_most,
+        "11",
+        10i8,
+        "exceeds maximum of 10"
+    );
+    neg!(
+        at_most_underflow,
+        number_at_most,
+        "-129",
+        10i8,
+        "number too small to fit in target type"
+    );
+    neg!(
+        hex_at_least_below,
+        maybe_hex_at_least,
+        "0xFF",
+        0x100u32,
+        "less than minimum of 0x100"
+    );
+    neg!(
+        hex_at_most_above,
+        maybe_hex_at_most,
+        "0x100",
+        0xFFu16,
+        "exceeds maximum of 0xFF"
+    );
+    neg!(
+        si_at_least_below,
+        si_number_at_least,
+        "999",
+        1_000u64,
+        "less than minimum of 1000"
+    );
+    neg!(
+        si_at_most_above,
+        si_number_at_most,
+        "1k",
+        0i32,
+        "exceeds maximum of 0"
+    );
+    neg!(
+        invalid,
+        number_at_least,
+        "x",
+        0u8,
+        "invalid digit found in string"
+    );
+}
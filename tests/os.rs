@@ -0,0 +1,113 @@
+use clap::Parser;
+use clap_num::{
+    maybe_bin_os, maybe_hex_os, maybe_oct_os, number_range_os, parse_os, si_number_os, NumberParser,
+};
+use std::ffi::{OsStr, OsString};
+
+#[cfg(unix)]
+fn invalid(s: &[u8]) -> OsString {
+    use std::os::unix::ffi::OsStrExt;
+    OsStr::from_bytes(s).to_os_string()
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(number_range_os(OsStr::new("-5"), -10, 10), Ok(-5));
+        assert_eq!(maybe_hex_os::<u16>(OsStr::new("0xFF")), Ok(0xFF));
+        assert_eq!(maybe_bin_os::<u8>(OsStr::new("0b101")), Ok(5));
+        assert_eq!(maybe_oct_os::<u16>(OsStr::new("0o755")), Ok(0o755));
+        assert_eq!(si_number_os::<u32>(OsStr::new("4k7")), Ok(4_700));
+        assert_eq!(
+            NumberParser::<u8>::decimal()
+                .max(99)
+                .parse_os(OsStr::new("42")),
+            Ok(42)
+        );
+    }
+
+    #[test]
+    fn parser_errors() {
+        assert_eq!(
+            number_range_os(OsStr::new("11"), -10, 10),
+            Err(String::from("exceeds maximum of 10"))
+        );
+        assert_eq!(
+            maybe_hex_os::<u8>(OsStr::new("0xZZ")),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn not_utf8() {
+        let arg = invalid(b"0x1\xFF");
+        let msg = String::from("argument was not valid UTF-8: '0x1\u{FFFD}'");
+        assert_eq!(maybe_hex_os::<u32>(&arg), Err(msg.clone()));
+        assert_eq!(maybe_bin_os::<u32>(&arg), Err(msg.clone()));
+        assert_eq!(maybe_oct_os::<u32>(&arg), Err(msg.clone()));
+        assert_eq!(si_number_os::<u32>(&arg), Err(msg.clone()));
+        assert_eq!(number_range_os(&arg, 0, 1), Err(msg.clone()));
+        assert_eq!(
+            NumberParser::<u32>::decimal().parse_os(&arg),
+            Err(msg.clone())
+        );
+        assert_eq!(parse_os(&arg, |_| Ok(())), Err(msg));
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+    use clap::builder::TypedValueParser;
+
+    #[derive(Clone)]
+    struct Address;
+
+    impl TypedValueParser for Address {
+        type Value = u32;
+
+        fn parse_ref(
+            &self,
+            cmd: &clap::Command,
+            _arg: Option<&clap::Arg>,
+            value: &OsStr,
+        ) -> Result<u32, clap::Error> {
+            maybe_hex_os(value).map_err(|e| {
+                clap::Error::raw(clap::error::ErrorKind::InvalidValue, e).with_cmd(cmd)
+            })
+        }
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=Address)]
+        address: u32,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--address", "0x10"]);
+        assert_eq!(args.address, 0x10);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn neg() {
+        let args = Args::try_parse_from([
+            OsString::new(),
+            OsString::from("--address"),
+            invalid(b"0x1\xFF"),
+        ]);
+        match args {
+            Err(e) => assert!(e
+                .to_string()
+                .contains("argument was not valid UTF-8: '0x1\u{FFFD}'")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use clap::Parser;
+use clap_num::{maybe_hex, mem_size, page_aligned_to};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $PAGE:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(page_aligned_to($VAL, $PAGE, maybe_hex), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $PAGE:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u64, String> = page_aligned_to($VAL, $PAGE, maybe_hex);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(zero, "0", 4096, 0u64);
+    pos!(one_page, "0x1000", 4096, 0x1000u64);
+    pos!(decimal, "8192", 4096, 8192u64);
+    pos!(huge, "0x7F0000200000", 2 << 20, 0x7F00_0020_0000u64);
+    pos!(byte_pages, "3", 1, 3u64);
+    pos!(narrow_type, "0", 4096, 0u8);
+
+    neg!(
+        unaligned,
+        "0x1001",
+        4096,
+        "not a multiple of the page size of 4KiB"
+    );
+    neg!(
+        huge_unaligned,
+        "0x1000",
+        2 << 20,
+        "not a multiple of the page size of 2MiB"
+    );
+    neg!(
+        gigantic_unaligned,
+        "0x200000",
+        1 << 30,
+        "not a multiple of the page size of 1GiB"
+    );
+    neg!(
+        parse_error,
+        "0x10000000000000000",
+        4096,
+        "number too large to fit in target type"
+    );
+
+    #[test]
+    fn narrow_type_unaligned() {
+        let val: Result<u8, String> = page_aligned_to("255", 4096, maybe_hex);
+        assert_eq!(
+            val,
+            Err(String::from("not a multiple of the page size of 4KiB"))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_power_of_two() {
+        let _: Result<u64, String> = page_aligned_to("0", 1000, maybe_hex);
+    }
+}
+
+// page size of the system
+#[cfg(feature = "page-size")]
+mod system {
+    use super::*;
+    use clap_num::{page_aligned, page_size};
+
+    #[test]
+    fn power_of_two() {
+        assert!(page_size().is_power_of_two());
+    }
+
+    #[test]
+    fn aligned() {
+        let size = page_size();
+        assert_eq!(
+            page_aligned(&(size * 3).to_string(), maybe_hex),
+            Ok(size * 3)
+        );
+    }
+
+    #[test]
+    fn unaligned() {
+        let val: Result<u64, String> = page_aligned("1", maybe_hex);
+        assert!(val
+            .unwrap_err()
+            .starts_with("not a multiple of the page size of "));
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn length(s: &str) -> Result<u64, String> {
+        page_aligned_to(s, 16 << 10, mem_size)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=length)]
+        length: u64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--length", "64KiB"]);
+        assert_eq!(args.length, 64 << 10);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--length", "4KiB"]);
+        match args {
+            Err(e) => assert!(e
+                .to_string()
+                .contains("not a multiple of the page size of 16KiB")),
+            _ => unreachable!(),
+        }
+    }
+}
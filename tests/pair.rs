@@ -0,0 +1,100 @@
+use clap::Parser;
+use clap_num::{maybe_hex, number_range, pair, si_number};
+
+fn threshold(s: &str) -> Result<(i32, i32), String> {
+    pair(s, |v| number_range(v, -100, 100))
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(threshold($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(threshold($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(simple, "10:20", (10, 20));
+    pos!(equal, "5:5", (5, 5));
+    pos!(negative, "-10:-5", (-10, -5));
+    pos!(limits, "-100:100", (-100, 100));
+
+    neg!(no_colon, "10", "expected pair in the format of 10:20");
+    neg!(dot_dot, "10..20", "expected pair in the format of 10:20");
+    neg!(empty, "", "expected pair in the format of 10:20");
+    neg!(empty_low, ":20", "cannot parse integer from empty string");
+    neg!(empty_high, "10:", "cannot parse integer from empty string");
+    neg!(extra, "1:2:3", "invalid digit found in string");
+    neg!(whitespace, "10: 20", "invalid digit found in string");
+    neg!(
+        reversed,
+        "20:10",
+        "low value of 20 exceeds high value of 10"
+    );
+    neg!(above, "0:101", "exceeds maximum of 100");
+    neg!(below, "-101:0", "less than minimum of -100");
+}
+
+#[cfg(test)]
+mod other_parsers {
+    use super::*;
+
+    #[test]
+    fn hex() {
+        assert_eq!(pair::<u8, _>("0x10:0xF0", maybe_hex), Ok((0x10, 0xF0)));
+        assert_eq!(pair::<u8, _>("0x10:255", maybe_hex), Ok((0x10, 0xFF)));
+    }
+
+    #[test]
+    fn si() {
+        assert_eq!(pair::<u32, _>("1k:4k7", si_number), Ok((1_000, 4_700)));
+        assert_eq!(
+            pair::<u32, _>("2k:1k", si_number),
+            Err(String::from("low value of 2000 exceeds high value of 1000"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=threshold, allow_hyphen_values=true)]
+        threshold: (i32, i32),
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--threshold", "-10:20"]);
+        assert_eq!(args.threshold, (-10, 20));
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--threshold", "20:10"]);
+        match args {
+            Err(e) => assert!(e
+                .to_string()
+                .contains("low value of 20 exceeds high value of 10")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "testing")]
+
+use clap_num::testing::{check_invariants, Grammar};
+use proptest::prelude::*;
+
+// characters that are meaningful to at least one parser
+const SYNTAX: &str =
+    "[0-9a-fA-FxXbBoOkKMGTPEZYmµunpiIsShHzdB_.+\\-×±%/@,:;=#$\\[\\]() \u{e9}\u{1F600}]{0,24}";
+
+fn check_all(s: &str) {
+    for grammar in Grammar::ALL {
+        check_invariants(grammar, s);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(4096))]
+
+    #[test]
+    fn any_string(s in any::<String>()) {
+        check_all(&s);
+    }
+
+    #[test]
+    fn syntax(s in SYNTAX) {
+        check_all(&s);
+    }
+}
+
+#[test]
+fn edge_cases() {
+    for s in [
+        "",
+        " ",
+        "-",
+        "+",
+        "_",
+        ".",
+        "0x",
+        "0b",
+        "0o",
+        "-0x",
+        "+0x_",
+        "1e",
+        "1e-",
+        "1e999999999999",
+        "1e-999999999999",
+        "1.k",
+        ".k",
+        "k",
+        "1k.",
+        "1k5k",
+        "µ",
+        "1µ",
+        "1 µ",
+        "1\u{e9}",
+        "\u{e9}1",
+        "1.\u{1F600}",
+        "1Ki",
+        "1KiB",
+        "4KiB",
+        "1/",
+        "/1",
+        "1/0",
+        "0/0",
+        "[",
+        "[]",
+        "[,]",
+        "(,)",
+        "[\u{e9},]",
+        "±",
+        "1±",
+        "±1",
+        "1+/-",
+        "1±%",
+        "170141183460469231731687303715884105727±170141183460469231731687303715884105727",
+        "4294967295±4294967295",
+        "2147483647±1",
+        "250±10",
+        "5±10",
+        "0-9999999999",
+        "=",
+        "a=",
+        "=1",
+        "1-",
+        "-1",
+        "1--2",
+        "3-1",
+        "0-99999999999999999999",
+        ",",
+        ",,",
+        "1,",
+        "::",
+        "1.2.3.4.5",
+        "255.255.255.255",
+        "/33",
+        "#",
+        "#fff\u{e9}",
+        "00000000-0000-0000-0000-00000000000\u{e9}",
+        "{00000000-0000-0000-0000-000000000000}",
+        "1h\u{e9}",
+        "999999999999999999999h",
+        "1.5e308",
+        "-1.5e308x",
+        "1e400dB",
+        "-1e400dB",
+        "NaN",
+        "inf",
+        "-inf",
+        "infdB",
+        "NaN%",
+    ] {
+        check_all(s);
+    }
+}
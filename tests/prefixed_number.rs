@@ -0,0 +1,257 @@
+use clap::Parser;
+use clap_num::{prefixed_number, prefixed_number_range, PrefixTable, UnitTable};
+
+const BINARY: [(&str, u128); 3] = [("Ki", 1 << 10), ("Mi", 1 << 20), ("Gi", 1 << 30)];
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(prefixed_number($VAL, &BINARY), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ty, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<$TYPE, String> = prefixed_number($VAL, &BINARY);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plain, "100", 100u32);
+    pos!(kibi, "4Ki", 4_096u32);
+    pos!(mebi, "2Mi", 2_097_152u32);
+    pos!(fraction, "1.5Ki", 1_536u32);
+    pos!(fraction_exact, "0.25Mi", 262_144u32);
+    pos!(trailing_zeros, "1.500Ki", 1_536u32);
+    pos!(underscores, "1_000Ki", 1_024_000u32);
+    pos!(negative, "-2Ki", -2_048i32);
+    pos!(plus, "+2Ki", 2_048i32);
+    pos!(zero, "0Gi", 0u8);
+    pos!(negative_zero, "-0Ki", 0u8);
+    pos!(min, "-128", i8::MIN);
+    pos!(min_prefixed, "-8Gi", i64::from(i32::MIN) * 4);
+    pos!(max, "4Gi", 4u64 << 30);
+
+    neg!(
+        unknown,
+        "4K",
+        u32,
+        "unknown suffix 'K', expected one of Ki, Mi, Gi"
+    );
+    neg!(
+        case_sensitive,
+        "4ki",
+        u32,
+        "unknown suffix 'ki', expected one of Ki, Mi, Gi"
+    );
+    neg!(suffix_only, "Ki", u32, "no value found before suffix 'Ki'");
+    neg!(empty, "", u32, "cannot parse integer from empty string");
+    neg!(not_an_integer, "1.0001Ki", u32, "not an integer");
+    neg!(not_an_integer_plain, "1.5", u32, "not an integer");
+    neg!(invalid, "1.2.3Ki", u32, "invalid digit found in string");
+    neg!(sign_only, "-Ki", i32, "invalid digit found in string");
+    neg!(negative_unsigned, "-1Ki", u32, "value cannot be negative");
+    neg!(
+        overflow,
+        "256Ki",
+        u16,
+        "number too large to fit in target type"
+    );
+    neg!(
+        underflow,
+        "-129",
+        i8,
+        "number too small to fit in target type"
+    );
+    neg!(
+        underflow_prefixed,
+        "-1Ki",
+        i8,
+        "number too small to fit in target type"
+    );
+}
+
+#[cfg(test)]
+mod table {
+    use super::*;
+
+    struct Storage;
+
+    impl PrefixTable for Storage {
+        fn prefixes(&self) -> &[(&str, u128)] {
+            &[("s", 512), ("p", 4_096)]
+        }
+    }
+
+    #[test]
+    fn custom() {
+        assert_eq!(prefixed_number("8p", &Storage), Ok(32_768u64));
+        assert_eq!(Storage.multiplier("s"), Some(512));
+        assert_eq!(Storage.multiplier("x"), None);
+    }
+
+    #[test]
+    fn slice() {
+        let table: &[(&str, u128)] = &BINARY;
+        assert_eq!(prefixed_number("1Ki", table), Ok(1_024u16));
+    }
+
+    #[test]
+    fn empty_table() {
+        let table: [(&str, u128); 0] = [];
+        assert_eq!(prefixed_number("10", &table), Ok(10u8));
+        assert_eq!(
+            prefixed_number::<u8, _>("10k", &table),
+            Err(String::from("unknown suffix 'k', expected one of "))
+        );
+    }
+}
+
+#[cfg(test)]
+mod units {
+    use super::*;
+
+    fn storage() -> UnitTable {
+        UnitTable::new()
+            .unit("s", 512)
+            .unit("sect", 512)
+            .unit("p", 4_096)
+    }
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(prefixed_number($VAL, &storage()), Ok($RESULT));
+            }
+        };
+    }
+
+    pos!(bytes, "100", 100u64);
+    pos!(sectors, "3s", 1_536u64);
+    pos!(sectors_long, "3sect", 1_536u64);
+    pos!(pages, "8p", 32_768u64);
+    pos!(half_page, "0.5p", 2_048u64);
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            prefixed_number::<u64, _>("1sec", &storage()),
+            Err(String::from(
+                "unknown suffix 'sec', expected one of s, sect, p"
+            ))
+        );
+    }
+
+    #[test]
+    fn replace() {
+        let units = storage().unit("p", 2 << 20);
+        assert_eq!(units.multiplier("p"), Some(2 << 20));
+        assert_eq!(units.prefixes().len(), 3);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(UnitTable::new().prefixes(), []);
+        assert_eq!(UnitTable::new(), UnitTable::default());
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(
+            prefixed_number_range::<u64, _>("9p", &storage(), 0, 32_768),
+            Err(String::from("exceeds maximum of 8p"))
+        );
+        assert_eq!(
+            prefixed_number_range::<u64, _>("1s", &storage(), 1_024, 32_768),
+            Err(String::from("less than minimum of 2s"))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn invalid_suffix_debug_assert() {
+        let _ = UnitTable::new().unit("4k", 4_096);
+    }
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<i64, String> = prefixed_number_range($VAL, &BINARY, $MIN, $MAX);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    #[test]
+    fn in_range() {
+        assert_eq!(
+            prefixed_number_range("16Mi", &BINARY, 4 << 10, 16 << 20),
+            Ok(16u32 << 20)
+        );
+    }
+
+    neg!(max, "32Mi", 0, 16 << 20, "exceeds maximum of 16Mi");
+    neg!(min, "1Ki", 4 << 10, 16 << 20, "less than minimum of 4Ki");
+    neg!(largest, "2Gi", 0, 1 << 30, "exceeds maximum of 1Gi");
+    neg!(inexact, "2Ki", 0, 1_000, "exceeds maximum of 1000");
+    neg!(
+        mixed,
+        "2Mi",
+        0,
+        (1 << 20) + (1 << 10),
+        "exceeds maximum of 1025Ki"
+    );
+    neg!(negative, "-2Ki", -(1 << 10), 0, "less than minimum of -1Ki");
+    neg!(zero, "1", 0, 0, "exceeds maximum of 0");
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn buffer(s: &str) -> Result<u64, String> {
+        prefixed_number_range(s, &BINARY, 4 << 10, 1 << 30)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=buffer)]
+        buffer: u64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--buffer", "64Ki"]);
+        assert_eq!(args.buffer, 65_536);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--buffer", "2Gi"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 1Gi")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use clap_num::presets::*;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(pwm, pwm_duty_cycle, "50", 50);
+    pos!(pwm_percent, pwm_duty_cycle, "100%", 100);
+    pos!(percent_zero, percent, "0%", 0);
+    pos!(probability_zero, probability, "0", 0.0);
+    pos!(probability_one, probability, "1", 1.0);
+    pos!(probability_fraction, probability, "0.25", 0.25);
+    pos!(probability_percent, probability, "15%", 0.15);
+    pos!(probability_full_percent, probability, "100%", 1.0);
+    pos!(dac_8, dac_8bit, "0xFF", 0xFF);
+    pos!(dac_10, dac_10bit, "1023", 0x3FF);
+    pos!(dac_12, dac_12bit, "0xFFF", 0xFFF);
+    pos!(dac_16, dac_16bit, "0xFFFF", 0xFFFF);
+    pos!(rgb, rgb_channel, "0x80", 0x80);
+    pos!(ascii_a, ascii, "0x41", b'A');
+    pos!(midi_max, midi, "127", 127);
+    pos!(port_min, port, "1", 1);
+    pos!(port_max, port, "65535", 65535);
+
+    neg!(pwm_above, pwm_duty_cycle, "101%", "exceeds maximum of 100");
+    neg!(
+        percent_twice,
+        percent,
+        "1%%",
+        "invalid digit found in string"
+    );
+    neg!(
+        probability_above,
+        probability,
+        "1.5",
+        "exceeds maximum of 1"
+    );
+    neg!(
+        probability_percent_above,
+        probability,
+        "150%",
+        "exceeds maximum of 100"
+    );
+    neg!(
+        probability_below,
+        probability,
+        "-0.1",
+        "less than minimum of 0"
+    );
+    neg!(
+        probability_nan,
+        probability,
+        "NaN",
+        "probability is not a number"
+    );
+    neg!(probability_inf, probability, "inf", "exceeds maximum of 1");
+    neg!(dac_10_above, dac_10bit, "0x400", "exceeds maximum of 0x3FF");
+    neg!(dac_12_above, dac_12bit, "4096", "exceeds maximum of 4095");
+    neg!(
+        dac_16_above,
+        dac_16bit,
+        "0x10000",
+        "number too large to fit in target type"
+    );
+    neg!(
+        rgb_above,
+        rgb_channel,
+        "256",
+        "number too large to fit in target type"
+    );
+    neg!(ascii_above, ascii, "0x80", "exceeds maximum of 0x7F");
+    neg!(midi_above, midi, "128", "exceeds maximum of 127");
+    neg!(port_zero, port, "0", "less than minimum of 1");
+}
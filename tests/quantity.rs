@@ -0,0 +1,137 @@
+use clap::Parser;
+use clap_num::QuantityParser;
+
+fn length() -> QuantityParser {
+    QuantityParser::new("m")
+        .unit("cm", 0.01)
+        .unit("mm", 0.001)
+        .unit("in", 0.0254)
+        .unit("ft", 0.3048)
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path, with a tolerance for the conversion
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val = length().parse($VAL).unwrap();
+                assert!((val - $RESULT).abs() < 1e-12, "{val} != {}", $RESULT);
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(length().parse($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(base, "2m", 2.0);
+    pos!(no_unit, "2", 2.0);
+    pos!(centimetres, "12.5cm", 0.125);
+    pos!(inches, "3in", 0.0762);
+    pos!(feet, "10ft", 3.048);
+    pos!(negative, "-5mm", -0.005);
+    pos!(underscores, "1_000mm", 1.0);
+
+    neg!(
+        unknown,
+        "3yd",
+        "unknown unit 'yd', expected one of m, cm, mm, in, ft"
+    );
+    neg!(
+        case_sensitive,
+        "3CM",
+        "unknown unit 'CM', expected one of m, cm, mm, in, ft"
+    );
+    neg!(
+        whitespace,
+        "3 cm",
+        "unknown unit ' cm', expected one of m, cm, mm, in, ft"
+    );
+    neg!(no_value, "cm", "no value found before unit 'cm'");
+    neg!(empty, "", "cannot parse float from empty string");
+    neg!(invalid, "1.2.3m", "invalid float literal");
+    neg!(
+        overflow,
+        &format!("{}ft", "9".repeat(400)),
+        "number too large to fit in target type"
+    );
+
+    #[test]
+    fn replace_unit() {
+        let parser = length().unit("in", 0.025);
+        assert_eq!(parser.parse("2in"), Ok(0.05));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_factor() {
+        let _ = length().unit("pt", 0.0);
+    }
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(length().parse_range("50cm", 0.0, 1.0), Ok(0.5));
+    }
+
+    #[test]
+    fn above_max() {
+        assert_eq!(
+            length().parse_range("4ft", 0.0, 1.0),
+            Err(String::from("exceeds maximum of 1m"))
+        );
+    }
+
+    #[test]
+    fn below_min() {
+        assert_eq!(
+            length().parse_range("1mm", 0.01, 1.0),
+            Err(String::from("less than minimum of 0.01m"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn height(s: &str) -> Result<f64, String> {
+        length().parse_range(s, 0.0, 3.0)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=height)]
+        height: f64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--height", "180cm"]);
+        assert_eq!(args.height, 1.8);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--height", "12ft"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 3m")),
+            _ => unreachable!(),
+        }
+    }
+}
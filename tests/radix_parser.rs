@@ -0,0 +1,138 @@
+use clap::Parser;
+use clap_num::RadixParser;
+
+fn parser() -> RadixParser {
+    RadixParser::new()
+        .prefix("$", 16)
+        .prefix("%", 2)
+        .prefix("0y", 32)
+        .prefix("@", 8)
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(parser().parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u8, String> = parser().parse($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(hex, "$FF", 255u8);
+    pos!(binary, "%1010", 10u8);
+    pos!(base32, "0yV", 31u8);
+    pos!(base32_upper, "0YV", 31u8);
+    pos!(octal, "@17", 15u8);
+    pos!(decimal, "42", 42u8);
+    pos!(underscores, "%1010_1010", 0xAAu8);
+    pos!(large, "$FFFF_FFFF_FFFF_FFFF", u64::MAX);
+
+    neg!(
+        overflow,
+        "%1_0000_0000",
+        "number too large to fit in target type"
+    );
+    neg!(invalid_digit, "%102", "invalid digit found in string");
+    neg!(empty_digits, "%", "cannot parse integer from empty string");
+    neg!(unknown_prefix, "0x10", "invalid digit found in string");
+    neg!(negative, "-%1", "value cannot be negative");
+
+    #[test]
+    fn decimal_only() {
+        let parser = RadixParser::new();
+        assert_eq!(parser.parse("255"), Ok(255u8));
+        assert_eq!(
+            parser.parse::<u8>("$FF"),
+            Err(String::from("invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn replace_radix() {
+        let parser = RadixParser::new().prefix("#", 16).prefix("#", 8);
+        assert_eq!(parser.parse("#17"), Ok(15u8));
+    }
+
+    #[test]
+    fn longest_prefix() {
+        let parser = RadixParser::new().prefix("0", 8).prefix("0x", 16);
+        assert_eq!(parser.parse("0x17"), Ok(0x17u8));
+        assert_eq!(parser.parse("017"), Ok(0o17u8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_radix() {
+        let _ = RadixParser::new().prefix("z", 37);
+    }
+}
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<u16, String> = parser().parse_range($VAL, $MIN, $MAX);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    #[test]
+    fn in_range() {
+        assert_eq!(parser().parse_range("%101", 0u16, 0x200), Ok(5));
+    }
+
+    neg!(hex_max, "$201", 0x100, 0x200, "exceeds maximum of $200");
+    neg!(binary_min, "%1", 0b100, 0b111, "less than minimum of %100");
+    neg!(base32_max, "0y100", 0, 1023, "exceeds maximum of 0yVV");
+    neg!(decimal_max, "513", 0, 0x200, "exceeds maximum of 512");
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn word(s: &str) -> Result<u16, String> {
+        parser().parse_range(s, 0, 0xFFF)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=word)]
+        word: u16,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--word", "%1010"]);
+        assert_eq!(args.word, 0b1010);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--word", "$1000"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of $FFF")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use clap::Parser;
+use clap_num::raw;
+use core::num::{IntErrorKind, ParseIntError};
+
+fn kind<T>(result: Result<T, ParseIntError>) -> IntErrorKind {
+    *result.err().unwrap().kind()
+}
+
+#[cfg(test)]
+mod number {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(raw::number::<u8>("255"), Ok(255));
+        assert_eq!(raw::number::<i32>("-1_000"), Ok(-1_000));
+        assert_eq!(raw::number::<f64>("1.5"), Ok(1.5));
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(kind(raw::number::<u8>("")), IntErrorKind::Empty);
+        assert_eq!(kind(raw::number::<u8>("1x")), IntErrorKind::InvalidDigit);
+        assert_eq!(kind(raw::number::<u8>("256")), IntErrorKind::PosOverflow);
+        assert_eq!(kind(raw::number::<i8>("-129")), IntErrorKind::NegOverflow);
+        // not rewritten to a friendlier message
+        assert_eq!(kind(raw::number::<u8>("-1")), IntErrorKind::InvalidDigit);
+    }
+}
+
+#[cfg(test)]
+mod number_range {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(raw::number_range::<u8>("99", 0, 99), Ok(99));
+        assert_eq!(raw::number_range::<i8>("-5", -5, 5), Ok(-5));
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(
+            raw::number_range::<u8>("100", 0, 99),
+            Err(raw::Error::AboveMaximum(99))
+        );
+        assert_eq!(
+            raw::number_range::<i8>("-6", -5, 5),
+            Err(raw::Error::BelowMinimum(-5))
+        );
+        match raw::number_range::<u8>("1000", 0, 99) {
+            Err(raw::Error::Parse(e)) => assert_eq!(e.kind(), &IntErrorKind::PosOverflow),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn display() {
+        let err = raw::number_range::<u8>("100", 0, 99).unwrap_err();
+        assert_eq!(err.to_string(), "exceeds maximum of 99");
+        let err = raw::number_range::<u8>("1y", 0, 99).unwrap_err();
+        assert_eq!(err.to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn source() {
+        use std::error::Error;
+
+        let err = raw::number_range::<u8>("1y", 0, 99).unwrap_err();
+        assert!(err.source().is_some());
+        let err = raw::number_range::<u8>("100", 0, 99).unwrap_err();
+        assert!(err.source().is_none());
+    }
+}
+
+#[cfg(test)]
+mod radix {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        assert_eq!(raw::maybe_hex::<u32>("0xDEAD_BEEF"), Ok(0xDEAD_BEEF));
+        assert_eq!(raw::maybe_hex::<u32>("0XFF"), Ok(0xFF));
+        assert_eq!(raw::maybe_hex::<u32>("10"), Ok(10));
+        assert_eq!(raw::maybe_bin::<u8>("0b1010"), Ok(0b1010));
+        assert_eq!(raw::maybe_bin::<u8>("10"), Ok(10));
+        assert_eq!(raw::maybe_oct::<u16>("0o755"), Ok(0o755));
+        assert_eq!(raw::maybe_oct::<u16>("755"), Ok(755));
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(kind(raw::maybe_hex::<u8>("0x")), IntErrorKind::Empty);
+        assert_eq!(
+            kind(raw::maybe_hex::<u8>("0x100")),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            kind(raw::maybe_hex::<u8>("0xG")),
+            IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind(raw::maybe_bin::<u8>("0b2")),
+            IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind(raw::maybe_oct::<u8>("0o8")),
+            IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind(raw::maybe_hex::<u8>("0b1")),
+            IntErrorKind::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(
+            raw::maybe_hex_range::<u16>("0x1FF", 0x100, 0x1FF),
+            Ok(0x1FF)
+        );
+        assert_eq!(
+            raw::maybe_hex_range::<u16>("0x200", 0x100, 0x1FF),
+            Err(raw::Error::AboveMaximum(0x1FF))
+        );
+        assert_eq!(
+            raw::maybe_hex_range::<u16>("255", 0x100, 0x1FF),
+            Err(raw::Error::BelowMinimum(0x100))
+        );
+        match raw::maybe_hex_range::<u16>("0x1_0000", 0, 0x1FF) {
+            Err(raw::Error::Parse(e)) => assert_eq!(e.kind(), &IntErrorKind::PosOverflow),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod helpers {
+    use super::*;
+    use clap_num::{maybe_hex, number_range, Base, SiPrefix};
+
+    #[test]
+    fn messages() {
+        assert_eq!(
+            maybe_hex::<u8>("0x100"),
+            Err(String::from(raw::OVERFLOW_MSG))
+        );
+        assert_eq!(
+            number_range::<i8>("-129", -5, 5),
+            Err(String::from(raw::UNDERFLOW_MSG))
+        );
+        assert_eq!(
+            number_range::<u8>("1y", 0, 5),
+            Err(String::from(raw::INVALID_DIGIT_MSG))
+        );
+        assert_eq!(
+            number_range::<u8>("", 0, 5),
+            Err(String::from(raw::EMPTY_MSG))
+        );
+        assert_eq!(
+            number_range::<u8>("-1", 0, 5),
+            Err(String::from(raw::NEGATIVE_MSG))
+        );
+    }
+
+    #[test]
+    fn check_range() {
+        assert_eq!(raw::check_range(5, 0, 5), Ok(5));
+        assert_eq!(raw::check_range(0, 0, 5), Ok(0));
+        assert_eq!(
+            raw::check_range(6, 0, 5),
+            number_range::<u8>("6", 0, 5).map(u32::from)
+        );
+        assert_eq!(
+            raw::check_range(-1, 0, 5),
+            number_range::<i8>("-1", 0, 5).map(i32::from)
+        );
+    }
+
+    #[test]
+    fn strip_underscores() {
+        assert_eq!(raw::strip_underscores("1_000_000"), "1000000");
+        assert_eq!(raw::strip_underscores("1000"), "1000");
+    }
+
+    #[test]
+    fn split_sign() {
+        assert_eq!(raw::split_sign("-1"), (true, "1"));
+        assert_eq!(raw::split_sign("+1"), (false, "1"));
+        assert_eq!(raw::split_sign("1"), (false, "1"));
+        assert_eq!(raw::split_sign("--1"), (true, "-1"));
+    }
+
+    #[test]
+    fn split_radix_prefix() {
+        assert_eq!(raw::split_radix_prefix("0x1F"), (Base::Hexadecimal, "1F"));
+        assert_eq!(raw::split_radix_prefix("0B101"), (Base::Binary, "101"));
+        assert_eq!(raw::split_radix_prefix("0o17"), (Base::Octal, "17"));
+        assert_eq!(raw::split_radix_prefix("017"), (Base::Decimal, "017"));
+        assert_eq!(raw::split_radix_prefix("0x"), (Base::Hexadecimal, ""));
+        assert_eq!(raw::split_radix_prefix(""), (Base::Decimal, ""));
+    }
+
+    #[test]
+    fn split_si_symbol() {
+        assert_eq!(
+            raw::split_si_symbol("4k7"),
+            Some(("4", SiPrefix::Kilo, "7"))
+        );
+        assert_eq!(raw::split_si_symbol("4K"), Some(("4", SiPrefix::Kilo, "")));
+        assert_eq!(
+            raw::split_si_symbol("-1.5G"),
+            Some(("-1.5", SiPrefix::Giga, ""))
+        );
+        assert_eq!(raw::split_si_symbol("1Y"), Some(("1", SiPrefix::Yotta, "")));
+        assert_eq!(raw::split_si_symbol("M"), Some(("", SiPrefix::Mega, "")));
+        assert_eq!(raw::split_si_symbol("1000"), None);
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn cents(s: &str) -> Result<u8, raw::Error<ParseIntError, u8>> {
+        raw::number_range(s, 0, 99)
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=cents)]
+        cents: u8,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--cents", "99"]);
+        assert_eq!(args.cents, 99);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--cents", "100"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 99")),
+            _ => unreachable!(),
+        }
+    }
+}
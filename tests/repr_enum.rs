@@ -0,0 +1,120 @@
+use clap_num::repr_enum;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+enum Command {
+    Reset = 0x00,
+    Read = 0x03,
+    Write = 0x04,
+    Erase = 0x05,
+    Status = 0xFF,
+}
+
+impl TryFrom<u8> for Command {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(Self::Reset),
+            0x03 => Ok(Self::Read),
+            0x04 => Ok(Self::Write),
+            0x05 => Ok(Self::Erase),
+            0xFF => Ok(Self::Status),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+enum Register {
+    Id = 0x1000,
+}
+
+impl TryFrom<u16> for Register {
+    type Error = ();
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0x1000 => Ok(Self::Id),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Wide(u32);
+
+impl TryFrom<u32> for Wide {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value == 7 {
+            Ok(Self(value))
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $TYPE:ty, $REPR:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(repr_enum::<$TYPE, $REPR>($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $TYPE:ty, $REPR:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(repr_enum::<$TYPE, $REPR>($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(zero, Command, u8, "0", Command::Reset);
+    pos!(decimal, Command, u8, "3", Command::Read);
+    pos!(hex, Command, u8, "0x04", Command::Write);
+    pos!(max, Command, u8, "0xFF", Command::Status);
+    pos!(u16_repr, Register, u16, "0x1000", Register::Id);
+    pos!(u32_repr, Wide, u32, "7", Wide(7));
+
+    neg!(
+        invalid,
+        Command,
+        u8,
+        "0x10",
+        "invalid discriminant 0x10, expected one of 0x00, 0x03-0x05, 0xFF"
+    );
+    neg!(
+        u16_invalid,
+        Register,
+        u16,
+        "1",
+        "invalid discriminant 0x0001, expected one of 0x1000"
+    );
+    neg!(
+        u32_invalid,
+        Wide,
+        u32,
+        "8",
+        "invalid discriminant 0x00000008"
+    );
+    neg!(
+        overflow,
+        Command,
+        u8,
+        "0x100",
+        "number too large to fit in target type"
+    );
+    neg!(digit, Command, u8, "0xG", "invalid digit found in string");
+}
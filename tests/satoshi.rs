@@ -0,0 +1,139 @@
+use clap::Parser;
+use clap_num::{satoshi, satoshi_range};
+
+const BTC: u64 = 100_000_000;
+
+fn amount(s: &str) -> Result<u64, String> {
+    satoshi_range(s, 546, 21_000_000 * BTC)
+}
+
+fn fee(s: &str) -> Result<u64, String> {
+    satoshi_range(s, 1, BTC / 2)
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(btc, satoshi::<u64>, "0.001btc", 100_000);
+    pos!(upper_btc, satoshi::<u64>, "1BTC", BTC);
+    pos!(mbtc, satoshi::<u64>, "1.5mBTC", 150_000);
+    pos!(ubtc, satoshi::<u64>, "1.5uBTC", 150);
+    pos!(sat, satoshi::<u64>, "150000sat", 150_000);
+    pos!(sats, satoshi::<u64>, "150000sats", 150_000);
+    pos!(whitespace, satoshi::<u64>, "0.5 btc", BTC / 2);
+    pos!(smallest, satoshi::<u64>, "0.00000001btc", 1);
+    pos!(trailing_zeros, satoshi::<u64>, "0.0000000100btc", 1);
+    pos!(underscores, satoshi::<u64>, "150_000sat", 150_000);
+    pos!(zero, satoshi::<u64>, "0btc", 0);
+    pos!(supply, satoshi::<u64>, "21000000btc", 21_000_000 * BTC);
+    pos!(signed, satoshi::<i64>, "-0.5btc", -50_000_000);
+
+    neg!(
+        too_precise_btc,
+        satoshi::<u64>,
+        "0.000000001btc",
+        "not a whole number of satoshis"
+    );
+    neg!(
+        too_precise_mbtc,
+        satoshi::<u64>,
+        "0.000001mbtc",
+        "not a whole number of satoshis"
+    );
+    neg!(
+        fractional_sat,
+        satoshi::<u64>,
+        "1.5sat",
+        "not a whole number of satoshis"
+    );
+    neg!(
+        no_unit,
+        satoshi::<u64>,
+        "100",
+        "missing unit, expected one of btc, mbtc, ubtc, sat, sats"
+    );
+    neg!(
+        unknown_unit,
+        satoshi::<u64>,
+        "1eth",
+        "unknown unit 'eth', expected one of btc, mbtc, ubtc, sat, sats"
+    );
+    neg!(
+        no_value,
+        satoshi::<u64>,
+        "btc",
+        "no value found before unit 'btc'"
+    );
+    neg!(
+        negative,
+        satoshi::<u64>,
+        "-1btc",
+        "value cannot be negative"
+    );
+    neg!(
+        overflow,
+        satoshi::<u32>,
+        "43btc",
+        "number too large to fit in target type"
+    );
+
+    pos!(range_min, amount, "546sat", 546);
+    pos!(range_max, amount, "21000000btc", 21_000_000 * BTC);
+    neg!(range_below, amount, "545sat", "less than minimum of 546sat");
+    neg!(
+        range_above,
+        amount,
+        "22000000btc",
+        "exceeds maximum of 21000000btc"
+    );
+    neg!(range_fee, fee, "1btc", "exceeds maximum of 50000000sat");
+}
+
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=amount)]
+        amount: u64,
+        #[clap(long, value_parser=satoshi::<u64>)]
+        fee: u64,
+    }
+
+    #[test]
+    fn parse() {
+        let args = Args::parse_from(["", "--amount", "1.5mBTC", "--fee", "1500sat"]);
+        assert_eq!(args.amount, 150_000);
+        assert_eq!(args.fee, 1_500);
+    }
+
+    #[test]
+    fn too_precise() {
+        let err = Args::try_parse_from(["", "--amount", "0.123456789btc", "--fee", "1sat"])
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("not a whole number of satoshis"));
+    }
+}
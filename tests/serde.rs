@@ -0,0 +1,113 @@
+#![cfg(feature = "serde")]
+
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Config {
+    #[serde(deserialize_with = "clap_num::serde::deserialize_number")]
+    count: i8,
+    #[serde(deserialize_with = "clap_num::serde::deserialize_si_number")]
+    size: u64,
+    #[serde(deserialize_with = "clap_num::serde::deserialize_maybe_hex")]
+    addr: u32,
+    #[serde(deserialize_with = "clap_num::serde::deserialize_maybe_bin")]
+    flags: u8,
+    #[serde(deserialize_with = "clap_num::serde::deserialize_maybe_oct")]
+    umask: u16,
+    #[serde(deserialize_with = "clap_num::serde::deserialize_file_mode")]
+    mode: u32,
+}
+
+fn parse(s: &str) -> Result<Config, String> {
+    toml::from_str(s).map_err(|e| e.message().to_string())
+}
+
+#[test]
+fn strings() {
+    assert_eq!(
+        parse(
+            r#"
+            count = "-1_0"
+            size = "1.5k"
+            addr = "0xDEAD_BEEF"
+            flags = "0b1010"
+            umask = "0o22"
+            mode = "0755"
+            "#
+        ),
+        Ok(Config {
+            count: -10,
+            size: 1_500,
+            addr: 0xDEAD_BEEF,
+            flags: 0b1010,
+            umask: 0o22,
+            mode: 0o755,
+        })
+    );
+}
+
+#[test]
+fn integers() {
+    assert_eq!(
+        parse(
+            r#"
+            count = -10
+            size = 1500
+            addr = 48879
+            flags = 10
+            umask = 18
+            mode = 755
+            "#
+        ),
+        Ok(Config {
+            count: -10,
+            size: 1_500,
+            addr: 48879,
+            flags: 10,
+            umask: 18,
+            mode: 0o755,
+        })
+    );
+}
+
+#[test]
+fn errors() {
+    let valid = [
+        ("count", "0"),
+        ("size", "0"),
+        ("addr", "0"),
+        ("flags", "0"),
+        ("umask", "0"),
+        ("mode", "0"),
+    ];
+    let with = |key: &str, value: &str| {
+        valid
+            .iter()
+            .map(|&(k, v)| format!("{k} = {}\n", if k == key { value } else { v }))
+            .collect::<String>()
+    };
+
+    assert_eq!(
+        parse(&with("count", "128")),
+        Err("number too large to fit in target type".to_string())
+    );
+    assert_eq!(
+        parse(&with("size", "\"1.5\"")),
+        Err("invalid digit found in string".to_string())
+    );
+    assert_eq!(
+        parse(&with("addr", "\"0xG\"")),
+        Err("invalid digit found in string".to_string())
+    );
+    assert_eq!(
+        parse(&with("mode", "\"0o8\"")),
+        Err("invalid octal digit '8'".to_string())
+    );
+    assert_eq!(
+        parse(&with("addr", "1.5")),
+        Err(
+            "invalid type: floating point `1.5`, expected a decimal or hexadecimal integer"
+                .to_string()
+        )
+    );
+}
@@ -0,0 +1,64 @@
+use clap_num::si_interval;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(si_interval($VAL, $MIN, $MAX), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<_, String> = si_interval::<i64>($VAL, $MIN, $MAX);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(simple, "10k..20k", 0u32, u32::MAX, 10_000..=20_000);
+    pos!(decimal, "1.5k..2M", 0u32, u32::MAX, 1_500..=2_000_000);
+    pos!(single, "1k..1k", 0u32, u32::MAX, 1_000..=1_000);
+    pos!(bounds, "20..20k", 20u32, 20_000, 20..=20_000);
+    pos!(negative, "-1k..1k", -1_000i32, 1_000, -1_000..=1_000);
+
+    neg!(
+        missing,
+        "10k",
+        i64::MIN,
+        i64::MAX,
+        "expected interval in the format of 10k..20k"
+    );
+    neg!(
+        reversed,
+        "2k..1k",
+        i64::MIN,
+        i64::MAX,
+        "start of 2000 exceeds end of 1000"
+    );
+    neg!(above, "1k..30k", 0, 20_000, "exceeds maximum of 20k");
+    neg!(below, "10..1k", 20, 20_000, "less than minimum of 20");
+    neg!(
+        empty_end,
+        "1k..",
+        i64::MIN,
+        i64::MAX,
+        "cannot parse integer from empty string"
+    );
+    neg!(
+        invalid_end,
+        "1k..x",
+        i64::MIN,
+        i64::MAX,
+        "invalid digit found in string"
+    );
+}
@@ -1,5 +1,5 @@
 use clap::Parser;
-use clap_num::si_number;
+use clap_num::{si_number, si_number_range, Rounding, SiParser};
 
 #[test]
 fn utf8_byte_index_not_char_boundry() {
@@ -59,6 +59,8 @@ mod basic {
     neg!(trailing_4, "1k2345", u16, "not an integer");
     pos!(trailing_do_nothing, "1k000", 1_000u16);
     pos!(negative_trailing, "-1k234", -1_234i16);
+    pos!(negative_zero_trailing, "-0k5", -500i16);
+    pos!(negative_zero_dec, "-0.5k", -500i16);
 
     pos!(leading_2, "12k123", 12_123u16);
     pos!(leading_3, "123k123", 123_123u32);
@@ -95,6 +97,651 @@ mod basic {
     );
 
     neg!(multiple_suffix, "1kk", u16, "invalid digit found in string");
+
+    pos!(underscores_si, "1_000k", 1_000_000u32);
+    pos!(no_leading_digit, ".5k", 500u16);
+    pos!(exact_trailing_zero, "1.2340k", 1_234u16);
+    pos!(zero_overflowing_prefix, "0k", 0u8);
+    neg!(
+        negative_overflow,
+        "-129",
+        i8,
+        "number too small to fit in target type"
+    );
+    neg!(negative_unsigned, "-1k", u16, "value cannot be negative");
+    neg!(sign_only, "-", i8, "invalid digit found in string");
+    neg!(
+        overflow_round,
+        "4000000000",
+        i32,
+        "number too large to fit in target type"
+    );
+    neg!(
+        underflow_round,
+        "-4000000000",
+        i32,
+        "number too small to fit in target type"
+    );
+    neg!(
+        overflow_no_hint,
+        "4000000001",
+        i32,
+        "number too large to fit in target type"
+    );
+    neg!(empty, "", u8, "cannot parse integer from empty string");
+}
+
+// exponent notation
+#[cfg(test)]
+mod exponent {
+    use super::*;
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(si_number($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ident, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let num: Result<$TYPE, String> = si_number($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plain, "2e6", 2_000_000u32);
+    pos!(decimal, "2.5e1", 25u8);
+    pos!(explicit_positive, "1e+3", 1_000u16);
+    pos!(zero_exponent, "7e0", 7u8);
+    pos!(with_si, "1.5e3k", 1_500_000u32);
+    pos!(negative_exponent, "15e-1k", 1_500u16);
+    pos!(negative_value, "-2e3", -2_000i16);
+    pos!(exact_negative_exponent, "100e-2", 1u8);
+    pos!(zero_huge_exponent, "0e999999", 0u8);
+    pos!(exa_not_exponent, "1E6", 1_600_000_000_000_000_000u64);
+
+    neg!(not_integer, "15e-1", u8, "not an integer");
+    neg!(
+        overflow,
+        "1e3",
+        u8,
+        "number too large to fit in target type"
+    );
+    neg!(
+        huge_exponent,
+        "1e999999",
+        u128,
+        "number too large to fit in target type"
+    );
+    neg!(
+        underflow,
+        "-1e3",
+        i8,
+        "number too small to fit in target type"
+    );
+    neg!(missing, "1e", u8, "invalid digit found in string");
+    neg!(double, "1e3e3", u32, "invalid digit found in string");
+    neg!(
+        decimal_exponent,
+        "1e1.5",
+        u32,
+        "invalid digit found in string"
+    );
+    neg!(
+        trailing_si_digits,
+        "1e3k5",
+        u32,
+        "invalid digit found in string"
+    );
+
+    #[test]
+    fn rounding() {
+        let parser = SiParser::new().rounding(Rounding::Nearest);
+        assert_eq!(parser.parse("15e-1"), Ok(2u8));
+        assert_eq!(parser.parse("1e-9"), Ok(0u8));
+    }
+
+    #[test]
+    fn ceil_far_below_one() {
+        let parser = SiParser::new().rounding(Rounding::Ceil);
+        assert_eq!(parser.parse("1e-999999"), Ok(1u8));
+    }
+}
+
+// rounding of over-precise values
+#[cfg(test)]
+mod rounding {
+    use clap_num::{Rounding, SiParser};
+
+    macro_rules! pos {
+        ($NAME:ident, $ROUNDING:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let parser = SiParser::new().rounding(Rounding::$ROUNDING);
+                assert_eq!(parser.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $ROUNDING:ident, $VAL:expr, $TYPE:ident, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let parser = SiParser::new().rounding(Rounding::$ROUNDING);
+                let num: Result<$TYPE, String> = parser.parse($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(nearest_up, Nearest, "1.2345k", 1_235u16);
+    pos!(nearest_down, Nearest, "1.2344k", 1_234u16);
+    pos!(nearest_carry, Nearest, "1.9999k", 2_000u16);
+    pos!(nearest_trailing, Nearest, "1k2346", 1_235u16);
+    pos!(nearest_negative, Nearest, "-1.2345k", -1_235i16);
+    pos!(floor, Floor, "1.2349k", 1_234u16);
+    pos!(floor_negative, Floor, "-1.2341k", -1_235i16);
+    pos!(floor_exact, Floor, "-1.2340k", -1_234i16);
+    pos!(ceil, Ceil, "1.2341k", 1_235u16);
+    pos!(ceil_exact, Ceil, "1.2340k", 1_234u16);
+    pos!(ceil_negative, Ceil, "-1.2349k", -1_234i16);
+    pos!(in_range, Nearest, "1.5k", 1_500u16);
+    pos!(mega, Nearest, "1.0000005M", 1_000_001u32);
+
+    neg!(
+        overflow,
+        Ceil,
+        "65.5351k",
+        u16,
+        "number too large to fit in target type"
+    );
+    neg!(
+        invalid_dropped,
+        Nearest,
+        "1K23.45",
+        u16,
+        "invalid digit found in string"
+    );
+    neg!(
+        multibyte_dropped,
+        Nearest,
+        "1k23˲",
+        u16,
+        "invalid digit found in string"
+    );
+
+    #[test]
+    fn default_rejects() {
+        let num: Result<u16, String> = SiParser::new().parse("1.2345k");
+        assert_eq!(num, Err(String::from("not an integer")));
+    }
+}
+
+// whitespace between the value and the SI symbol
+#[cfg(test)]
+mod whitespace {
+    use clap_num::{si_number, SiParser};
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let parser = SiParser::new().allow_whitespace();
+                assert_eq!(parser.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ident, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let parser = SiParser::new().allow_whitespace();
+                let num: Result<$TYPE, String> = parser.parse($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(space, "10 k", 10_000u16);
+    pos!(decimal, "3.3 M", 3_300_000u32);
+    pos!(tab, "1\tk", 1_000u16);
+    pos!(multiple, "4  G", 4_000_000_000u64);
+    pos!(trailing, "1 k2", 1_200u16);
+    pos!(negative, "-2.5 k", -2_500i16);
+    pos!(no_space, "1k", 1_000u16);
+
+    neg!(
+        leading_symbol,
+        " k1",
+        u16,
+        "no value found before SI symbol"
+    );
+    neg!(within_value, "1 0k", u16, "invalid digit found in string");
+    neg!(after_symbol, "1k 2", u16, "invalid digit found in string");
+
+    #[test]
+    fn default_rejects() {
+        let num: Result<u16, String> = si_number("10 k");
+        assert_eq!(num, Err(String::from("invalid digit found in string")));
+    }
+}
+
+// spelled-out prefix names
+#[cfg(test)]
+mod names {
+    use clap_num::{si_number, SiParser};
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let parser = SiParser::new().allow_names();
+                assert_eq!(parser.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ident, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let parser = SiParser::new().allow_names();
+                let num: Result<$TYPE, String> = parser.parse($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(kilo, "1kilo", 1_000u16);
+    pos!(mega, "2mega", 2_000_000u32);
+    pos!(giga_caps, "3GIGA", 3_000_000_000u64);
+    pos!(tera_mixed, "4Tera", 4_000_000_000_000u64);
+    pos!(peta, "1peta", 1_000_000_000_000_000u64);
+    pos!(exa, "1exa", 1_000_000_000_000_000_000u64);
+    pos!(zetta, "1zetta", 1_000_000_000_000_000_000_000u128);
+    pos!(yotta, "1yotta", 1_000_000_000_000_000_000_000_000u128);
+    pos!(decimal, "2.5mega", 2_500_000u32);
+    pos!(negative, "-1.5kilo", -1_500i16);
+    pos!(symbol, "3k3", 3_300u16);
+
+    neg!(unknown_name, "1hecto", u16, "invalid digit found in string");
+    neg!(
+        trailing_digits,
+        "1mega5",
+        u16,
+        "invalid digit found in string"
+    );
+    neg!(
+        missing_value,
+        "kilo",
+        u16,
+        "no value found before SI symbol"
+    );
+    neg!(
+        space_not_allowed,
+        "2 mega",
+        u32,
+        "invalid digit found in string"
+    );
+
+    #[test]
+    fn with_whitespace() {
+        let parser = SiParser::new().allow_names().allow_whitespace();
+        assert_eq!(parser.parse("2 mega"), Ok(2_000_000u32));
+    }
+
+    #[test]
+    fn default_rejects() {
+        let num: Result<u16, String> = si_number("1kilo");
+        assert_eq!(num, Err(String::from("invalid digit found in string")));
+    }
+}
+
+// powers of 1024 instead of powers of 1000
+#[cfg(test)]
+mod binary {
+    use clap_num::{si_number, Rounding, SiParser};
+
+    const BINARY: SiParser = SiParser::new().binary();
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(BINARY.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ident, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let num: Result<$TYPE, String> = BINARY.parse($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(kilo, "4k", 4_096u16);
+    pos!(kilo_caps, "4K", 4_096u16);
+    pos!(mega, "1.5M", 1_572_864u32);
+    pos!(giga, "2G", 2u64 << 30);
+    pos!(tera, "1T", 1u64 << 40);
+    pos!(exa, "1E", 1u64 << 60);
+    pos!(yotta, "1Y", 1u128 << 80);
+    pos!(separator, "1k5", 1_536u16);
+    pos!(exponent, "0.5e1k", 5_120u16);
+    pos!(negative, "-1k", -1_024i16);
+    pos!(signed_min, "-32k", i16::MIN);
+    pos!(plain, "1000", 1_000u16);
+    pos!(plain_exponent, "2e3", 2_000u16);
+    pos!(underscores, "1_024k", 1_048_576u32);
+    pos!(zero, "0k", 0u8);
+    pos!(fraction_exact, "0.25k", 256u16);
+
+    neg!(fraction, "1.001k", u16, "not an integer");
+    neg!(
+        overflow,
+        "64k",
+        u16,
+        "number too large to fit in target type"
+    );
+    neg!(
+        underflow,
+        "-33k",
+        i16,
+        "number too small to fit in target type"
+    );
+    neg!(negative_unsigned, "-1k", u16, "value cannot be negative");
+    neg!(sign_only, "-k", i16, "invalid digit found in string");
+    neg!(invalid, "1.x5k", u16, "invalid digit found in string");
+    neg!(
+        plain_overflow,
+        "100000000",
+        u16,
+        "number too large to fit in target type"
+    );
+
+    #[test]
+    fn rounding() {
+        let parser = BINARY.rounding(Rounding::Nearest);
+        assert_eq!(parser.parse("1.0001k"), Ok(1_024u16));
+        assert_eq!(parser.parse("0.0005k"), Ok(1u16));
+        assert_eq!(parser.parse("-0.0005k"), Ok(-1i16));
+        assert_eq!(
+            BINARY.rounding(Rounding::Floor).parse("1.001k"),
+            Ok(1_025u16)
+        );
+        assert_eq!(
+            BINARY.rounding(Rounding::Ceil).parse("1.001k"),
+            Ok(1_026u16)
+        );
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(
+            BINARY.parse_range("65M", 0u64, 64 << 20),
+            Err(String::from("exceeds maximum of 64M"))
+        );
+        assert_eq!(
+            BINARY.parse_range("1k", 4_096u64, 64 << 20),
+            Err(String::from("less than minimum of 4k"))
+        );
+        assert_eq!(
+            BINARY.parse_range("1k", 0u64, 1_000),
+            Err(String::from("exceeds maximum of 1000"))
+        );
+        assert_eq!(
+            BINARY.parse_range("100000000", 0u64, 64 << 20),
+            Err(String::from("exceeds maximum of 67108864"))
+        );
+    }
+
+    #[test]
+    fn default_is_decimal() {
+        assert_eq!(si_number("4k"), Ok(4_000u16));
+    }
+}
+
+// values with an SI symbol are rejected as ambiguous
+#[cfg(test)]
+mod reject_ambiguous {
+    use clap_num::{si_number, ErrorKind, NumberParser, SiParser};
+
+    const STRICT: SiParser = SiParser::new().reject_ambiguous();
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(STRICT.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let num: Result<u128, String> = STRICT.parse($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plain, "4096", 4_096u32);
+    pos!(exponent, "4e3", 4_000u32);
+    pos!(negative, "-12", -12i8);
+
+    neg!(kilo, "4k", "ambiguous unit: use kB (1000) or KiB (1024)");
+    neg!(
+        kilo_caps,
+        "4K",
+        "ambiguous unit: use kB (1000) or KiB (1024)"
+    );
+    neg!(
+        mega,
+        "1.5M",
+        "ambiguous unit: use MB (1000000) or MiB (1048576)"
+    );
+    neg!(
+        giga,
+        "2G",
+        "ambiguous unit: use GB (1000000000) or GiB (1073741824)"
+    );
+    neg!(
+        separator,
+        "1k5",
+        "ambiguous unit: use kB (1000) or KiB (1024)"
+    );
+    neg!(invalid, "4x", "invalid digit found in string");
+
+    #[test]
+    fn binary() {
+        let num: Result<u32, String> = STRICT.binary().parse("4k");
+        assert_eq!(
+            num,
+            Err(String::from("ambiguous unit: use kB (1000) or KiB (1024)"))
+        );
+    }
+
+    #[test]
+    fn names() {
+        let num: Result<u32, String> = STRICT.allow_names().parse("4mega");
+        assert_eq!(
+            num,
+            Err(String::from(
+                "ambiguous unit: use MB (1000000) or MiB (1048576)"
+            ))
+        );
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(
+            STRICT.parse_range("1k", 0u32, 100),
+            Err(String::from("ambiguous unit: use kB (1000) or KiB (1024)"))
+        );
+        assert_eq!(
+            STRICT.parse_range("101", 0u32, 100),
+            Err(String::from("exceeds maximum of 100"))
+        );
+    }
+
+    #[test]
+    fn number_parser() {
+        let parser = NumberParser::<u32>::si(STRICT);
+        let err = parser.try_parse("4k").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(err.message(), "ambiguous unit: use kB (1000) or KiB (1024)");
+    }
+
+    #[test]
+    fn default_accepts() {
+        assert_eq!(si_number("4k"), Ok(4_000u16));
+    }
+}
+
+// decimal prefixes, such as hecto and centi
+#[cfg(test)]
+mod decimal_prefixes {
+    use clap_num::{si_number, si_number_with_unit, Rounding, SiParser, SiPrefix};
+
+    const DECIMAL: SiParser = SiParser::new().decimal_prefixes();
+
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(DECIMAL.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ident, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let num: Result<$TYPE, String> = DECIMAL.parse($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(deca, "5da", 50u16);
+    pos!(hecto, "10h", 1_000u16);
+    pos!(deci, "20d", 2u16);
+    pos!(centi, "200c", 2u16);
+    pos!(hecto_fraction, "1013.25h", 101_325u32);
+    pos!(hecto_separator, "1h5", 150u16);
+    pos!(deca_separator, "1da5", 15u16);
+    pos!(exponent, "1e3h", 100_000u32);
+    pos!(negative, "-2h", -200i16);
+    pos!(kilo, "1k", 1_000u16);
+    pos!(plain, "1000", 1_000u16);
+    pos!(zero, "0c", 0u8);
+
+    neg!(deci_fraction, "15d", u16, "not an integer");
+    neg!(centi_fraction, "150c", u16, "not an integer");
+    neg!(
+        overflow,
+        "1000h",
+        u16,
+        "number too large to fit in target type"
+    );
+    neg!(negative_unsigned, "-1h", u16, "value cannot be negative");
+    neg!(no_value, "h", u16, "no value found before SI symbol");
+    neg!(invalid, "1hh", u16, "invalid digit found in string");
+
+    #[test]
+    fn rounding() {
+        let parser = DECIMAL.rounding(Rounding::Nearest);
+        assert_eq!(parser.parse("15d"), Ok(2u16));
+        assert_eq!(parser.parse("149c"), Ok(1u16));
+    }
+
+    #[test]
+    fn unit() {
+        let parser = DECIMAL.optional_unit("Pa");
+        assert_eq!(parser.parse("1013hPa"), Ok(101_300u32));
+        assert_eq!(parser.parse("1013h"), Ok(101_300u32));
+        assert_eq!(parser.parse("101kPa"), Ok(101_000u32));
+        assert_eq!(
+            parser.parse::<u32>("1013hbar"),
+            Err(String::from("unexpected unit 'bar', expected 'Pa'"))
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        assert_eq!(
+            DECIMAL.parse_with_prefix("10h"),
+            Ok((1_000u16, Some(SiPrefix::Hecto)))
+        );
+        assert_eq!(
+            DECIMAL.parse_with_prefix("5da"),
+            Ok((50u16, Some(SiPrefix::Deca)))
+        );
+        assert_eq!(
+            DECIMAL.parse_with_prefix("20d"),
+            Ok((2u16, Some(SiPrefix::Deci)))
+        );
+        assert_eq!(
+            DECIMAL.parse_with_prefix("200c"),
+            Ok((2u16, Some(SiPrefix::Centi)))
+        );
+        assert_eq!(
+            DECIMAL.parse_with_prefix("1k"),
+            Ok((1_000u16, Some(SiPrefix::Kilo)))
+        );
+    }
+
+    #[test]
+    fn names() {
+        let parser = DECIMAL.allow_names();
+        assert_eq!(
+            parser.parse_with_prefix("5hecto"),
+            Ok((500u16, Some(SiPrefix::Hecto)))
+        );
+        assert_eq!(parser.parse("200CENTI"), Ok(2u16));
+        let num: Result<u16, String> = SiParser::new().allow_names().parse("5hecto");
+        assert_eq!(num, Err(String::from("invalid digit found in string")));
+    }
+
+    #[test]
+    fn binary() {
+        let parser = DECIMAL.binary();
+        assert_eq!(parser.parse("1h"), Ok(100u16));
+        assert_eq!(parser.parse("1k"), Ok(1_024u16));
+    }
+
+    #[test]
+    fn reject_ambiguous() {
+        let parser = DECIMAL.reject_ambiguous();
+        assert_eq!(parser.parse("1h"), Ok(100u16));
+        let num: Result<u16, String> = parser.parse("1k");
+        assert_eq!(
+            num,
+            Err(String::from("ambiguous unit: use kB (1000) or KiB (1024)"))
+        );
+    }
+
+    #[test]
+    fn default_rejects() {
+        let num: Result<u16, String> = si_number("10h");
+        assert_eq!(num, Err(String::from("invalid digit found in string")));
+        let num: Result<u16, String> = si_number_with_unit("10hPa", "Pa");
+        assert_eq!(num, Err(String::from("invalid digit found in string")));
+    }
 }
 
 // integration tests with clap
@@ -144,3 +791,80 @@ mod integration {
     neg!(precise, "1k1111", "not an integer");
     neg!(leading_prefix, "k123", "no value found before SI symbol");
 }
+
+#[cfg(test)]
+mod range {
+    use super::*;
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $MIN:expr, $MAX:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<i64, String> = si_number_range($VAL, $MIN, $MAX);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    neg!(max_si, "1M", 1_000, 999_999, "exceeds maximum of 999.999k");
+    neg!(min_si, "0.999k", 1_000, 999_999, "less than minimum of 1k");
+    neg!(
+        max_plain,
+        "1000000",
+        1_000,
+        999_999,
+        "exceeds maximum of 999999 (1000000 can be written as 1M)"
+    );
+    neg!(
+        max_plain_no_hint,
+        "1234567",
+        1_000,
+        999_999,
+        "exceeds maximum of 999999"
+    );
+    neg!(
+        max_plain_short,
+        "100000",
+        1_000,
+        99_999,
+        "exceeds maximum of 99999"
+    );
+    neg!(
+        min_plain_negative,
+        "-25000000",
+        -1_000,
+        0,
+        "less than minimum of -1000 (-25000000 can be written as -25M)"
+    );
+    neg!(max_small, "1k", 0, 999, "exceeds maximum of 999");
+    neg!(max_mega, "2M", 0, 1_500_000, "exceeds maximum of 1.5M");
+    neg!(max_zero, "1k", 0, 0, "exceeds maximum of 0");
+    neg!(min_negative, "-2k", -1_500, 0, "less than minimum of -1.5k");
+    neg!(max_exponent, "1e6", 0, 1_000, "exceeds maximum of 1000");
+    neg!(
+        max_large,
+        "10E",
+        0,
+        i64::MAX,
+        "number too large to fit in target type"
+    );
+
+    #[test]
+    fn max_yotta() {
+        assert_eq!(
+            si_number_range::<u128>("2Y", 0, 1_000_000_000_000_000_000_000_000),
+            Err(String::from("exceeds maximum of 1Y"))
+        );
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            SiParser::new()
+                .allow_names()
+                .parse_range::<u32>("2mega", 0, 1_000_000),
+            Err(String::from("exceeds maximum of 1M"))
+        );
+    }
+}
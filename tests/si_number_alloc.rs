@@ -0,0 +1,67 @@
+//! Guards against allocations when parsing valid SI values.
+//!
+//! This is in a separate test binary because it replaces the global
+//! allocator.
+
+use clap_num::{si_number, si_number_range, Rounding, SiParser};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// counts allocations made by the current thread while running `f`
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn no_allocations() {
+    let inputs = [
+        ("1", 1),
+        ("1_000_000", 1_000_000),
+        ("3k3", 3_300),
+        ("3.3k", 3_300),
+        ("-1.234k", -1_234),
+        ("1.5e3k", 1_500_000),
+        ("2e6", 2_000_000),
+        ("1Y123456789987654321", 1_123_456_789_987_654_321_000_000),
+    ];
+
+    for (input, expected) in inputs {
+        let (result, count) = allocations(|| si_number::<i128>(input));
+        assert_eq!(result, Ok(expected));
+        assert_eq!(count, 0, "{input} allocated");
+    }
+
+    let (result, count) = allocations(|| si_number_range::<u32>("999k999", 1_000, 999_999));
+    assert_eq!(result, Ok(999_999));
+    assert_eq!(count, 0);
+
+    let parser = SiParser::new()
+        .rounding(Rounding::Nearest)
+        .allow_names()
+        .allow_whitespace();
+    let (result, count) = allocations(|| parser.parse::<u32>("1.23456 kilo"));
+    assert_eq!(result, Ok(1_235));
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,109 @@
+use clap::Parser;
+use clap_num::{si_number_with_prefix, SiParser, SiPrefix};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr, $PREFIX:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(si_number_with_prefix($VAL), Ok(($RESULT, $PREFIX)));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let num: Result<(u32, _), String> = si_number_with_prefix($VAL);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plain, "3300", 3_300u32, None);
+    pos!(exponent, "3.3e3", 3_300u32, None);
+    pos!(kilo, "3.3k", 3_300u32, Some(SiPrefix::Kilo));
+    pos!(kilo_infix, "3k3", 3_300u32, Some(SiPrefix::Kilo));
+    pos!(kilo_upper, "3K3", 3_300u32, Some(SiPrefix::Kilo));
+    pos!(mega, "1M", 1_000_000u32, Some(SiPrefix::Mega));
+    pos!(negative, "-2G", -2_000_000_000i64, Some(SiPrefix::Giga));
+    pos!(zero, "0k", 0u32, Some(SiPrefix::Kilo));
+
+    neg!(empty, "", "cannot parse integer from empty string");
+    neg!(no_value, "k", "no value found before SI symbol");
+    neg!(overflow, "5G", "number too large to fit in target type");
+    neg!(fraction, "1.0001k", "not an integer");
+}
+
+#[cfg(test)]
+mod parser {
+    use super::*;
+
+    #[test]
+    fn names() {
+        let parser = SiParser::new().allow_names().allow_whitespace();
+        assert_eq!(
+            parser.parse_with_prefix::<u32>("2 mega"),
+            Ok((2_000_000, Some(SiPrefix::Mega)))
+        );
+        assert_eq!(
+            parser.parse_with_prefix::<u32>("2 M"),
+            Ok((2_000_000, Some(SiPrefix::Mega)))
+        );
+    }
+
+    #[test]
+    fn unit() {
+        let parser = SiParser::new().optional_unit("Hz");
+        assert_eq!(
+            parser.parse_with_prefix::<u32>("10kHz"),
+            Ok((10_000, Some(SiPrefix::Kilo)))
+        );
+        assert_eq!(parser.parse_with_prefix::<u32>("10Hz"), Ok((10, None)));
+        assert_eq!(parser.parse_with_prefix::<u32>("10"), Ok((10, None)));
+    }
+
+    #[test]
+    fn binary() {
+        let parser = SiParser::new().binary();
+        assert_eq!(
+            parser.parse_with_prefix::<u32>("4k"),
+            Ok((4_096, Some(SiPrefix::Kilo)))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=si_number_with_prefix::<u32>)]
+        resistance: (u32, Option<SiPrefix>),
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--resistance", "4k7"]);
+        assert_eq!(args.resistance, (4_700, Some(SiPrefix::Kilo)));
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--resistance", "5G"]);
+        match args {
+            Err(e) => assert!(e
+                .to_string()
+                .contains("number too large to fit in target type")),
+            _ => unreachable!(),
+        }
+    }
+}
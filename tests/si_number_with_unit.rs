@@ -0,0 +1,160 @@
+use clap::Parser;
+use clap_num::{si_number_with_unit, SiParser};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $UNIT:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(si_number_with_unit($VAL, $UNIT), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $UNIT:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let num: Result<u32, String> = si_number_with_unit($VAL, $UNIT);
+                assert_eq!(num, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plain, "10Hz", "Hz", 10u32);
+    pos!(kilo, "10kHz", "Hz", 10_000u32);
+    pos!(decimal, "2.4kHz", "Hz", 2_400u32);
+    pos!(separator, "3k3Hz", "Hz", 3_300u32);
+    pos!(exponent, "1.5e3kHz", "Hz", 1_500_000u32);
+    pos!(negative, "-1.5kV", "V", -1_500i32);
+    pos!(prefixed_unit, "4kB", "kB", 4u32);
+    pos!(si_symbol_unit, "10kB", "B", 10_000u32);
+
+    neg!(missing, "10k", "Hz", "missing unit 'Hz'");
+    neg!(missing_plain, "10", "Hz", "missing unit 'Hz'");
+    neg!(other, "10kB", "Hz", "unexpected unit 'B', expected 'Hz'");
+    neg!(
+        other_plain,
+        "10B",
+        "Hz",
+        "unexpected unit 'B', expected 'Hz'"
+    );
+    neg!(
+        other_long,
+        "1Mbit",
+        "Hz",
+        "unexpected unit 'bit', expected 'Hz'"
+    );
+    neg!(
+        other_prefixed,
+        "10Hz",
+        "kHz",
+        "unexpected unit 'Hz', expected 'kHz'"
+    );
+    neg!(
+        case_sensitive,
+        "10hz",
+        "Hz",
+        "unexpected unit 'hz', expected 'Hz'"
+    );
+    neg!(whitespace, "10k Hz", "Hz", "invalid digit found in string");
+    neg!(
+        only_unit,
+        "Hz",
+        "Hz",
+        "cannot parse integer from empty string"
+    );
+}
+
+#[cfg(test)]
+mod parser {
+    use super::*;
+
+    #[test]
+    fn optional() {
+        let parser = SiParser::new().optional_unit("Hz");
+        assert_eq!(parser.parse("10kHz"), Ok(10_000u32));
+        assert_eq!(parser.parse("10k"), Ok(10_000u32));
+        assert_eq!(
+            parser.parse::<u32>("10kB"),
+            Err(String::from("unexpected unit 'B', expected 'Hz'"))
+        );
+    }
+
+    #[test]
+    fn required() {
+        let parser = SiParser::new().unit("Hz");
+        assert_eq!(parser.parse("10kHz"), Ok(10_000u32));
+        assert_eq!(
+            parser.parse::<u32>("10k"),
+            Err(String::from("missing unit 'Hz'"))
+        );
+    }
+
+    #[test]
+    fn whitespace() {
+        let parser = SiParser::new().allow_whitespace().unit("Hz");
+        assert_eq!(parser.parse("10 k Hz"), Ok(10_000u32));
+        assert_eq!(parser.parse("10 Hz"), Ok(10u32));
+    }
+
+    #[test]
+    fn names() {
+        let parser = SiParser::new().allow_names().unit("Hz");
+        assert_eq!(parser.parse("10kiloHz"), Ok(10_000u32));
+        assert_eq!(
+            parser.parse::<u32>("10kilo"),
+            Err(String::from("missing unit 'Hz'"))
+        );
+    }
+
+    #[test]
+    fn range() {
+        let parser = SiParser::new().unit("Hz");
+        assert_eq!(parser.parse_range("20kHz", 20, 20_000), Ok(20_000u32));
+        assert_eq!(
+            parser.parse_range::<u32>("21kHz", 20, 20_000),
+            Err(String::from("exceeds maximum of 20k"))
+        );
+        assert_eq!(
+            parser.parse_range::<u32>("21kB", 20, 20_000),
+            Err(String::from("unexpected unit 'B', expected 'Hz'"))
+        );
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    fn hertz(s: &str) -> Result<u64, String> {
+        si_number_with_unit(s, "Hz")
+    }
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=hertz)]
+        frequency: u64,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--frequency", "2.4GHz"]);
+        assert_eq!(args.frequency, 2_400_000_000);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--frequency", "2.4GB"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("unexpected unit 'B', expected 'Hz'")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use clap::Parser;
+use clap_num::{si_number, SiPrefix};
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($VAL.parse::<SiPrefix>(), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($VAL.parse::<SiPrefix>(), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(kilo, "k", SiPrefix::Kilo);
+    pos!(kilo_upper, "K", SiPrefix::Kilo);
+    pos!(mega, "M", SiPrefix::Mega);
+    pos!(yotta, "Y", SiPrefix::Yotta);
+    pos!(name, "giga", SiPrefix::Giga);
+    pos!(name_upper, "TERA", SiPrefix::Tera);
+    pos!(hecto, "h", SiPrefix::Hecto);
+    pos!(deca, "da", SiPrefix::Deca);
+    pos!(deci, "d", SiPrefix::Deci);
+    pos!(centi, "c", SiPrefix::Centi);
+    pos!(decimal_name, "centi", SiPrefix::Centi);
+
+    neg!(
+        empty,
+        "",
+        "unknown SI prefix '', expected one of Y, Z, E, P, T, G, M, k, h, da, d, c"
+    );
+    neg!(
+        milli,
+        "m",
+        "unknown SI prefix 'm', expected one of Y, Z, E, P, T, G, M, k, h, da, d, c"
+    );
+    neg!(
+        two_symbols,
+        "kM",
+        "unknown SI prefix 'kM', expected one of Y, Z, E, P, T, G, M, k, h, da, d, c"
+    );
+    neg!(
+        binary,
+        "Ki",
+        "unknown SI prefix 'Ki', expected one of Y, Z, E, P, T, G, M, k, h, da, d, c"
+    );
+}
+
+#[cfg(test)]
+mod table {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for prefix in SiPrefix::ALL.into_iter().chain(SiPrefix::DECIMAL) {
+            assert_eq!(prefix.to_string().parse(), Ok(prefix));
+            assert_eq!(prefix.name().parse(), Ok(prefix));
+        }
+    }
+
+    #[test]
+    fn multiplier() {
+        assert_eq!(SiPrefix::Kilo.multiplier(), Some(1_000));
+        assert_eq!(SiPrefix::Yotta.multiplier(), Some(10u128.pow(24)));
+        assert_eq!(SiPrefix::Hecto.multiplier(), Some(100));
+        assert_eq!(SiPrefix::Deca.multiplier(), Some(10));
+        assert_eq!(SiPrefix::Deci.multiplier(), None);
+        assert_eq!(SiPrefix::Centi.multiplier(), None);
+        for prefix in SiPrefix::ALL {
+            assert_eq!(
+                prefix.multiplier(),
+                Some(10u128.pow(prefix.exponent() as u32))
+            );
+        }
+    }
+
+    #[test]
+    fn matches_si_number() {
+        for prefix in SiPrefix::ALL {
+            assert_eq!(
+                si_number::<u128>(&format!("1{prefix}")).ok(),
+                prefix.multiplier()
+            );
+        }
+    }
+
+    #[test]
+    fn largest_first() {
+        let exponents: Vec<i32> = SiPrefix::ALL.iter().map(|p| p.exponent()).collect();
+        assert_eq!(exponents, [24, 21, 18, 15, 12, 9, 6, 3]);
+        let exponents: Vec<i32> = SiPrefix::DECIMAL.iter().map(|p| p.exponent()).collect();
+        assert_eq!(exponents, [2, 1, -1, -2]);
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long)]
+        unit: SiPrefix,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--unit", "mega"]);
+        assert_eq!(args.unit, SiPrefix::Mega);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--unit", "x"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("unknown SI prefix 'x'")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use clap::Parser;
+use clap_num::si_range;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        let rate = si_range!(u64, 1k..=10M);
+        assert_eq!(rate("1k"), Ok(1_000));
+        assert_eq!(rate("44k1"), Ok(44_100));
+        assert_eq!(rate("10M"), Ok(10_000_000));
+    }
+
+    #[test]
+    fn above_max() {
+        let rate = si_range!(u64, 1k..=10M);
+        assert_eq!(rate("10.5M"), Err(String::from("exceeds maximum of 10M")));
+    }
+
+    #[test]
+    fn below_min() {
+        let rate = si_range!(u64, 1k..=10M);
+        assert_eq!(rate("999"), Err(String::from("less than minimum of 1000")));
+    }
+
+    #[test]
+    fn negative() {
+        let offset = si_range!(i32, -1.5k..=4k7);
+        assert_eq!(offset("-1k5"), Ok(-1_500));
+        assert_eq!(offset("4700"), Ok(4_700));
+        assert_eq!(
+            offset("-2k"),
+            Err(String::from("less than minimum of -1.5k"))
+        );
+    }
+
+    #[test]
+    fn plain() {
+        let percent = si_range!(u8, 0..=100);
+        assert_eq!(percent("100"), Ok(100));
+        assert_eq!(percent("101"), Err(String::from("exceeds maximum of 100")));
+    }
+
+    #[test]
+    fn exponent() {
+        let rate = si_range!(u64, 1e3..=2.5e3k);
+        assert_eq!(rate("2.5M"), Ok(2_500_000));
+        assert_eq!(rate("999"), Err(String::from("less than minimum of 1000")));
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=si_range!(u32, 1k..=1M))]
+        rate: u32,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--rate", "48k"]);
+        assert_eq!(args.rate, 48_000);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--rate", "2M"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 1M")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use clap::Parser;
+use clap_num::{negative, non_negative, positive};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $TYPE:ty, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<$TYPE, String> = $PARSER($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(positive_one, positive, "1", 1i8);
+    pos!(positive_max, positive, "127", i8::MAX);
+    pos!(positive_float, positive, "0.001", 0.001f64);
+    pos!(non_negative_zero, non_negative, "0", 0i32);
+    pos!(non_negative_one, non_negative, "1_000", 1_000i32);
+    pos!(non_negative_float, non_negative, "0.0", 0.0f32);
+    pos!(negative_one, negative, "-1", -1i16);
+    pos!(negative_min, negative, "-128", i8::MIN);
+    pos!(negative_float, negative, "-0.5", -0.5f32);
+
+    neg!(positive_zero, positive, "0", i8, "value must be positive");
+    neg!(positive_neg, positive, "-1", i8, "value must be positive");
+    neg!(
+        positive_float_zero,
+        positive,
+        "-0.0",
+        f32,
+        "value must be positive"
+    );
+    neg!(positive_nan, positive, "NaN", f32, "value must be positive");
+    neg!(
+        non_negative_neg,
+        non_negative,
+        "-1",
+        i64,
+        "value cannot be negative"
+    );
+    neg!(
+        non_negative_nan,
+        non_negative,
+        "NaN",
+        f64,
+        "value cannot be negative"
+    );
+    neg!(negative_zero, negative, "0", i8, "value must be negative");
+    neg!(negative_pos, negative, "1", i8, "value must be negative");
+    neg!(
+        negative_overflow,
+        negative,
+        "-129",
+        i8,
+        "number too small to fit in target type"
+    );
+    neg!(
+        invalid,
+        positive,
+        "1.5",
+        i32,
+        "invalid digit found in string"
+    );
+    neg!(
+        hex_prefix,
+        positive,
+        "0x10",
+        i32,
+        "hexadecimal prefix '0x' is not accepted, expected decimal"
+    );
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=positive::<i32>, allow_hyphen_values=true)]
+        step: i32,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--step", "5"]);
+        assert_eq!(args.step, 5);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--step", "-5"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("value must be positive")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+#![cfg(feature = "testing")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use clap_num::testing::*;
+use clap_num::{
+    bcd, file_mode, gray_code, hex_color, hex_only, ipv4, ipv4_netmask, ipv4_prefix_len,
+    ipv6_netmask, ipv6_prefix_len, maybe_bin, maybe_hex, maybe_oct, number_range, si_number, uuid,
+    SiParser,
+};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn number_valid_i32((value, s) in valid_number::<i32>()) {
+        prop_assert_eq!(number_range(&s, i32::MIN, i32::MAX), Ok(value));
+    }
+
+    #[test]
+    fn number_valid_u64((value, s) in valid_number::<u64>()) {
+        prop_assert_eq!(number_range(&s, u64::MIN, u64::MAX), Ok(value));
+    }
+
+    #[test]
+    fn number_invalid(s in invalid_number()) {
+        prop_assert!(number_range(&s, i64::MIN, i64::MAX).is_err());
+    }
+
+    #[test]
+    fn maybe_hex_valid((value, s) in valid_maybe_hex::<u32>()) {
+        prop_assert_eq!(maybe_hex::<u32>(&s), Ok(value));
+    }
+
+    #[test]
+    fn maybe_hex_invalid(s in invalid_maybe_hex()) {
+        prop_assert!(maybe_hex::<u128>(&s).is_err());
+    }
+
+    #[test]
+    fn maybe_bin_valid((value, s) in valid_maybe_bin::<u16>()) {
+        prop_assert_eq!(maybe_bin::<u16>(&s), Ok(value));
+    }
+
+    #[test]
+    fn maybe_bin_invalid(s in invalid_maybe_bin()) {
+        prop_assert!(maybe_bin::<u128>(&s).is_err());
+    }
+
+    #[test]
+    fn maybe_oct_valid((value, s) in valid_maybe_oct::<u64>()) {
+        prop_assert_eq!(maybe_oct::<u64>(&s), Ok(value));
+    }
+
+    #[test]
+    fn maybe_oct_invalid(s in invalid_maybe_oct()) {
+        prop_assert!(maybe_oct::<u128>(&s).is_err());
+    }
+
+    #[test]
+    fn hex_only_valid((value, s) in valid_hex_only::<u8>()) {
+        prop_assert_eq!(hex_only::<u8>(&s), Ok(value));
+    }
+
+    #[test]
+    fn hex_only_invalid(s in invalid_hex_only()) {
+        prop_assert!(hex_only::<u128>(&s).is_err());
+    }
+
+    #[test]
+    fn si_number_valid_i64((value, s) in valid_si_number::<i64>()) {
+        prop_assert_eq!(si_number::<i64>(&s), Ok(value));
+    }
+
+    #[test]
+    fn si_number_valid_u128((value, s) in valid_si_number::<u128>()) {
+        prop_assert_eq!(si_number::<u128>(&s), Ok(value));
+    }
+
+    #[test]
+    fn si_number_invalid(s in invalid_si_number()) {
+        prop_assert!(si_number::<i128>(&s).is_err());
+    }
+
+    #[test]
+    fn si_whitespace_valid((value, s) in valid_si_whitespace::<i64>()) {
+        prop_assert_eq!(SiParser::new().allow_whitespace().parse::<i64>(&s), Ok(value));
+    }
+
+    #[test]
+    fn si_whitespace_invalid(s in invalid_si_whitespace()) {
+        prop_assert!(SiParser::new().allow_whitespace().parse::<i128>(&s).is_err());
+    }
+
+    #[test]
+    fn si_names_valid((value, s) in valid_si_names::<u128>()) {
+        prop_assert_eq!(SiParser::new().allow_names().parse::<u128>(&s), Ok(value));
+    }
+
+    #[test]
+    fn si_names_invalid(s in invalid_si_names()) {
+        prop_assert!(SiParser::new().allow_names().parse::<i128>(&s).is_err());
+    }
+
+    #[test]
+    fn bcd_valid((value, s) in valid_bcd::<u32>()) {
+        prop_assert_eq!(bcd::<u32>(&s), Ok(value));
+    }
+
+    #[test]
+    fn bcd_invalid(s in invalid_bcd()) {
+        prop_assert!(bcd::<u128>(&s).is_err());
+    }
+
+    #[test]
+    fn gray_code_valid((value, s) in valid_gray_code::<u16>()) {
+        prop_assert_eq!(gray_code::<u16>(&s), Ok(value));
+    }
+
+    #[test]
+    fn gray_code_invalid(s in invalid_gray_code()) {
+        prop_assert!(gray_code::<u128>(&s).is_err());
+    }
+
+    #[test]
+    fn ipv4_valid((value, s) in valid_ipv4()) {
+        prop_assert_eq!(ipv4(&s), Ok(value));
+    }
+
+    #[test]
+    fn ipv4_invalid(s in invalid_ipv4()) {
+        prop_assert!(ipv4(&s).is_err());
+    }
+
+    #[test]
+    fn ipv4_prefix_len_valid((value, s) in valid_ipv4_prefix_len()) {
+        prop_assert_eq!(ipv4_prefix_len(&s), Ok(value));
+    }
+
+    #[test]
+    fn ipv4_prefix_len_invalid(s in invalid_ipv4_prefix_len()) {
+        prop_assert!(ipv4_prefix_len(&s).is_err());
+        prop_assert!(ipv4_netmask(&s).is_err());
+    }
+
+    #[test]
+    fn ipv6_prefix_len_valid((value, s) in valid_ipv6_prefix_len()) {
+        prop_assert_eq!(ipv6_prefix_len(&s), Ok(value));
+    }
+
+    #[test]
+    fn ipv6_prefix_len_invalid(s in invalid_ipv6_prefix_len()) {
+        prop_assert!(ipv6_prefix_len(&s).is_err());
+        prop_assert!(ipv6_netmask(&s).is_err());
+    }
+
+    #[test]
+    fn ipv4_netmask_valid((value, s) in valid_ipv4_netmask()) {
+        prop_assert_eq!(ipv4_netmask(&s), Ok(value));
+    }
+
+    #[test]
+    fn ipv6_netmask_valid((value, s) in valid_ipv6_netmask()) {
+        prop_assert_eq!(ipv6_netmask(&s), Ok(value));
+    }
+
+    #[test]
+    fn file_mode_valid((value, s) in valid_file_mode()) {
+        prop_assert_eq!(file_mode(&s), Ok(value));
+    }
+
+    #[test]
+    fn file_mode_invalid(s in invalid_file_mode()) {
+        prop_assert!(file_mode(&s).is_err());
+    }
+
+    #[test]
+    fn uuid_valid((value, s) in valid_uuid()) {
+        prop_assert_eq!(uuid(&s), Ok(value));
+    }
+
+    #[test]
+    fn uuid_invalid(s in invalid_uuid()) {
+        prop_assert!(uuid(&s).is_err());
+    }
+
+    #[test]
+    fn hex_color_valid((value, s) in valid_hex_color()) {
+        prop_assert_eq!(hex_color(&s), Ok(value));
+    }
+
+    #[test]
+    fn hex_color_invalid(s in invalid_hex_color()) {
+        prop_assert!(hex_color(&s).is_err());
+    }
+
+    #[test]
+    fn arbitrary_inputs(data in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let mut u = Unstructured::new(&data);
+        let input = SiNumberInput::<i32>::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(si_number::<i32>(&input.input), Ok(input.value));
+        let input = MaybeHexInput::<u64>::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(maybe_hex::<u64>(&input.input), Ok(input.value));
+        let input = NumberInput::<i8>::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(number_range(&input.input, i8::MIN, i8::MAX), Ok(input.value));
+        let input = SiWhitespaceInput::<u16>::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(SiParser::new().allow_whitespace().parse::<u16>(&input.input), Ok(input.value));
+        let input = SiNamesInput::<i16>::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(SiParser::new().allow_names().parse::<i16>(&input.input), Ok(input.value));
+        let input = BcdInput::<u64>::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(bcd::<u64>(&input.input), Ok(input.value));
+        let input = GrayCodeInput::<u8>::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(gray_code::<u8>(&input.input), Ok(input.value));
+        let input = Ipv4Input::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(ipv4(&input.input), Ok(input.value));
+        let input = Ipv4PrefixLenInput::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(ipv4_prefix_len(&input.input), Ok(input.value));
+        let input = Ipv6PrefixLenInput::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(ipv6_prefix_len(&input.input), Ok(input.value));
+        let input = Ipv4NetmaskInput::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(ipv4_netmask(&input.input), Ok(input.value));
+        let input = Ipv6NetmaskInput::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(ipv6_netmask(&input.input), Ok(input.value));
+        let input = FileModeInput::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(file_mode(&input.input), Ok(input.value));
+        let input = UuidInput::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(uuid(&input.input), Ok(input.value));
+        let input = HexColorInput::arbitrary(&mut u).unwrap();
+        prop_assert_eq!(hex_color(&input.input), Ok(input.value));
+    }
+}
@@ -0,0 +1,145 @@
+use clap::Parser;
+use clap_num::{Rounding, TickParser};
+use std::time::Duration;
+
+#[cfg(test)]
+mod frequency {
+    use super::*;
+
+    const KHZ: TickParser = TickParser::frequency(1_000);
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(KHZ.parse($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $TYPE:ty, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<$TYPE, String> = KHZ.parse($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(zero, "0", 0u32);
+    pos!(millis, "10ms", 10u32);
+    pos!(secs, "1.5s", 1_500u32);
+    pos!(compound, "1m30s", 90_000u32);
+    pos!(micros, "2000us", 2u8);
+
+    neg!(
+        fraction,
+        "1500us",
+        u32,
+        "1500us is not a whole number of ticks"
+    );
+    neg!(overflow, "1s", u8, "number too large to fit in target type");
+    neg!(
+        invalid,
+        "10",
+        u32,
+        "missing unit, expected one of ns, us, ms, s, m, h, d"
+    );
+}
+
+#[cfg(test)]
+mod period {
+    use super::*;
+
+    #[test]
+    fn exact() {
+        let ticks = TickParser::period(Duration::from_millis(10));
+        assert_eq!(ticks.parse("1s"), Ok(100u16));
+        assert_eq!(ticks.parse("20ms"), Ok(2u16));
+    }
+
+    #[test]
+    fn inexact() {
+        let ticks = TickParser::period(Duration::from_millis(10));
+        assert_eq!(
+            ticks.parse::<u16>("15ms"),
+            Err(String::from("15ms is not a whole number of ticks"))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_period() {
+        let _ = TickParser::period(Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_frequency() {
+        let _ = TickParser::frequency(0);
+    }
+}
+
+#[cfg(test)]
+mod rounding {
+    use super::*;
+
+    macro_rules! round {
+        ($NAME:ident, $VAL:expr, $NEAREST:expr, $FLOOR:expr, $CEIL:expr) => {
+            #[test]
+            fn $NAME() {
+                let ticks = TickParser::period(Duration::from_millis(10));
+                let parse = |rounding| ticks.rounding(rounding).parse::<u32>($VAL);
+                assert_eq!(parse(Rounding::Nearest), Ok($NEAREST));
+                assert_eq!(parse(Rounding::Floor), Ok($FLOOR));
+                assert_eq!(parse(Rounding::Ceil), Ok($CEIL));
+            }
+        };
+    }
+
+    round!(exact, "20ms", 2, 2, 2);
+    round!(below_half, "14ms", 1, 1, 2);
+    round!(half, "15ms", 2, 1, 2);
+    round!(above_half, "16ms", 2, 1, 2);
+    round!(below_one, "1ns", 0, 0, 1);
+
+    #[test]
+    fn frequency() {
+        // 3 ticks per second, 1s/3 is not exact
+        let ticks = TickParser::frequency(3).rounding(Rounding::Nearest);
+        assert_eq!(ticks.parse("500ms"), Ok(2u32));
+        assert_eq!(ticks.parse("1s"), Ok(3u32));
+    }
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    const TICKS: TickParser = TickParser::frequency(100).rounding(Rounding::Ceil);
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=|s: &str| TICKS.parse::<u32>(s))]
+        timeout: u32,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from(["", "--timeout", "15ms"]);
+        assert_eq!(args.timeout, 2);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from(["", "--timeout", "15"]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("missing unit")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use clap_num::{tolerance, Tolerance};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $NOMINAL:expr, $DEVIATION:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(
+                    tolerance($VAL),
+                    Ok(Tolerance {
+                        nominal: $NOMINAL,
+                        deviation: $DEVIATION
+                    })
+                );
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val: Result<Tolerance<i32>, String> = tolerance($VAL);
+                assert_eq!(val, Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(plus_minus, "100±5", 100u32, 5);
+    pos!(ascii, "100+/-5", 100u32, 5);
+    pos!(whitespace, "100 ± 5", 100u32, 5);
+    pos!(si, "1.5M+/-2k", 1_500_000u32, 2_000);
+    pos!(percent, "10k±5%", 10_000u32, 500);
+    pos!(zero, "7±0", 7u8, 0);
+    pos!(negative_nominal, "-100±5", -100i32, 5);
+    pos!(negative_percent, "-100±5%", -100i32, 5);
+    pos!(percent_u8, "100±5%", 100u8, 5);
+    pos!(percent_u8_large, "200±5%", 200u8, 10);
+    pos!(percent_u8_max, "200±25%", 200u8, 50);
+    pos!(fractional_percent, "1000±0.5%", 1_000u32, 5);
+    pos!(fractional_percent_small, "10k±0.25%", 10_000u32, 25);
+    pos!(percent_large, "4G±1%", 4_000_000_000u32, 40_000_000);
+
+    neg!(missing, "100", "expected tolerance in the format of 100±5");
+    neg!(
+        plus_only,
+        "100+5",
+        "expected tolerance in the format of 100±5"
+    );
+    neg!(inexact, "10±5%", "5% of 10 is not an integer");
+    neg!(inexact_fraction, "10±0.5%", "0.5% of 10 is not an integer");
+    neg!(invalid_percent, "100±5x%", "invalid digit found in string");
+    neg!(negative, "100±-5", "tolerance must not be negative");
+    neg!(
+        negative_percent_deviation,
+        "100±-5%",
+        "tolerance must not be negative"
+    );
+    neg!(
+        negative_whitespace,
+        "100 ± -5",
+        "tolerance must not be negative"
+    );
+    neg!(
+        empty_nominal,
+        "±5",
+        "cannot parse integer from empty string"
+    );
+    neg!(invalid, "100±x", "invalid digit found in string");
+    neg!(
+        overflow,
+        "2147483647±1",
+        "number too large to fit in target type"
+    );
+
+    #[test]
+    fn unsigned_underflow() {
+        assert_eq!(
+            tolerance::<u8>("3±5"),
+            Err(String::from("number too small to fit in target type"))
+        );
+    }
+
+    #[test]
+    fn unsigned_negative() {
+        assert_eq!(
+            tolerance::<u32>("100±-5"),
+            Err(String::from("tolerance must not be negative"))
+        );
+        assert_eq!(
+            tolerance::<u32>("100±-5%"),
+            Err(String::from("tolerance must not be negative"))
+        );
+    }
+
+    #[test]
+    fn range() {
+        let limits = Tolerance {
+            nominal: 100,
+            deviation: 5,
+        };
+        assert_eq!(limits.range(), 95..=105);
+    }
+}
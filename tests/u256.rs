@@ -0,0 +1,218 @@
+#![cfg(feature = "primitive-types")]
+
+use clap::Parser;
+use clap_num::u256::{maybe_hex, maybe_hex_range, number_range, si_number, si_number_range};
+use primitive_types::U256;
+
+const MAX: &str = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const MAX_HEX: &str = "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF";
+
+fn u(val: u128) -> U256 {
+    U256::from(val)
+}
+
+fn word_range(s: &str) -> Result<U256, String> {
+    maybe_hex_range(s, u(0x10), u(0xFF))
+}
+
+fn amount_range(s: &str) -> Result<U256, String> {
+    number_range(s, u(1), u(1_000))
+}
+
+fn si_amount_range(s: &str) -> Result<U256, String> {
+    si_number_range(s, u(1_000), u(1_000_000))
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(hex_decimal, maybe_hex, "10", u(10));
+    pos!(hex_prefixed, maybe_hex, "0x10", u(0x10));
+    pos!(hex_upper_prefix, maybe_hex, "0XfF", u(0xFF));
+    pos!(hex_underscores, maybe_hex, "0xFFFF_FFFF", u(0xFFFF_FFFF));
+    pos!(hex_max, maybe_hex, MAX_HEX, U256::MAX);
+    pos!(hex_decimal_max, maybe_hex, MAX, U256::MAX);
+    pos!(
+        hex_above_u128,
+        maybe_hex,
+        "0x1_0000_0000_0000_0000_0000_0000_0000_0000",
+        U256::from(u128::MAX) + 1
+    );
+    neg!(
+        hex_overflow,
+        maybe_hex,
+        &format!("{MAX_HEX}0"),
+        "number too large to fit in target type"
+    );
+    neg!(
+        hex_decimal_overflow,
+        maybe_hex,
+        &format!("{MAX}0"),
+        "number too large to fit in target type"
+    );
+    neg!(
+        hex_empty,
+        maybe_hex,
+        "",
+        "cannot parse integer from empty string"
+    );
+    neg!(
+        hex_invalid,
+        maybe_hex,
+        "0xG",
+        "invalid digit found in string"
+    );
+    neg!(hex_negative, maybe_hex, "-1", "value cannot be negative");
+
+    pos!(range_min, word_range, "0x10", u(0x10));
+    pos!(range_max, word_range, "255", u(0xFF));
+    neg!(range_above, word_range, "0x100", "exceeds maximum of 0xFF");
+    neg!(range_below, word_range, "15", "less than minimum of 16");
+
+    pos!(number_simple, amount_range, "1000", u(1_000));
+    pos!(number_not_hex, amount_range, "10", u(10));
+    neg!(
+        number_above,
+        amount_range,
+        "1001",
+        "exceeds maximum of 1000"
+    );
+    neg!(
+        number_hex,
+        amount_range,
+        "0x10",
+        "hexadecimal prefix '0x' is not accepted, expected decimal"
+    );
+    neg!(
+        number_negative,
+        amount_range,
+        "-1",
+        "value cannot be negative"
+    );
+
+    pos!(si_plain, si_number, "10", u(10));
+    pos!(si_kilo, si_number, "1k5", u(1_500));
+    pos!(
+        si_yotta,
+        si_number,
+        "1.5Y",
+        u(1_500_000_000_000_000_000_000_000)
+    );
+    pos!(si_exponent, si_number, "1e30Y", U256::exp10(54));
+    pos!(si_max, si_number, MAX, U256::MAX);
+    neg!(
+        si_overflow,
+        si_number,
+        "1e60Y",
+        "number too large to fit in target type"
+    );
+    neg!(si_fraction, si_number, "1.0001k", "not an integer");
+
+    pos!(si_range_max, si_amount_range, "1M", u(1_000_000));
+    neg!(
+        si_range_above,
+        si_amount_range,
+        "1.1M",
+        "exceeds maximum of 1M"
+    );
+    neg!(
+        si_range_below,
+        si_amount_range,
+        "999",
+        "less than minimum of 1000"
+    );
+}
+
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=maybe_hex)]
+        slot: U256,
+        #[clap(long, value_parser=si_number)]
+        amount: U256,
+    }
+
+    #[test]
+    fn parse() {
+        let args = Args::parse_from(["", "--slot", MAX_HEX, "--amount", "20G"]);
+        assert_eq!(args.slot, U256::MAX);
+        assert_eq!(args.amount, u(20_000_000_000));
+    }
+
+    #[test]
+    fn overflow() {
+        let err = Args::try_parse_from(["", "--slot", &format!("{MAX_HEX}F"), "--amount", "1"])
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .contains("number too large to fit in target type"));
+    }
+}
+
+#[cfg(test)]
+mod wei {
+    use super::*;
+    use clap_num::u256::{wei, wei_range};
+
+    fn deposit(s: &str) -> Result<U256, String> {
+        wei_range(s, u(1), U256::exp10(40))
+    }
+
+    #[test]
+    fn above_u128() {
+        assert_eq!(wei("1000000000000000000000000eth"), Ok(U256::exp10(42)));
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(wei("1.000000000000000001eth"), Ok(U256::exp10(18) + 1));
+        assert_eq!(
+            wei("1.0000000000000000001eth"),
+            Err(String::from("not a whole number of wei"))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            wei(&format!("{MAX}eth")),
+            Err(String::from("number too large to fit in target type"))
+        );
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(
+            deposit("100000000000000000000000eth"),
+            Err(String::from(
+                "exceeds maximum of 10000000000000000000000000000000000000000wei"
+            ))
+        );
+        assert_eq!(deposit("10000000000000000000000eth"), Ok(U256::exp10(40)));
+    }
+}
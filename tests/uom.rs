@@ -0,0 +1,126 @@
+#![cfg(feature = "uom")]
+
+use clap::Parser;
+use clap_num::uom::{electric_potential, frequency, length};
+use uom::si::electric_potential::{millivolt, volt};
+use uom::si::f64::{ElectricPotential, Frequency, Length};
+use uom::si::frequency::{hertz, megahertz};
+use uom::si::length::{meter, millimeter};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path, with a tolerance for the conversion
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $UNIT:ty, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                let val = $PARSER($VAL).unwrap().get::<$UNIT>();
+                assert!((val - $RESULT).abs() < 1e-9, "{val} != {}", $RESULT);
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL).unwrap_err(), $RESULT);
+            }
+        };
+    }
+
+    pos!(length_base, length, "2m", meter, 2.0);
+    pos!(length_no_unit, length, "2", meter, 2.0);
+    pos!(length_millimeters, length, "5mm", millimeter, 5.0);
+    pos!(length_kilometers, length, "1.5km", meter, 1_500.0);
+    pos!(length_micro, length, "250µm", millimeter, 0.25);
+    pos!(length_micro_ascii, length, "250um", millimeter, 0.25);
+    pos!(frequency_base, frequency, "50Hz", hertz, 50.0);
+    pos!(frequency_mega, frequency, "16MHz", megahertz, 16.0);
+    pos!(frequency_giga, frequency, "2.4GHz", hertz, 2.4e9);
+    pos!(potential_base, electric_potential, "3.3V", volt, 3.3);
+    pos!(
+        potential_milli,
+        electric_potential,
+        "-500mV",
+        millivolt,
+        -500.0
+    );
+
+    neg!(
+        length_unknown,
+        length,
+        "3ft",
+        "unknown unit 'ft', expected one of m, km, cm, mm, µm, um, nm"
+    );
+    neg!(
+        frequency_wrong_dimension,
+        frequency,
+        "5V",
+        "unknown unit 'V', expected one of Hz, kHz, MHz, GHz"
+    );
+    neg!(
+        potential_wrong_dimension,
+        electric_potential,
+        "5m",
+        "unknown unit 'm', expected one of V, kV, mV, µV, uV"
+    );
+    neg!(
+        length_no_value,
+        length,
+        "mm",
+        "no value found before unit 'mm'"
+    );
+}
+
+// integration tests with clap
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=length)]
+        wavelength: Length,
+        #[clap(long, value_parser=frequency)]
+        clock: Frequency,
+        #[clap(long, value_parser=electric_potential)]
+        vdd: ElectricPotential,
+    }
+
+    #[test]
+    fn pos() {
+        let args = Args::parse_from([
+            "",
+            "--wavelength",
+            "500nm",
+            "--clock",
+            "8MHz",
+            "--vdd",
+            "1.8V",
+        ]);
+        assert!((args.wavelength.get::<meter>() - 500e-9).abs() < 1e-15);
+        assert_eq!(args.clock.get::<hertz>(), 8e6);
+        assert_eq!(args.vdd.get::<volt>(), 1.8);
+    }
+
+    #[test]
+    fn neg() {
+        let args = Args::try_parse_from([
+            "",
+            "--wavelength",
+            "500nm",
+            "--clock",
+            "8MV",
+            "--vdd",
+            "1.8V",
+        ]);
+        match args {
+            Err(e) => assert!(e.to_string().contains("unknown unit 'MV'")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use clap::Parser;
+use clap_num::utc_offset;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(utc_offset($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(utc_offset($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    const FORMAT: &str = "expected an offset such as +05:30 or -0800";
+
+    pos!(colon, "+05:30", 330);
+    pos!(compact, "+0530", 330);
+    pos!(negative_colon, "-08:00", -480);
+    pos!(negative_compact, "-0800", -480);
+    pos!(zero, "+00:00", 0);
+    pos!(negative_zero, "-00:00", 0);
+    pos!(zulu, "Z", 0);
+    pos!(zulu_lower, "z", 0);
+    pos!(quarter_hour, "+05:45", 345);
+    pos!(max, "+14:00", 840);
+    pos!(min, "-14:00", -840);
+    pos!(max_compact, "+1400", 840);
+
+    neg!(above, "+14:01", "exceeds maximum of +14:00");
+    neg!(below, "-15:00", "less than minimum of -14:00");
+    neg!(large_hours, "+99:00", "exceeds maximum of +14:00");
+    neg!(minutes, "+05:60", "minutes must be less than 60, found 60");
+    neg!(
+        minutes_compact,
+        "+0575",
+        "minutes must be less than 60, found 75"
+    );
+    neg!(no_sign, "05:30", FORMAT);
+    neg!(empty, "", FORMAT);
+    neg!(sign_only, "+", FORMAT);
+    neg!(hours_only, "+05", FORMAT);
+    neg!(short_hours, "+5:30", FORMAT);
+    neg!(short_minutes, "+05:3", FORMAT);
+    neg!(compact_short, "+530", FORMAT);
+    neg!(seconds, "+05:30:00", FORMAT);
+    neg!(double_sign, "+-05:30", FORMAT);
+    neg!(letters, "+ab:cd", FORMAT);
+    neg!(whitespace, "+05: 30", FORMAT);
+    neg!(non_ascii, "+0µ0", FORMAT);
+    neg!(utc_name, "UTC", FORMAT);
+}
+
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=utc_offset, allow_hyphen_values=true)]
+        offset: i32,
+    }
+
+    #[test]
+    fn parse() {
+        let args = Args::parse_from(["", "--offset", "-03:30"]);
+        assert_eq!(args.offset, -210);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let err = Args::try_parse_from(["", "--offset", "+15:00"])
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("exceeds maximum of +14:00"));
+    }
+}
@@ -0,0 +1,87 @@
+use clap_num::uuid;
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(uuid($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!(uuid($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(
+        hyphenated,
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8
+    );
+    pos!(
+        hyphenated_upper,
+        "67E55044-10B1-426F-9247-BB680E5FE0C8",
+        0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8
+    );
+    pos!(
+        bare,
+        "67e5504410b1426f9247bb680e5fe0c8",
+        0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8
+    );
+    pos!(nil, "00000000-0000-0000-0000-000000000000", 0);
+    pos!(max, "ffffffff-ffff-ffff-ffff-ffffffffffff", u128::MAX);
+    pos!(hex, "0x1234", 0x1234);
+    pos!(
+        hex_upper,
+        "0X67E5504410B1426F9247BB680E5FE0C8",
+        0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8
+    );
+
+    neg!(
+        hyphen_misplaced,
+        "67e5504-410b1-426f-9247-bb680e5fe0c8",
+        "invalid UUID hyphen placement"
+    );
+    neg!(
+        missing_hyphen,
+        "67e55044-10b1-426f-9247bb680e5fe0c8",
+        "invalid UUID hyphen placement"
+    );
+    neg!(
+        too_short,
+        "67e5504410b1426f9247bb680e5fe0c",
+        "expected 32 hex digits in UUID, found 31"
+    );
+    neg!(
+        too_long,
+        "67e5504410b1426f9247bb680e5fe0c80",
+        "expected 32 hex digits in UUID, found 33"
+    );
+    neg!(
+        non_hex,
+        "67e5504410b1426f9247bb680e5fe0cg",
+        "invalid digit found in string"
+    );
+    neg!(
+        sign,
+        "+67e5504410b1426f9247bb680e5fe0c",
+        "invalid digit found in string"
+    );
+    neg!(
+        hex_overflow,
+        "0x167e5504410b1426f9247bb680e5fe0c8",
+        "number too large to fit in target type"
+    );
+    neg!(empty, "", "expected 32 hex digits in UUID, found 0");
+}
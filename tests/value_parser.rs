@@ -0,0 +1,127 @@
+#![cfg(feature = "clap")]
+
+use clap::builder::ValueParserFactory;
+use clap::{value_parser, Arg, Command};
+use clap_num::{HexOnly, MaybeBin, MaybeHex, MaybeOct, Ranged, SiNumber, Verbatim};
+
+fn command() -> Command {
+    Command::new("test")
+        .arg(
+            Arg::new("address")
+                .long("address")
+                .value_parser(value_parser!(MaybeHex<u32>)),
+        )
+        .arg(
+            Arg::new("resistance")
+                .long("resistance")
+                .value_parser(value_parser!(Verbatim<SiNumber<u32>>)),
+        )
+        .arg(
+            Arg::new("cents")
+                .long("cents")
+                .value_parser(value_parser!(Ranged<u8, 0, 99>)),
+        )
+}
+
+#[cfg(test)]
+mod factory {
+    use super::*;
+
+    #[test]
+    fn maybe_hex() {
+        let parser = MaybeHex::<u16>::value_parser();
+        assert_eq!(parser("0x10"), Ok(MaybeHex(16)));
+        assert_eq!(
+            parser("0x10000"),
+            Err(String::from("number too large to fit in target type"))
+        );
+    }
+
+    #[test]
+    fn maybe_bin() {
+        assert_eq!(MaybeBin::<u8>::value_parser()("0b101"), Ok(MaybeBin(5)));
+    }
+
+    #[test]
+    fn maybe_oct() {
+        assert_eq!(
+            MaybeOct::<u16>::value_parser()("0o755"),
+            Ok(MaybeOct(0o755))
+        );
+    }
+
+    #[test]
+    fn hex_only() {
+        assert_eq!(HexOnly::<u32>::value_parser()("FF"), Ok(HexOnly(0xFF)));
+    }
+
+    #[test]
+    fn si_number() {
+        assert_eq!(
+            SiNumber::<i64>::value_parser()("-4k7"),
+            Ok(SiNumber(-4_700))
+        );
+    }
+
+    #[test]
+    fn verbatim() {
+        let resistance = Verbatim::<SiNumber<u32>>::value_parser()("3k3").unwrap();
+        assert_eq!(resistance.value, SiNumber(3_300));
+        assert_eq!(resistance.as_str(), "3k3");
+    }
+
+    #[test]
+    fn ranged() {
+        let parser = Ranged::<i16, -100, 100>::value_parser();
+        assert_eq!(parser("-100"), Ok(Ranged::new(-100).unwrap()));
+        assert_eq!(parser("101"), Err(String::from("exceeds maximum of 100")));
+    }
+}
+
+// integration tests with the clap builder API
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[test]
+    fn pos() {
+        let matches = command().get_matches_from(["", "--address", "0x10", "--resistance", "3k3"]);
+        assert_eq!(
+            matches.get_one::<MaybeHex<u32>>("address"),
+            Some(&MaybeHex(16))
+        );
+        assert_eq!(
+            matches
+                .get_one::<Verbatim<SiNumber<u32>>>("resistance")
+                .map(Verbatim::as_str),
+            Some("3k3")
+        );
+    }
+
+    #[test]
+    fn neg() {
+        let matches = command().try_get_matches_from(["", "--address", "0xZZ"]);
+        match matches {
+            Err(e) => assert!(e.to_string().contains("invalid digit found in string")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn ranged() {
+        let matches = command().get_matches_from(["", "--cents", "42"]);
+        assert_eq!(
+            matches
+                .get_one::<Ranged<u8, 0, 99>>("cents")
+                .copied()
+                .map(Ranged::get),
+            Some(42)
+        );
+
+        let matches = command().try_get_matches_from(["", "--cents", "100"]);
+        match matches {
+            Err(e) => assert!(e.to_string().contains("exceeds maximum of 99")),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use clap::Parser;
+use clap_num::{wei, wei_range};
+
+const ETH: u128 = 1_000_000_000_000_000_000;
+const GWEI: u128 = 1_000_000_000;
+
+fn priority_fee(s: &str) -> Result<u128, String> {
+    wei_range(s, 1, 2 * GWEI)
+}
+
+fn value(s: &str) -> Result<u128, String> {
+    wei_range(s, ETH / 2, 32 * ETH)
+}
+
+fn odd_bounds(s: &str) -> Result<u128, String> {
+    wei_range(s, 1_500, 1_000_000)
+}
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $PARSER:expr, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($PARSER($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(eth, wei::<u128>, "1.5eth", 3 * ETH / 2);
+    pos!(ether, wei::<u128>, "2ether", 2 * ETH);
+    pos!(gwei, wei::<u128>, "20gwei", 20 * GWEI);
+    pos!(plain_wei, wei::<u128>, "1000000wei", 1_000_000);
+    pos!(kwei, wei::<u128>, "1kwei", 1_000);
+    pos!(mwei, wei::<u128>, "1mwei", 1_000_000);
+    pos!(szabo, wei::<u128>, "1szabo", 1_000_000_000_000);
+    pos!(finney, wei::<u128>, "1finney", 1_000_000_000_000_000);
+    pos!(upper_case, wei::<u128>, "1.5ETH", 3 * ETH / 2);
+    pos!(mixed_case, wei::<u128>, "20GWei", 20 * GWEI);
+    pos!(whitespace, wei::<u128>, "1.5 eth", 3 * ETH / 2);
+    pos!(leading_dot, wei::<u128>, ".5eth", ETH / 2);
+    pos!(trailing_dot, wei::<u128>, "1.eth", ETH);
+    pos!(smallest, wei::<u128>, "0.000000000000000001eth", 1);
+    pos!(
+        trailing_zeros,
+        wei::<u128>,
+        "1.000000000000000000000eth",
+        ETH
+    );
+    pos!(underscores, wei::<u128>, "1_000gwei", 1_000 * GWEI);
+    pos!(zero, wei::<u128>, "0eth", 0);
+    pos!(plus, wei::<u128>, "+1gwei", GWEI);
+    pos!(signed, wei::<i128>, "-1.5gwei", -1_500_000_000);
+    pos!(u64_gwei, wei::<u64>, "18.446744073709551615eth", u64::MAX);
+
+    neg!(
+        too_precise_eth,
+        wei::<u128>,
+        "0.0000000000000000001eth",
+        "not a whole number of wei"
+    );
+    neg!(
+        too_precise_gwei,
+        wei::<u128>,
+        "1.0000000001gwei",
+        "not a whole number of wei"
+    );
+    neg!(
+        fractional_wei,
+        wei::<u128>,
+        "1.5wei",
+        "not a whole number of wei"
+    );
+    neg!(
+        no_unit,
+        wei::<u128>,
+        "1000",
+        "missing unit, expected one of wei, kwei, mwei, gwei, szabo, finney, ether, eth"
+    );
+    neg!(
+        unknown_unit,
+        wei::<u128>,
+        "1btc",
+        "unknown unit 'btc', expected one of wei, kwei, mwei, gwei, szabo, finney, ether, eth"
+    );
+    neg!(
+        exponent,
+        wei::<u128>,
+        "1e18wei",
+        "unknown unit 'e18wei', expected one of wei, kwei, mwei, gwei, szabo, finney, ether, eth"
+    );
+    neg!(
+        no_value,
+        wei::<u128>,
+        "eth",
+        "no value found before unit 'eth'"
+    );
+    neg!(
+        empty_value,
+        wei::<u128>,
+        ".eth",
+        "cannot parse integer from empty string"
+    );
+    neg!(
+        two_dots,
+        wei::<u128>,
+        "1.2.3eth",
+        "invalid digit found in string"
+    );
+    neg!(negative, wei::<u128>, "-1eth", "value cannot be negative");
+    neg!(
+        overflow,
+        wei::<u64>,
+        "18.5eth",
+        "number too large to fit in target type"
+    );
+    neg!(
+        underflow,
+        wei::<i64>,
+        "-10eth",
+        "number too small to fit in target type"
+    );
+
+    pos!(range_max, priority_fee, "2gwei", 2 * GWEI);
+    pos!(range_min, priority_fee, "1wei", 1);
+    neg!(
+        range_above,
+        priority_fee,
+        "0.1eth",
+        "exceeds maximum of 2gwei"
+    );
+    neg!(
+        range_below,
+        priority_fee,
+        "0gwei",
+        "less than minimum of 1wei"
+    );
+    neg!(range_eth, value, "33eth", "exceeds maximum of 32eth");
+    neg!(
+        range_half_eth,
+        value,
+        "0.1eth",
+        "less than minimum of 500000000gwei"
+    );
+    neg!(
+        range_odd,
+        odd_bounds,
+        "1wei",
+        "less than minimum of 1500wei"
+    );
+}
+
+#[cfg(test)]
+mod integration {
+    use super::*;
+
+    #[derive(Parser)]
+    struct Args {
+        #[clap(long, value_parser=wei::<u128>)]
+        value: u128,
+        #[clap(long, value_parser=priority_fee)]
+        priority_fee: u128,
+    }
+
+    #[test]
+    fn parse() {
+        let args = Args::parse_from(["", "--value", "1.5eth", "--priority-fee", "1.5gwei"]);
+        assert_eq!(args.value, 3 * ETH / 2);
+        assert_eq!(args.priority_fee, 1_500_000_000);
+    }
+
+    #[test]
+    fn too_precise() {
+        let err = Args::try_parse_from(["", "--value", "1wei", "--priority-fee", "1.5wei"])
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("not a whole number of wei"));
+    }
+}
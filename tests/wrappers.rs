@@ -0,0 +1,212 @@
+use clap_num::{HexOnly, MaybeBin, MaybeHex, MaybeOct, Ranged, SiNumber, Verbatim};
+
+#[cfg(test)]
+mod basic {
+    use super::*;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $TYPE:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($VAL.parse::<$TYPE>(), Ok($RESULT));
+                assert_eq!(<$TYPE>::try_from($VAL), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $TYPE:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($VAL.parse::<$TYPE>(), Err(String::from($RESULT)));
+                assert_eq!(<$TYPE>::try_from($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(maybe_hex, MaybeHex<u32>, "0xFF", MaybeHex(255));
+    pos!(maybe_hex_dec, MaybeHex<u32>, "255", MaybeHex(255));
+    pos!(maybe_bin, MaybeBin<u8>, "0b11", MaybeBin(3));
+    pos!(maybe_oct, MaybeOct<u16>, "0o17", MaybeOct(15));
+    pos!(hex_only, HexOnly<u16>, "ff", HexOnly(255));
+    pos!(si_number, SiNumber<i64>, "-1.5M", SiNumber(-1_500_000));
+
+    neg!(
+        maybe_hex_invalid,
+        MaybeHex<u32>,
+        "0xG",
+        "invalid digit found in string"
+    );
+    neg!(
+        maybe_bin_invalid,
+        MaybeBin<u8>,
+        "0b2",
+        "invalid digit found in string"
+    );
+    neg!(
+        maybe_oct_overflow,
+        MaybeOct<u8>,
+        "0o400",
+        "number too large to fit in target type"
+    );
+    neg!(
+        hex_only_empty,
+        HexOnly<u8>,
+        "",
+        "cannot parse integer from empty string"
+    );
+    neg!(
+        si_number_fraction,
+        SiNumber<u32>,
+        "1.2345k",
+        "not an integer"
+    );
+
+    #[test]
+    fn into_inner() {
+        assert_eq!(MaybeHex(7u8).into_inner(), 7);
+    }
+}
+
+// the original string is kept along with the value
+#[cfg(test)]
+mod verbatim {
+    use super::*;
+    use clap_num::{maybe_hex_range, si_number, verbatim};
+
+    #[test]
+    fn from_str() {
+        let val: Verbatim<SiNumber<u32>> = "3k3".parse().unwrap();
+        assert_eq!(val.value, SiNumber(3_300));
+        assert_eq!(val.as_str(), "3k3");
+        assert_eq!(val.to_string(), "3k3");
+        assert_eq!(val.into_inner(), SiNumber(3_300));
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            Verbatim::<MaybeHex<u8>>::try_from("0x1F"),
+            Ok(Verbatim {
+                value: MaybeHex(0x1F),
+                input: String::from("0x1F"),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_error() {
+        assert_eq!(
+            "0x1FF".parse::<Verbatim<MaybeHex<u8>>>(),
+            Err(String::from("number too large to fit in target type"))
+        );
+    }
+
+    #[test]
+    fn function() {
+        let val = verbatim("1.5M", si_number::<u64>).unwrap();
+        assert_eq!(val.value, 1_500_000);
+        assert_eq!(val.input, "1.5M");
+    }
+
+    #[test]
+    fn function_error() {
+        assert_eq!(
+            verbatim("0x80", |s| maybe_hex_range(s, 0u8, 0x7F)),
+            Err(String::from("exceeds maximum of 0x7F"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod ranged {
+    use super::*;
+
+    type Cents = Ranged<u8, 0, 99>;
+    type Offset = Ranged<i8, -10, 10>;
+
+    // positive path
+    macro_rules! pos {
+        ($NAME:ident, $TYPE:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($VAL.parse::<$TYPE>().map(|val| val.get()), Ok($RESULT));
+                assert_eq!(<$TYPE>::try_from($VAL).map(|val| val.get()), Ok($RESULT));
+            }
+        };
+    }
+
+    // negative path
+    macro_rules! neg {
+        ($NAME:ident, $TYPE:ty, $VAL:expr, $RESULT:expr) => {
+            #[test]
+            fn $NAME() {
+                assert_eq!($VAL.parse::<$TYPE>(), Err(String::from($RESULT)));
+                assert_eq!(<$TYPE>::try_from($VAL), Err(String::from($RESULT)));
+            }
+        };
+    }
+
+    pos!(value, Cents, "42", 42);
+    pos!(min, Cents, "0", 0);
+    pos!(max, Cents, "99", 99);
+    pos!(signed_min, Offset, "-10", -10);
+    pos!(signed_max, Offset, "10", 10);
+    pos!(wide, Ranged<u128, 0, { i128::MAX }>, "1000", 1000);
+    pos!(
+        full_range,
+        Ranged<i128, { i128::MIN }, { i128::MAX }>,
+        "-170141183460469231731687303715884105728",
+        i128::MIN
+    );
+
+    neg!(above, Cents, "100", "exceeds maximum of 99");
+    neg!(negative, Cents, "-1", "value cannot be negative");
+    neg!(signed_below, Offset, "-11", "less than minimum of -10");
+    neg!(signed_above, Offset, "11", "exceeds maximum of 10");
+    neg!(
+        overflow,
+        Cents,
+        "256",
+        "number too large to fit in target type"
+    );
+    neg!(empty, Cents, "", "cannot parse integer from empty string");
+    neg!(invalid, Cents, "4a", "invalid digit found in string");
+    neg!(
+        hex,
+        Cents,
+        "0x10",
+        "hexadecimal prefix '0x' is not accepted, expected decimal"
+    );
+    neg!(
+        above_i128,
+        Ranged<u128, 0, { i128::MAX }>,
+        "170141183460469231731687303715884105728",
+        "exceeds maximum of 170141183460469231731687303715884105727"
+    );
+
+    #[test]
+    fn new() {
+        assert_eq!(Cents::new(99).map(Cents::get), Some(99));
+        assert_eq!(Cents::new(100), None);
+        assert_eq!(Offset::new(-11), None);
+        assert_eq!(Ranged::<u128, 0, 10>::new(u128::MAX), None);
+    }
+
+    #[test]
+    fn into_inner() {
+        assert_eq!(Offset::new(-3).unwrap().into_inner(), -3);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Offset::new(-3).unwrap().to_string(), "-3");
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Cents::new(1) < Cents::new(2));
+    }
+}